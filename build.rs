@@ -14,6 +14,12 @@ fn build_cli_tests() {
         .iter()
         .flat_map(|pattern| glob(pattern).unwrap())
         .filter_map(Result::ok)
+        // files of `X.in` are inputs of tests, not tests
+        .filter(|path| {
+            !path
+                .components()
+                .any(|c| c.as_os_str().to_string_lossy().ends_with(".in"))
+        })
         .collect::<Vec<_>>();
 
     for path in files {
@@ -53,6 +59,7 @@ fn build_cli_tests() {
     #[test]
     fn {test_name}() {{
         trycmd::TestCases::new()
+            .env("STARTLANG_CACHE", "")
             .case("{path}")
             .run();
     }}
@@ -65,6 +72,32 @@ fn build_cli_tests() {
     fs::write(dest_path, output).unwrap();
 }
 
+/// hash of the sources of the build, FNV-1a of the files of `dirs` in order of their paths
+fn fingerprint(dirs: &[&str]) -> u64 {
+    let mut files = dirs
+        .iter()
+        .flat_map(|dir| glob(&format!("{dir}/**/*")).unwrap())
+        .filter_map(Result::ok)
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+        .iter()
+        .flat_map(|path| {
+            let mut bytes = path.to_string_lossy().into_owned().into_bytes();
+            bytes.extend(fs::read(path).unwrap());
+            bytes
+        })
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
 fn main() {
     build_cli_tests();
+    // cached interfaces written by another build are not loaded
+    println!(
+        "cargo:rustc-env=STARTLANG_FINGERPRINT={:016x}",
+        fingerprint(&["src", "assets"])
+    );
 }
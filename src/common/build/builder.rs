@@ -0,0 +1,200 @@
+use super::error::{ErrorDependencyCycle, ErrorManifest};
+use super::manifest::{Manifest, MANIFEST};
+use crate::file_interpreter::{self, error::ErrorFileRead};
+use crate::interpreter::require::Status;
+use crate::interpreter::Interpreter as _;
+use crate::typer::Exports;
+use crate::utils::error::{ErrorCode, ErrorPrint};
+use crate::utils::location::SourceId;
+use crate::utils::theme::Theme;
+use ariadne::Source;
+use std::collections::{HashMap, HashSet};
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+pub struct Builder {
+    /// interpreter running the files of the build, each file is typed in its own environment,
+    /// its typer imports every checked file so that their identifiers are distinct
+    interpreter: file_interpreter::Interpreter,
+    /// canonical paths of the files of packages already checked, by canonical directory
+    files: HashMap<PathBuf, Vec<PathBuf>>,
+    /// packages in dependency order
+    packages: Vec<Manifest>,
+    /// canonical directories of packages already loaded
+    loaded: HashSet<PathBuf>,
+    /// canonical directories and names of packages being loaded
+    loading: Vec<(PathBuf, String)>,
+    pub err_code: i32,
+    theme: Theme,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        let mut interpreter = file_interpreter::Interpreter::with_stdlib();
+        interpreter.set_quiet(true);
+        Self {
+            interpreter,
+            files: HashMap::new(),
+            packages: Vec::new(),
+            loaded: HashSet::new(),
+            loading: Vec::new(),
+            err_code: 0,
+            theme: Theme::default_theme(),
+        }
+    }
+
+    /// print error, `source` is the source where it is located
+    fn fail<E>(&mut self, error: E, source: (SourceId, &str))
+    where
+        E: ErrorPrint + ErrorCode,
+    {
+        let mut cache = (source.0, Source::from(source.1));
+        error.eprint(&self.theme, &mut cache).unwrap();
+        self.add_error_code(error.code());
+    }
+
+    /// aggregate error code, the code of the first error or 1 after several errors
+    fn add_error_code(&mut self, code: i32) {
+        if code != 0 {
+            self.err_code = if self.err_code == 0 { code } else { 1 };
+        }
+    }
+
+    /// load package in `dir` and its dependencies, dependencies first
+    pub fn load(&mut self, dir: &Path) {
+        let path = dir.join(MANIFEST);
+        let Ok(content) = read_to_string(&path) else {
+            return self.fail(ErrorFileRead::new(path), (SourceId::Unknown, ""));
+        };
+        let manifest = match Manifest::parse(&path, &content) {
+            Ok(manifest) => manifest,
+            Err(err) => return self.fail(err, (SourceId::File(path), &content)),
+        };
+        let package_dir = canonical(dir);
+        self.loading
+            .push((package_dir.clone(), manifest.name.clone()));
+        for dependency in &manifest.dependencies {
+            let dependency_dir = canonical(&dependency.dir);
+            if let Some(pos) = self
+                .loading
+                .iter()
+                .position(|(dir, _)| dir == &dependency_dir)
+            {
+                let cycle = self.loading[pos..]
+                    .iter()
+                    .map(|(_, name)| name.clone())
+                    .chain(std::iter::once(dependency.name.clone()))
+                    .collect();
+                let error = ErrorDependencyCycle::new(cycle, dependency.loc.clone());
+                self.fail(error, (SourceId::File(path.clone()), &content));
+            } else if !self.loaded.contains(&dependency_dir) {
+                self.load(&dependency.dir);
+            }
+        }
+        self.loading.pop();
+        self.loaded.insert(package_dir);
+        self.packages.push(manifest);
+    }
+
+    /// check every file of packages in dependency order, each file once,
+    /// a file sees the definitions of the files of the dependencies of its package,
+    /// other files of its package are only visible when it requires them
+    pub fn check(&mut self) {
+        let packages = std::mem::take(&mut self.packages);
+        for package in &packages {
+            let dependencies = package
+                .dependencies
+                .iter()
+                .flat_map(|dependency| {
+                    self.files
+                        .get(&canonical(&dependency.dir))
+                        .into_iter()
+                        .flatten()
+                        .cloned()
+                })
+                .collect::<Vec<_>>();
+            self.interpreter
+                .mut_requires()
+                .set_dependencies(&dependencies);
+            let mut checked = Vec::new();
+            for (source, loc) in &package.sources {
+                match source_files(source) {
+                    Ok(files) => {
+                        checked.extend(files.into_iter().filter_map(|file| self.check_file(file)))
+                    }
+                    Err(()) => {
+                        let manifest = package.dir.join(MANIFEST);
+                        let content = read_to_string(&manifest).unwrap_or_default();
+                        let error = ErrorManifest::new(
+                            format!("Source {} is not a directory.", source.display()),
+                            loc.clone(),
+                        );
+                        self.fail(error, (SourceId::File(manifest), &content));
+                    }
+                }
+            }
+            self.files.insert(canonical(&package.dir), checked);
+        }
+        self.packages = packages;
+    }
+
+    /// check file `path` in the environment of the standard library and of the dependencies
+    /// of its package, unless it is already required by another file,
+    /// get its canonical path
+    fn check_file(&mut self, path: PathBuf) -> Option<PathBuf> {
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        match self
+            .interpreter
+            .mut_requires()
+            .enter(canonical.clone(), path.clone())
+        {
+            Status::New => {}
+            Status::Loaded(_) => return Some(canonical),
+            Status::Cycle(_) => return None,
+        }
+        let (code, exports) = match read_to_string(&path) {
+            Ok(content) => self.interpreter.check_required(path, &canonical, content),
+            Err(_) => {
+                self.fail(ErrorFileRead::new(path), (SourceId::Unknown, ""));
+                (0, Exports::default())
+            }
+        };
+        let exports = Rc::new(exports);
+        self.interpreter.typer.import(&exports);
+        self.interpreter.mut_requires().leave(exports);
+        self.add_error_code(code);
+        Some(canonical)
+    }
+}
+
+/// canonical path of directory `dir`, the empty path is the current directory
+fn canonical(dir: &Path) -> PathBuf {
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf())
+}
+
+/// files `.st` in directory `dir` and its subdirectories, sorted by path
+fn source_files(dir: &Path) -> Result<Vec<PathBuf>, ()> {
+    let mut files = Vec::new();
+    for entry in read_dir(dir).map_err(|_| ())? {
+        let path = entry.map_err(|_| ())?.path();
+        if path.is_dir() {
+            files.extend(source_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "st") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::utils::error::{ErrorCode, ErrorReport, Message};
+use crate::utils::location::{Located, Location};
+
+// ===========================================================================
+// Manifest Error
+// ===========================================================================
+
+pub struct ErrorManifest {
+    message: String,
+    loc: Location,
+}
+
+impl ErrorManifest {
+    pub fn new(message: impl Into<String>, loc: Location) -> Self {
+        Self {
+            message: message.into(),
+            loc,
+        }
+    }
+}
+
+impl ErrorCode for ErrorManifest {
+    fn code(&self) -> i32 {
+        109
+    }
+}
+
+impl Located for ErrorManifest {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorManifest {
+    fn head(&self) -> Message {
+        Message::text("Invalid manifest.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        Some(Message::text(&self.message))
+    }
+}
+
+// ===========================================================================
+// Dependency Cycle
+// ===========================================================================
+
+pub struct ErrorDependencyCycle {
+    /// packages of the cycle, from the dependency to itself
+    cycle: Vec<String>,
+    loc: Location,
+}
+
+impl ErrorDependencyCycle {
+    pub fn new(cycle: Vec<String>, loc: Location) -> Self {
+        Self { cycle, loc }
+    }
+}
+
+impl ErrorCode for ErrorDependencyCycle {
+    fn code(&self) -> i32 {
+        110
+    }
+}
+
+impl Located for ErrorDependencyCycle {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorDependencyCycle {
+    fn head(&self) -> Message {
+        Message::text("Cyclic dependency.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let name = self.cycle.last().cloned().unwrap_or_default();
+        let msg = Message::text("Package ")
+            .append(Message::quoted(name).important())
+            .with_text(" is already being built.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        Some(
+            Message::text("Cycle is ")
+                .with_text(self.cycle.join(" -> "))
+                .with_text("."),
+        )
+    }
+}
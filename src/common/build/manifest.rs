@@ -0,0 +1,151 @@
+use super::error::ErrorManifest;
+use crate::utils::location::{Location, SourceId};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use toml_edit::{Document, Item, Value};
+
+/// name of manifest file of a package
+pub const MANIFEST: &str = "Start.toml";
+
+/// source roots of a package without `sources` field
+const DEFAULT_SOURCES: &[&str] = &["src"];
+
+// ===========================================================================
+// Dependency
+// ===========================================================================
+/// local dependency `name = { path = "../name" }`
+pub struct Dependency {
+    /// name of dependency
+    pub name: String,
+    /// directory of dependency, relative to the current directory
+    pub dir: PathBuf,
+    /// location of dependency in the manifest
+    pub loc: Location,
+}
+
+// ===========================================================================
+// Manifest
+// ===========================================================================
+/// manifest of a package
+/// ```toml
+/// [package]
+/// name = "geometry"
+/// sources = ["src"]
+///
+/// [dependencies]
+/// shapes = { path = "../shapes" }
+/// ```
+pub struct Manifest {
+    /// name of package
+    pub name: String,
+    /// directory of package, relative to the current directory
+    pub dir: PathBuf,
+    /// source roots, relative to the current directory, with their location in the manifest
+    pub sources: Vec<(PathBuf, Location)>,
+    /// local dependencies
+    pub dependencies: Vec<Dependency>,
+}
+
+/// location of `span` in manifest, the start of the manifest when unknown
+fn location(source_id: &SourceId, span: Option<Range<usize>>) -> Location {
+    let span = span.unwrap_or(0..0);
+    Location::new(source_id.clone(), span.start, span.end)
+}
+
+/// get string of `item`, `what` describes the expected value
+fn string<'a>(source_id: &SourceId, item: &'a Item, what: &str) -> Result<&'a str, ErrorManifest> {
+    item.as_str().ok_or_else(|| {
+        ErrorManifest::new(
+            format!("Expected a string for {what}."),
+            location(source_id, item.span()),
+        )
+    })
+}
+
+impl Manifest {
+    /// parse `content` of manifest file `path`
+    pub fn parse(path: &Path, content: &str) -> Result<Self, ErrorManifest> {
+        let source_id = SourceId::File(path.to_path_buf());
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let document = Document::parse(content)
+            .map_err(|err| ErrorManifest::new(err.message(), location(&source_id, err.span())))?;
+
+        let package = document
+            .get("package")
+            .and_then(Item::as_table)
+            .ok_or_else(|| {
+                ErrorManifest::new("Missing table [package].", location(&source_id, None))
+            })?;
+        let package_loc = location(&source_id, package.span());
+
+        let name = match package.get("name") {
+            Some(item) => string(&source_id, item, "name of package")?.to_string(),
+            None => return Err(ErrorManifest::new("Missing name of package.", package_loc)),
+        };
+
+        let sources = match package.get("sources") {
+            None => DEFAULT_SOURCES
+                .iter()
+                .map(|source| (dir.join(source), package_loc.clone()))
+                .collect(),
+            Some(item) => {
+                let array = item.as_array().ok_or_else(|| {
+                    ErrorManifest::new(
+                        "Expected an array of paths for sources.",
+                        location(&source_id, item.span()),
+                    )
+                })?;
+                array
+                    .iter()
+                    .map(|value| {
+                        let loc = location(&source_id, value.span());
+                        match value {
+                            Value::String(source) => Ok((dir.join(source.value()), loc)),
+                            _ => Err(ErrorManifest::new("Expected a path for source.", loc)),
+                        }
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        let dependencies = match document.get("dependencies") {
+            None => Vec::new(),
+            Some(item) => {
+                let table = item.as_table_like().ok_or_else(|| {
+                    ErrorManifest::new(
+                        "Expected a table for dependencies.",
+                        location(&source_id, item.span()),
+                    )
+                })?;
+                table
+                    .iter()
+                    .map(|(name, item)| {
+                        let loc = location(&source_id, item.span());
+                        let path = item
+                            .as_table_like()
+                            .and_then(|dependency| dependency.get("path"))
+                            .ok_or_else(|| {
+                                ErrorManifest::new(
+                                    format!("Missing path of dependency {name}."),
+                                    loc.clone(),
+                                )
+                            })?;
+                        let path = string(&source_id, path, "path of dependency")?;
+                        Ok(Dependency {
+                            name: name.to_string(),
+                            dir: dir.join(path),
+                            loc,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        Ok(Self {
+            name,
+            dir,
+            sources,
+            dependencies,
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use std::path::Path;
+
+mod builder;
+mod error;
+mod manifest;
+
+/// check every file of package in directory `dir` and of its dependencies
+pub fn run(dir: &Path) -> i32 {
+    // paths of files are shown relative to the current directory
+    let dir = if dir == Path::new(".") {
+        Path::new("")
+    } else {
+        dir
+    };
+    let mut builder = builder::Builder::new();
+    builder.load(dir);
+    if builder.err_code == 0 {
+        builder.check();
+    }
+    builder.err_code
+}
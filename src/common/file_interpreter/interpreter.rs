@@ -1,7 +1,9 @@
 use super::error::ErrorFileRead;
 use crate::interpreter;
+use crate::interpreter::cache;
 use crate::interpreter::flag::DebugFlag;
 use crate::interpreter::flag::Flag;
+use crate::interpreter::require::Requires;
 use crate::interpreter::Interpreter as _;
 use crate::typer::Typer;
 use crate::utils::error::{ErrorPrint as _, ErrorReport};
@@ -20,17 +22,21 @@ pub struct Interpreter {
     err_code: i32,
     pub typer: Typer,
     pub vm: vm::Env,
+    pub requires: Requires,
     debug_lexer: bool,
     debug_parser: bool,
     debug_typer: bool,
+    obligations: bool,
+    /// outputs of commands are not printed, files are only checked
+    quiet: bool,
     theme: Theme,
 }
 
 impl Interpreter {
     pub fn new(path: &Path) -> Self {
-        let mut interpreter = Interpreter::stdlib();
-        interpreter.run();
+        let mut interpreter = Interpreter::with_stdlib();
         interpreter.source_id = SourceId::File(path.to_path_buf());
+        interpreter.requires = Requires::with_root(path);
         match read_to_string(path) {
             Ok(content) => {
                 interpreter.content = content;
@@ -48,12 +54,49 @@ impl Interpreter {
             err_code: 0,
             typer: Typer::default(),
             vm: vm::Env::default(),
+            requires: Requires::default(),
             debug_lexer: false,
             debug_parser: false,
             debug_typer: false,
+            obligations: false,
+            quiet: false,
             theme: Theme::default_theme(),
         }
     }
+
+    /// stdlib environment, loaded from its cached interface when possible
+    pub fn with_stdlib() -> Self {
+        let (typer, vm) = cache::stdlib();
+        Interpreter {
+            typer,
+            vm,
+            ..Interpreter::stdlib()
+        }
+    }
+
+    /// do not print outputs of commands, files are only checked
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    /// interpreter of `content` of file `path` required by a program, typed by `typer`,
+    /// its values are added to the environment `vm` of the program
+    pub fn required(
+        path: PathBuf,
+        content: String,
+        typer: Typer,
+        vm: vm::Env,
+        requires: Requires,
+    ) -> Self {
+        Interpreter {
+            source_id: SourceId::File(path),
+            content,
+            typer,
+            vm,
+            requires,
+            ..Interpreter::stdlib()
+        }
+    }
 }
 
 impl interpreter::Interpreter for Interpreter {
@@ -89,11 +132,31 @@ impl interpreter::Interpreter for Interpreter {
         &mut self.vm
     }
 
+    fn mut_requires(&mut self) -> &mut Requires {
+        &mut self.requires
+    }
+
+    fn run_required(&mut self, path: PathBuf, content: String, typer: Typer) -> (i32, Typer) {
+        let mut required = Interpreter::required(
+            path,
+            content,
+            typer,
+            std::mem::take(&mut self.vm),
+            std::mem::take(&mut self.requires),
+        );
+        required.quiet = self.quiet;
+        required.run_file();
+        self.vm = required.vm;
+        self.requires = required.requires;
+        (required.err_code, required.typer)
+    }
+
     fn set_flag(&mut self, b: bool, flag: Flag) {
         match flag {
             Flag::Debug(DebugFlag::Lexer) => self.debug_lexer = b,
             Flag::Debug(DebugFlag::Parser) => self.debug_parser = b,
             Flag::Debug(DebugFlag::Typer) => self.debug_typer = b,
+            Flag::Obligations => self.obligations = b,
         }
     }
 
@@ -105,8 +168,14 @@ impl interpreter::Interpreter for Interpreter {
         }
     }
 
+    fn is_active_obligations(&self) -> bool {
+        self.obligations
+    }
+
     fn print_summay(&self, _: &crate::typer::ast::ExpressionDefinition) {}
 
+    fn print_goals(&self, _: &crate::typer::tactic::Goals) {}
+
     fn eprint<E>(&mut self, error: &E)
     where
         E: ErrorReport,
@@ -119,6 +188,8 @@ impl interpreter::Interpreter for Interpreter {
     where
         Doc: Pretty,
     {
-        println!("{}", doc.make_string(&self.theme));
+        if !self.quiet {
+            println!("{}", doc.make_string(&self.theme));
+        }
     }
 }
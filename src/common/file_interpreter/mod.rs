@@ -9,6 +9,6 @@ pub use interpreter::Interpreter;
 /// intrerpet file
 pub fn run(path: &Path) -> i32 {
     let mut interpreter = interpreter::Interpreter::new(path);
-    interpreter.run();
+    interpreter.run_file();
     interpreter.get_error_code()
 }
@@ -0,0 +1,170 @@
+use super::require::Fingerprint;
+use crate::file_interpreter;
+use crate::interpreter::Interpreter as _;
+use crate::typer::ast;
+use crate::typer::interface::Interface;
+use crate::typer::Typer;
+use crate::utils::location::SourceId;
+use crate::vm;
+use crate::vm::value::{Constant, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// name of the interface of the standard library in the cache directory
+const STDLIB_INTERFACE: &str = "stdlib.sti";
+
+/// directory of the interfaces of required files in the cache directory
+const FILES_DIR: &str = "files";
+
+/// directory of cached interfaces, `STARTLANG_CACHE` or `startlang` in the cache directory
+/// of the user, an empty `STARTLANG_CACHE` disables the cache
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("STARTLANG_CACHE") {
+        return (!dir.is_empty()).then(|| PathBuf::from(dir));
+    }
+    let cache = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(cache.join("startlang"))
+}
+
+/// hash of a source, FNV-1a so that it is the same for every build
+fn hash(content: &str) -> u64 {
+    content.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// value of a definition as a constant of an interface
+fn constant(value: &Value) -> Option<ast::ConstantKind> {
+    match value {
+        Value::Constant(Constant::Nat(n)) => Some(ast::ConstantKind::Nat(n.clone())),
+        Value::Constant(Constant::Int(i)) => Some(ast::ConstantKind::Int(i.clone())),
+        Value::Constant(Constant::Bool(b)) => Some(ast::ConstantKind::Bool(*b)),
+        Value::Constant(Constant::Char(c)) => Some(ast::ConstantKind::Char(*c)),
+        Value::Constant(Constant::Unit) => Some(ast::ConstantKind::Unit),
+        Value::Primitive(p) => p.as_primitive().map(ast::ConstantKind::Primitive),
+        _ => None,
+    }
+}
+
+/// fingerprint of file of canonical path `canonical` with `content`
+pub fn fingerprint(canonical: &Path, content: &str) -> Fingerprint {
+    (hash(content), canonical.to_path_buf())
+}
+
+/// declare in `typer` the definitions of interface `path` of a source located in `source_id`,
+/// set their values in `vm`, get the fingerprints of the files the source depends on,
+/// `None` when the interface is missing or out of date
+fn load(
+    path: &Path,
+    source_id: &SourceId,
+    hash: u64,
+    mut typer: Typer,
+    vm: &mut vm::Env,
+) -> Option<(Typer, Vec<Fingerprint>)> {
+    let interface = Interface::parse(&fs::read_to_string(path).ok()?)?;
+    if interface.hash != hash {
+        return None;
+    }
+    // the interface is out of date as soon as a file it depends on changes
+    let unchanged = interface.depends.iter().all(|(hash, path)| {
+        fs::read_to_string(path).is_ok_and(|content| self::hash(&content) == *hash)
+    });
+    if !unchanged {
+        return None;
+    }
+    for (id, value) in typer.load_interface(&interface, source_id)? {
+        vm.set(id.as_ref().clone(), Value::from(&value));
+    }
+    Some((typer, interface.depends))
+}
+
+/// store interface at `path`, a cache that cannot be written is ignored
+fn store(path: &Path, interface: &Interface) {
+    // a concurrent run never reads a partially written interface
+    let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+    let stored = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&tmp, interface.to_string()))
+        .and_then(|()| fs::rename(&tmp, path));
+    if stored.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+}
+
+/// environments of the standard library, loaded from its cached interface
+/// when the standard library is unchanged
+pub fn stdlib() -> (Typer, vm::Env) {
+    let mut interpreter = file_interpreter::Interpreter::stdlib();
+    let hash = hash(interpreter.content());
+    let path = cache_dir().map(|dir| dir.join(STDLIB_INTERFACE));
+    let mut vm = vm::Env::default();
+    if let Some((typer, _)) = path.as_deref().and_then(|path| {
+        load(
+            path,
+            interpreter.source_id(),
+            hash,
+            Typer::default(),
+            &mut vm,
+        )
+    }) {
+        return (typer, vm);
+    }
+    interpreter.run_file();
+    if let Some(path) = path.filter(|_| interpreter.get_error_code() == 0) {
+        let vm = &interpreter.vm;
+        if let Some(interface) = interpreter
+            .typer
+            .interface(interpreter.source_id(), hash, |id| constant(vm.get(id)?))
+        {
+            store(&path, &interface);
+        }
+    }
+    (interpreter.typer, interpreter.vm)
+}
+
+/// interface of required file `canonical` in the cache directory
+fn required_interface(canonical: &Path) -> Option<PathBuf> {
+    let name = format!("{:016x}.sti", hash(&canonical.to_string_lossy()));
+    Some(cache_dir()?.join(FILES_DIR).join(name))
+}
+
+/// declare in `typer` the definitions of required file `file` of canonical path `canonical`,
+/// loaded from its cached interface when `content` and the files it depends on are unchanged,
+/// set their values in `vm`, get the fingerprints of the files it depends on
+pub fn load_required(
+    canonical: &Path,
+    file: &Path,
+    content: &str,
+    typer: Typer,
+    vm: &mut vm::Env,
+) -> Option<(Typer, Vec<Fingerprint>)> {
+    let path = required_interface(canonical)?;
+    let source_id = SourceId::File(file.to_path_buf());
+    load(&path, &source_id, hash(content), typer, vm)
+}
+
+/// store the interface of required file `file` of canonical path `canonical`,
+/// checked from `content` by `typer` with the values of `vm`,
+/// it depends on the files of fingerprints `depends`
+pub fn store_required(
+    canonical: &Path,
+    file: &Path,
+    content: &str,
+    depends: &[Fingerprint],
+    typer: &Typer,
+    vm: &vm::Env,
+) {
+    let Some(path) = required_interface(canonical) else {
+        return;
+    };
+    let source_id = SourceId::File(file.to_path_buf());
+    if let Some(mut interface) =
+        typer.interface(&source_id, hash(content), |id| constant(vm.get(id)?))
+    {
+        interface.depends = depends.to_vec();
+        store(&path, &interface);
+    }
+}
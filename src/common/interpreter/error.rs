@@ -1,7 +1,12 @@
 use crate::parser::cst::expression::Variable;
-use crate::parser::cst::AsIdentifier as _;
+use crate::parser::cst::module::Path;
+use crate::parser::cst::{AsIdentifier as _, AsString as _};
+use crate::typer::ast;
+use crate::typer::ast::ContractKind;
 use crate::utils::error::{ErrorCode, ErrorReport, Message};
 use crate::utils::location::{Located, Location};
+use crate::vm::{self, env::Violation, value::Value};
+use std::path::PathBuf;
 
 // =======================================================================
 // Unknown Option
@@ -40,3 +45,265 @@ impl ErrorReport for UnknownOption {
         Some(msg)
     }
 }
+
+// =======================================================================
+// Assertion Failed
+// =======================================================================
+
+pub struct AssertionFailed {
+    /// operands of asserted operation, evaluated
+    operands: Option<(Value, Value)>,
+    loc: Location,
+}
+
+impl AssertionFailed {
+    /// code of a failed assertion, also the exit code of several failed assertions
+    pub const CODE: i32 = 104;
+
+    /// failure of assertion `expr`, with operands evaluated by `vm`
+    pub fn new(expr: &ast::Expression, vm: &vm::Env) -> Self {
+        let operands = match expr {
+            ast::Expression::Binary(binary) => vm
+                .eval(binary.left())
+                .ok()
+                .zip(vm.eval(binary.right()).ok()),
+            _ => None,
+        };
+        Self {
+            operands,
+            loc: expr.loc(),
+        }
+    }
+}
+
+impl ErrorCode for AssertionFailed {
+    fn code(&self) -> i32 {
+        Self::CODE
+    }
+}
+
+impl Located for AssertionFailed {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for AssertionFailed {
+    fn head(&self) -> Message {
+        Message::text("Assertion failed.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Expression evaluates to ")
+            .append(Message::text("false").important())
+            .with_text(".");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        self.operands.as_ref().map(|(left, right)| {
+            Message::text("Left  : ")
+                .append(Message::of_pretty(left).important())
+                .with_line()
+                .with_text("Right : ")
+                .append(Message::of_pretty(right).important())
+        })
+    }
+}
+
+// =======================================================================
+// Contract Violation
+// =======================================================================
+
+pub struct ContractViolation {
+    violation: Box<Violation>,
+}
+
+impl From<Box<Violation>> for ContractViolation {
+    fn from(violation: Box<Violation>) -> Self {
+        Self { violation }
+    }
+}
+
+impl ErrorCode for ContractViolation {
+    fn code(&self) -> i32 {
+        105
+    }
+}
+
+impl Located for ContractViolation {
+    fn loc(&self) -> Location {
+        self.violation.loc()
+    }
+}
+
+impl ErrorReport for ContractViolation {
+    fn head(&self) -> Message {
+        Message::text("Contract violated.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let clause = self.violation.clause();
+        let kind = match clause.kind() {
+            ContractKind::Requires => "Precondition ",
+            ContractKind::Ensures => "Postcondition ",
+        };
+        let msg = Message::text(kind)
+            .append(Message::of_pretty(clause.source()).important())
+            .with_text(" does not hold.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let values = self.violation.values();
+        if values.is_empty() {
+            return None;
+        }
+        Some(Message::intersperse(
+            values.iter().map(|(id, value)| {
+                Message::text(format!("{} = ", id.name()))
+                    .append(Message::of_pretty(value).important())
+            }),
+            Message::line(),
+        ))
+    }
+}
+
+// =======================================================================
+// Require Not Found
+// =======================================================================
+
+pub struct RequireNotFound {
+    path: Path,
+    /// path of file, relative to the requiring file
+    file: PathBuf,
+}
+
+impl RequireNotFound {
+    pub fn new(path: Path, file: PathBuf) -> Self {
+        Self { path, file }
+    }
+}
+
+impl ErrorCode for RequireNotFound {
+    fn code(&self) -> i32 {
+        106
+    }
+}
+
+impl Located for RequireNotFound {
+    fn loc(&self) -> Location {
+        self.path.loc()
+    }
+}
+
+impl ErrorReport for RequireNotFound {
+    fn head(&self) -> Message {
+        Message::text("Required file not found.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("File ")
+            .append(Message::quoted(self.path.as_string()).important())
+            .with_text(" cannot be read.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        Some(
+            Message::text("Path is ")
+                .with_quoted(self.file.display())
+                .with_text("."),
+        )
+    }
+}
+
+// =======================================================================
+// Require Cycle
+// =======================================================================
+
+pub struct RequireCycle {
+    path: Path,
+    /// files of the cycle, from the required file to itself
+    cycle: Vec<PathBuf>,
+}
+
+impl RequireCycle {
+    pub fn new(path: Path, cycle: Vec<PathBuf>) -> Self {
+        Self { path, cycle }
+    }
+}
+
+impl ErrorCode for RequireCycle {
+    fn code(&self) -> i32 {
+        107
+    }
+}
+
+impl Located for RequireCycle {
+    fn loc(&self) -> Location {
+        self.path.loc()
+    }
+}
+
+impl ErrorReport for RequireCycle {
+    fn head(&self) -> Message {
+        Message::text("Cyclic require.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("File ")
+            .append(Message::quoted(self.path.as_string()).important())
+            .with_text(" is already being required.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let cycle = self
+            .cycle
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        Some(Message::text("Cycle is ").with_text(cycle).with_text("."))
+    }
+}
+
+// =======================================================================
+// Require Failed
+// =======================================================================
+
+pub struct RequireFailed {
+    path: Path,
+}
+
+impl From<Path> for RequireFailed {
+    fn from(path: Path) -> Self {
+        Self { path }
+    }
+}
+
+impl ErrorCode for RequireFailed {
+    fn code(&self) -> i32 {
+        108
+    }
+}
+
+impl Located for RequireFailed {
+    fn loc(&self) -> Location {
+        self.path.loc()
+    }
+}
+
+impl ErrorReport for RequireFailed {
+    fn head(&self) -> Message {
+        Message::text("Required file has errors.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("File ")
+            .append(Message::quoted(self.path.as_string()).important())
+            .with_text(" has errors.");
+        Some(msg)
+    }
+}
@@ -5,4 +5,6 @@ pub enum DebugFlag {
 }
 pub enum Flag {
     Debug(DebugFlag),
+    /// emit proof obligations of contracts instead of checking them at runtime
+    Obligations,
 }
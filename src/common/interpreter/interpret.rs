@@ -1,15 +1,20 @@
-use super::error::UnknownOption;
+use super::cache;
+use super::error::{
+    AssertionFailed, ContractViolation, RequireCycle, RequireFailed, RequireNotFound, UnknownOption,
+};
 use super::flag::{DebugFlag, Flag};
+use super::require::{self, Requires, Status};
 use crate::lexer;
-use crate::parser::cst::AsIdentifier as _;
+use crate::parser::cst::{AsIdentifier as _, AsString as _};
 use crate::parser::{self, cst};
-use crate::typer::ast::Typed as _;
 use crate::typer::{self, ast};
 use crate::utils::error::{ErrorCode, ErrorReport};
 use crate::utils::location::{Located, SourceId};
 use crate::utils::pretty::Pretty;
-use crate::vm;
+use crate::vm::{self, value::Constant, value::Value};
 use ariadne::Span as _;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 pub trait Interpreter {
     /// get content
@@ -36,12 +41,32 @@ pub trait Interpreter {
     /// get vm
     fn mut_vm(&mut self) -> &mut vm::Env;
 
+    /// get files required by the program
+    fn mut_requires(&mut self) -> &mut Requires;
+
+    /// get directory of source, paths of required files are relative to it
+    fn source_dir(&self) -> PathBuf {
+        require::source_dir(self.source_id())
+    }
+
+    /// run `content` of file `path` required by the program, typed by `typer`,
+    /// get its error code and its typer
+    fn run_required(
+        &mut self,
+        path: PathBuf,
+        content: String,
+        typer: typer::Typer,
+    ) -> (i32, typer::Typer);
+
     /// set debug parser
     fn set_flag(&mut self, b: bool, flag: Flag);
 
     /// get debug flags
     fn is_active_debug(&self, debug: DebugFlag) -> bool;
 
+    /// get flag of proof obligations, emitted instead of checking contracts at runtime
+    fn is_active_obligations(&self) -> bool;
+
     /// print
     fn print<Doc>(&mut self, doc: &Doc)
     where
@@ -50,13 +75,25 @@ pub trait Interpreter {
     /// active printing of summarry definition
     fn print_summay(&self, def: &ast::ExpressionDefinition);
 
+    /// active printing of goals left in a proof by tactics
+    fn print_goals(&self, goals: &typer::tactic::Goals);
+
+    /// print output of a command, the file being run prints outputs
+    fn output<Doc>(&mut self, doc: &Doc)
+    where
+        Doc: Pretty + Located,
+    {
+        self.mut_requires().output();
+        self.print(doc);
+    }
+
     /// pretty debug
     fn debug<Doc>(&mut self, flag: DebugFlag, doc: &Doc)
     where
         Doc: Pretty + Located,
     {
         if self.is_active_debug(flag) {
-            self.print(doc);
+            self.output(doc);
         }
     }
     /// print error
@@ -70,14 +107,38 @@ pub trait Interpreter {
         E: ErrorReport + ErrorCode,
     {
         self.eprint(&error);
-        let code = if self.get_error_code() == 0 {
-            error.code()
-        } else {
-            1
+        let code = match self.get_error_code() {
+            0 => error.code(),
+            AssertionFailed::CODE if error.code() == AssertionFailed::CODE => AssertionFailed::CODE,
+            _ => 1,
         };
         self.set_error_code(code);
     }
 
+    /// check that no error prevents evaluation, a failed assertion leaves the
+    /// environment unchanged so next commands are still evaluated
+    fn can_eval(&self) -> bool {
+        matches!(self.get_error_code(), 0 | AssertionFailed::CODE)
+    }
+
+    /// print proof obligations of definitions `defs`, when they are emitted
+    fn emit_obligations(&mut self, defs: &[ast::ExpressionDefinition]) {
+        if !self.is_active_obligations() {
+            return;
+        }
+        for def in defs {
+            match typer::kernel::obligation(def) {
+                Ok(Some(obligation)) => self.output(&obligation),
+                Ok(None) => {}
+                Err(errs) => {
+                    for err in errs {
+                        self.fail(err)
+                    }
+                }
+            }
+        }
+    }
+
     /// run command expr definition
     fn run_expr_definition(
         &mut self,
@@ -89,8 +150,39 @@ pub trait Interpreter {
             .map(|def| {
                 self.print_summay(&def);
                 self.debug(DebugFlag::Typer, &def);
-                if self.get_error_code() == 0 {
-                    self.mut_vm().add_definition(&def)
+                self.emit_obligations(std::slice::from_ref(&def));
+                if self.can_eval() {
+                    if let Err(violation) = self.mut_vm().add_definition(&def) {
+                        self.fail(ContractViolation::from(violation))
+                    }
+                }
+            })
+            .unwrap_or_else(|errs| {
+                for err in errs {
+                    self.fail(err)
+                }
+            })
+    }
+
+    /// run command fixpoint, `docs` are documentations of each definition
+    fn run_fixpoint(
+        &mut self,
+        cst_def: cst::FixpointDefinition,
+        docs: Vec<Option<ast::Documentation>>,
+    ) {
+        let definitions = cst_def.definitions().zip(docs).collect::<Vec<_>>();
+        self.mut_typer()
+            .fixpoint(&definitions)
+            .map(|fixpoint| {
+                for def in fixpoint.definitions() {
+                    self.print_summay(def);
+                }
+                self.debug(DebugFlag::Typer, &fixpoint);
+                self.emit_obligations(fixpoint.definitions());
+                if self.can_eval() {
+                    if let Err(violation) = self.mut_vm().add_fixpoint(&fixpoint) {
+                        self.fail(ContractViolation::from(violation))
+                    }
                 }
             })
             .unwrap_or_else(|errs| {
@@ -102,22 +194,118 @@ pub trait Interpreter {
 
     /// run command type definition
     fn run_type_definition(&mut self, def: cst::TypeDefinition, doc: Option<ast::Documentation>) {
-        if let Err(errs) = self.mut_typer().type_definition(&def, doc) {
+        self.mut_typer()
+            .type_definition(&def, doc)
+            .map(|def| {
+                self.debug(DebugFlag::Typer, &def);
+                if self.can_eval() {
+                    self.mut_vm().add_type_definition(&def)
+                }
+            })
+            .unwrap_or_else(|errs| {
+                for err in errs {
+                    self.fail(err)
+                }
+            })
+    }
+
+    /// run command class
+    fn run_class(&mut self, def: cst::TypeDefinition, doc: Option<ast::Documentation>) {
+        self.mut_typer()
+            .class(&def, doc)
+            .map(|def| {
+                self.debug(DebugFlag::Typer, &def);
+                if self.can_eval() {
+                    self.mut_vm().add_type_definition(&def)
+                }
+            })
+            .unwrap_or_else(|errs| {
+                for err in errs {
+                    self.fail(err)
+                }
+            })
+    }
+
+    /// run command instance, its dictionary is evaluated once
+    fn run_instance(&mut self, def: cst::Instance) {
+        self.mut_typer()
+            .instance(&def)
+            .map(|def| {
+                self.debug(DebugFlag::Typer, &def);
+                if self.can_eval() {
+                    if let Err(violation) = self.mut_vm().add_definition(&def) {
+                        self.fail(ContractViolation::from(violation))
+                    }
+                }
+            })
+            .unwrap_or_else(|errs| {
+                for err in errs {
+                    self.fail(err)
+                }
+            })
+    }
+
+    /// run command theorem, a theorem without proof term starts a proof by tactics
+    fn run_theorem(&mut self, def: cst::Theorem, doc: Option<ast::Documentation>) {
+        match &def.proof {
+            Some((_, proof)) => self
+                .mut_typer()
+                .theorem(&def, proof, doc)
+                .map(|theorem| self.debug(DebugFlag::Typer, theorem.as_ref())),
+            None => self
+                .mut_typer()
+                .start_proof(&def, doc)
+                .map(|goals| self.print_goals(&goals)),
+        }
+        .unwrap_or_else(|errs| {
             for err in errs {
-                self.fail(err);
+                self.fail(err)
             }
+        })
+    }
+
+    /// run commands `Proof`, `Qed` and `Abort`
+    fn run_proof(&mut self, keyword: cst::command::ProofKeyword) {
+        use cst::command::ProofKeywordT;
+        let loc = keyword.loc();
+        match keyword.value {
+            ProofKeywordT::Proof => self.mut_typer().resume_proof(&loc),
+            ProofKeywordT::Qed => self
+                .mut_typer()
+                .qed(&loc)
+                .map(|theorem| self.debug(DebugFlag::Typer, theorem.as_ref())),
+            ProofKeywordT::Abort => self.mut_typer().abort(&loc),
         }
+        .unwrap_or_else(|errs| {
+            for err in errs {
+                self.fail(err)
+            }
+        })
+    }
+
+    /// run tactic on proof in progress
+    fn run_tactic(&mut self, keyword: cst::command::TacticKeyword, args: cst::tactic::TacticArgs) {
+        self.mut_typer()
+            .tactic(&keyword, &args)
+            .map(|goals| self.print_goals(&goals))
+            .unwrap_or_else(|errs| {
+                for err in errs {
+                    self.fail(err)
+                }
+            })
     }
 
     /// run command eval
     fn run_eval(&mut self, expr: cst::Expression) {
         self.mut_typer()
-            .expression(&expr)
+            .evaluation(&expr)
             .map(|expr| {
                 self.debug(DebugFlag::Typer, &expr);
-                if self.get_error_code() == 0 {
-                    let value = self.mut_vm().eval(&expr).unwrap();
-                    self.print(&value.with_loc(expr.loc()));
+                if self.can_eval() {
+                    match self.mut_vm().eval(&expr) {
+                        Ok(value) => self.output(&value.with_loc(expr.loc())),
+                        Err(violation) => self.fail(ContractViolation::from(violation)),
+                    }
                 }
             })
             .unwrap_or_else(|errs| {
@@ -130,10 +318,32 @@ pub trait Interpreter {
     /// run type of expression
     fn run_typeof(&mut self, expr: cst::Expression) {
         self.mut_typer()
-            .expression(&expr)
+            .type_of(&expr)
+            .map(|ty| self.output(&ty))
+            .unwrap_or_else(|errs| {
+                for err in errs {
+                    self.fail(err)
+                }
+            })
+    }
+
+    /// run command assert, fail when the expression evaluates to `false`
+    fn run_assert(&mut self, expr: cst::Expression) {
+        self.mut_typer()
+            .assertion(&expr)
             .map(|expr| {
-                let ty = expr.ty();
-                self.print(ty);
+                self.debug(DebugFlag::Typer, &expr);
+                if self.can_eval() {
+                    let vm = self.mut_vm();
+                    match vm.eval(&expr) {
+                        Ok(Value::Constant(Constant::Bool(false))) => {
+                            let err = AssertionFailed::new(&expr, vm);
+                            self.fail(err)
+                        }
+                        Ok(_) => {}
+                        Err(violation) => self.fail(ContractViolation::from(violation)),
+                    }
+                }
             })
             .unwrap_or_else(|errs| {
                 for err in errs {
@@ -144,7 +354,7 @@ pub trait Interpreter {
 
     fn run_help(&mut self, var: cst::help::Variable) {
         match self.mut_typer().help(&var) {
-            Ok(help) => self.print(&help),
+            Ok(help) => self.output(&help),
             Err(errs) => {
                 for err in errs {
                     self.fail(err)
@@ -159,24 +369,147 @@ pub trait Interpreter {
             "DebugLexer" => self.set_flag(b, Flag::Debug(DebugFlag::Lexer)),
             "DebugParser" => self.set_flag(b, Flag::Debug(DebugFlag::Parser)),
             "DebugTyper" => self.set_flag(b, Flag::Debug(DebugFlag::Typer)),
+            "Obligations" => {
+                self.set_flag(b, Flag::Obligations);
+                self.mut_vm().set_check_contracts(!b)
+            }
             _ => self.fail(UnknownOption::from(var)),
         }
     }
 
+    /// run commands `Module` and `End`
+    fn run_module(&mut self, keyword: cst::command::ModuleKeyword, name: cst::module::Name) {
+        use cst::command::ModuleKeywordT;
+        match keyword.value {
+            ModuleKeywordT::Module => {
+                self.mut_typer().module(&name);
+                Ok(())
+            }
+            ModuleKeywordT::End => self.mut_typer().end_module(&name),
+        }
+        .unwrap_or_else(|errs| {
+            for err in errs {
+                self.fail(err)
+            }
+        })
+    }
+
+    /// run command open
+    fn run_open(&mut self, name: cst::module::Name) {
+        self.mut_typer().open(&name).unwrap_or_else(|errs| {
+            for err in errs {
+                self.fail(err)
+            }
+        })
+    }
+
+    /// typer of a required file, in the environment of the standard library
+    /// and of the dependencies of the package being built
+    fn required_typer(&mut self) -> typer::Typer {
+        let (stdlib, _) = cache::stdlib();
+        let mut typer = self.mut_typer().required(stdlib);
+        for exports in self.mut_requires().dependencies() {
+            typer.import(exports);
+        }
+        typer
+    }
+
+    /// check `content` of required file `file` of canonical path `canonical`,
+    /// loaded from its cached interface when it and the files it depends on are unchanged,
+    /// get its error code and the definitions it exports
+    fn check_required(
+        &mut self,
+        file: PathBuf,
+        canonical: &Path,
+        content: String,
+    ) -> (i32, typer::Exports) {
+        let source_id = SourceId::File(file.clone());
+        let fingerprint = cache::fingerprint(canonical, &content);
+        let typer = self.required_typer();
+        if let Some((typer, depends)) =
+            cache::load_required(canonical, &file, &content, typer, self.mut_vm())
+        {
+            self.mut_requires().depend(&depends);
+            self.mut_requires().depend(&[fingerprint]);
+            return (0, typer.exports(&source_id));
+        }
+        let typer = self.required_typer();
+        let (code, typer) = self.run_required(file.clone(), content.clone(), typer);
+        // a file loaded from its interface is not run, it would not print its outputs
+        if code == 0 && !self.mut_requires().outputs() {
+            let depends = self.mut_requires().depends().to_vec();
+            cache::store_required(canonical, &file, &content, &depends, &typer, self.mut_vm());
+        }
+        self.mut_requires().depend(&[fingerprint]);
+        (code, typer.exports(&source_id))
+    }
+
+    /// run command require, each file is run once in the environment of the standard library,
+    /// the definitions it exports are added to the environment
+    fn run_require(&mut self, path: cst::module::Path) {
+        let file = self.source_dir().join(path.as_string());
+        let Ok(canonical) = file.canonicalize() else {
+            return self.fail(RequireNotFound::new(path, file));
+        };
+        match self.mut_requires().enter(canonical.clone(), file.clone()) {
+            Status::New => {}
+            Status::Loaded(exports) => return self.mut_typer().import(&exports),
+            Status::Cycle(cycle) => return self.fail(RequireCycle::new(path, cycle)),
+        }
+        let (code, exports) = match std::fs::read_to_string(&file) {
+            Ok(content) => self.check_required(file, &canonical, content),
+            Err(_) => {
+                self.fail(RequireNotFound::new(path.clone(), file));
+                (0, typer::Exports::default())
+            }
+        };
+        let exports = Rc::new(exports);
+        self.mut_typer().import(&exports);
+        self.mut_requires().leave(exports);
+        if code != 0 {
+            self.fail(RequireFailed::from(path))
+        }
+    }
+
     /// run command
     fn run_command(&mut self, cmd: cst::Command) {
-        match cmd.kind {
+        self.run_command_kind(cmd.kind)
+    }
+
+    /// run kind of command
+    fn run_command_kind(&mut self, kind: cst::CommandKind) {
+        match kind {
             cst::CommandKind::ExpressionDefinition { keyword, def } => {
                 self.run_expr_definition(*def, keyword.get_doc())
             }
+            cst::CommandKind::Fixpoint { keyword, def } => {
+                let docs = std::iter::once(keyword.get_doc())
+                    .chain(def.rest.iter().map(|(keyword, _)| keyword.get_doc()))
+                    .collect();
+                self.run_fixpoint(*def, docs)
+            }
             cst::CommandKind::TypeDefinition { keyword, def } => {
                 self.run_type_definition(def, keyword.get_doc())
             }
+            cst::CommandKind::Class { keyword, def } => self.run_class(def, keyword.get_doc()),
+            cst::CommandKind::Instance { def, .. } => self.run_instance(*def),
+            cst::CommandKind::Theorem { keyword, def } => self.run_theorem(*def, keyword.get_doc()),
+            cst::CommandKind::Proof { keyword } => self.run_proof(keyword),
+            cst::CommandKind::Tactic { keyword, args } => self.run_tactic(keyword, args),
             cst::CommandKind::Eval { expr, .. } => self.run_eval(expr),
             cst::CommandKind::TypeOf { expr, .. } => self.run_typeof(expr),
+            cst::CommandKind::Assert { expr, .. } => self.run_assert(expr),
             cst::CommandKind::Help { var, .. } => self.run_help(var),
             cst::CommandKind::Set { var, .. } => self.run_set(true, var),
             cst::CommandKind::UnSet { var, .. } => self.run_set(false, var),
+            cst::CommandKind::Module { keyword, name } => self.run_module(keyword, name),
+            cst::CommandKind::Open { name, .. } => self.run_open(name),
+            cst::CommandKind::Require { path, .. } => self.run_require(path),
+            cst::CommandKind::Private { kind, .. } => {
+                self.mut_typer().set_private(true);
+                self.run_command_kind(*kind);
+                self.mut_typer().set_private(false);
+            }
         }
     }
 
@@ -209,6 +542,16 @@ pub trait Interpreter {
         }
     }
 
+    /// run the interpreter on a whole file, its modules must be ended at its end
+    fn run_file(&mut self) {
+        self.run();
+        if let Err(errs) = self.mut_typer().end_of_file() {
+            for err in errs {
+                self.fail(err)
+            }
+        }
+    }
+
     /// run the interpreter
     fn run(&mut self) {
         if self.get_error_code() != 0 {
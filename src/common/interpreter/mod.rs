@@ -1,5 +1,7 @@
+pub mod cache;
 mod error;
 pub mod flag;
 mod interpret;
+pub mod require;
 
 pub use interpret::Interpreter;
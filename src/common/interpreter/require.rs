@@ -0,0 +1,183 @@
+use crate::typer::Exports;
+use crate::utils::location::SourceId;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// state of a required file
+pub enum Status {
+    /// file is not required yet
+    New,
+    /// file is already required, it is not run again, its exports are reused
+    Loaded(Rc<Exports>),
+    /// file is being required, paths of the cycle from the file to itself
+    Cycle(Vec<PathBuf>),
+}
+
+/// hash of the content of a file with its canonical path
+pub type Fingerprint = (u64, PathBuf);
+
+/// file being required
+#[derive(Debug)]
+struct Running {
+    /// canonical path
+    canonical: PathBuf,
+    /// path as displayed
+    path: PathBuf,
+    /// fingerprints of the files it depends on
+    depends: Vec<Fingerprint>,
+    /// whether it or a file it requires prints outputs
+    outputs: bool,
+}
+
+/// file already required
+#[derive(Debug)]
+struct Loaded {
+    /// definitions it exports
+    exports: Rc<Exports>,
+    /// fingerprints of the files it depends on
+    depends: Vec<Fingerprint>,
+    /// whether it or a file it requires prints outputs
+    outputs: bool,
+}
+
+/// files required by a program, files are identified by their canonical path
+#[derive(Debug, Default)]
+pub struct Requires {
+    /// files already run
+    loaded: HashMap<PathBuf, Loaded>,
+    /// files being run, the innermost last
+    running: Vec<Running>,
+    /// definitions of the dependencies of the package being built,
+    /// visible in every file besides the standard library
+    dependencies: Vec<Rc<Exports>>,
+    /// fingerprints of the files of the dependencies of the package being built
+    dependencies_depends: Vec<Fingerprint>,
+}
+
+impl Requires {
+    /// requires of program in file `path`, the file itself cannot be required
+    pub fn with_root(path: &Path) -> Self {
+        let mut requires = Self::default();
+        if let Ok(canonical) = path.canonicalize() {
+            requires.running.push(Running {
+                canonical,
+                path: path.to_path_buf(),
+                depends: Vec::new(),
+                outputs: false,
+            });
+        }
+        requires
+    }
+
+    /// start to require file `path`, its canonical path is `canonical`
+    pub fn enter(&mut self, canonical: PathBuf, path: PathBuf) -> Status {
+        if let Some(pos) = self
+            .running
+            .iter()
+            .position(|running| running.canonical == canonical)
+        {
+            let cycle = self.running[pos..]
+                .iter()
+                .map(|running| running.path.clone())
+                .chain(std::iter::once(path))
+                .collect();
+            return Status::Cycle(cycle);
+        }
+        if let Some(loaded) = self.loaded.get(&canonical) {
+            let exports = loaded.exports.clone();
+            let depends = loaded.depends.clone();
+            let outputs = loaded.outputs;
+            self.depend(&depends);
+            if outputs {
+                self.output();
+            }
+            return Status::Loaded(exports);
+        }
+        self.running.push(Running {
+            canonical,
+            path,
+            depends: self.dependencies_depends.clone(),
+            outputs: false,
+        });
+        Status::New
+    }
+
+    /// set the dependencies of the package being built to the files `canonicals`
+    /// already required
+    pub fn set_dependencies(&mut self, canonicals: &[PathBuf]) {
+        let loaded = canonicals
+            .iter()
+            .filter_map(|canonical| self.loaded.get(canonical));
+        self.dependencies = loaded.clone().map(|file| file.exports.clone()).collect();
+        self.dependencies_depends = Vec::new();
+        for file in loaded {
+            add_depends(&mut self.dependencies_depends, &file.depends);
+        }
+    }
+
+    /// get definitions of the dependencies of the package being built
+    pub fn dependencies(&self) -> &[Rc<Exports>] {
+        &self.dependencies
+    }
+
+    /// the innermost file depends on the files of fingerprints `depends`
+    pub fn depend(&mut self, depends: &[Fingerprint]) {
+        if let Some(running) = self.running.last_mut() {
+            add_depends(&mut running.depends, depends);
+        }
+    }
+
+    /// get fingerprints of the files the innermost file depends on
+    pub fn depends(&self) -> &[Fingerprint] {
+        self.running
+            .last()
+            .map_or(&[], |running| running.depends.as_slice())
+    }
+
+    /// the innermost file prints outputs
+    pub fn output(&mut self) {
+        if let Some(running) = self.running.last_mut() {
+            running.outputs = true;
+        }
+    }
+
+    /// whether the innermost file or a file it requires prints outputs
+    pub fn outputs(&self) -> bool {
+        self.running.last().is_some_and(|running| running.outputs)
+    }
+
+    /// end to require the innermost file, it exports `exports`,
+    /// the file requiring it depends on the same files and prints outputs if it does
+    pub fn leave(&mut self, exports: Rc<Exports>) {
+        if let Some(running) = self.running.pop() {
+            self.depend(&running.depends);
+            if running.outputs {
+                self.output();
+            }
+            let loaded = Loaded {
+                exports,
+                depends: running.depends,
+                outputs: running.outputs,
+            };
+            self.loaded.insert(running.canonical, loaded);
+        }
+    }
+}
+
+/// add fingerprints `depends` to `to`, each file once
+fn add_depends(to: &mut Vec<Fingerprint>, depends: &[Fingerprint]) {
+    for depend in depends {
+        if !to.contains(depend) {
+            to.push(depend.clone());
+        }
+    }
+}
+
+/// directory of source, paths of required files are relative to it
+pub fn source_dir(source_id: &SourceId) -> PathBuf {
+    match source_id {
+        SourceId::File(path) => path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        SourceId::Unknown | SourceId::Repl | SourceId::Url(_) => PathBuf::new(),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::utils::error::{ErrorCode, ErrorReport, Message};
 use crate::utils::location::{Located, Location, SourceId};
-use chumsky::error::Rich;
+use chumsky::error::{Rich, RichReason};
 
 pub struct Error<'src> {
     loc: Location,
@@ -38,6 +38,10 @@ impl ErrorReport for Error<'_> {
 
     fn text(&self) -> Option<Message> {
         let mut msg = Message::nil();
+        if let RichReason::Custom(reason) = self.err.reason() {
+            msg.add_text(format!("Lexer {reason}."));
+            return Some(msg);
+        }
         if self.err.expected().len() == 1 {
             msg.add_text("Lexer expected ");
             let expect_str = self.err.expected().next().unwrap().to_string();
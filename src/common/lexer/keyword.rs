@@ -0,0 +1,76 @@
+// ===========================================================================
+// Command keywords
+// ===========================================================================
+pub const DEF: &str = "Def";
+pub const DEFINITION: &str = "Definition";
+pub const FIXPOINT: &str = "Fixpoint";
+pub const REC: &str = "rec";
+pub const THEOREM: &str = "Theorem";
+pub const LEMMA: &str = "Lemma";
+pub const PROOF: &str = "Proof";
+pub const QED: &str = "Qed";
+pub const ABORT: &str = "Abort";
+pub const EVAL: &str = "Eval";
+pub const TYPE_OF: &str = "TypeOf";
+pub const HELP: &str = "Help";
+pub const TYPE: &str = "Type";
+pub const TY: &str = "Ty";
+pub const ASSERT: &str = "Assert";
+pub const SET: &str = "Set";
+pub const UNSET: &str = "Unset";
+pub const MODULE: &str = "Module";
+pub const END: &str = "End";
+pub const OPEN: &str = "Open";
+pub const REQUIRE: &str = "Require";
+pub const CLASS: &str = "Class";
+pub const INSTANCE: &str = "Instance";
+pub const PRIVATE: &str = "Private";
+
+// ===========================================================================
+// Tactic keywords
+// ===========================================================================
+pub const INTRO: &str = "intro";
+pub const APPLY: &str = "apply";
+pub const EXACT: &str = "exact";
+pub const EXISTS: &str = "exists";
+pub const SPLIT: &str = "split";
+pub const LEFT: &str = "left";
+pub const RIGHT: &str = "right";
+pub const REFLEXIVITY: &str = "reflexivity";
+
+/// keywords starting a command (tactics included), the parser recognises commands by them
+/// and the lexer ends a command at a dot before one of them
+pub const COMMANDS: &[&str] = &[
+    DEF,
+    DEFINITION,
+    FIXPOINT,
+    REC,
+    THEOREM,
+    LEMMA,
+    PROOF,
+    QED,
+    ABORT,
+    EVAL,
+    TYPE_OF,
+    HELP,
+    TYPE,
+    TY,
+    ASSERT,
+    SET,
+    UNSET,
+    MODULE,
+    END,
+    OPEN,
+    REQUIRE,
+    CLASS,
+    INSTANCE,
+    PRIVATE,
+    INTRO,
+    APPLY,
+    EXACT,
+    EXISTS,
+    SPLIT,
+    LEFT,
+    RIGHT,
+    REFLEXIVITY,
+];
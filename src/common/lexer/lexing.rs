@@ -1,8 +1,10 @@
-use super::{comment::Comment, meta::CommentOrLines, token, ErrorChumsky, Meta};
-use crate::utils::location::{Location, SourceId};
+use super::token::{MetaToken, Token};
+use super::{comment::Comment, keyword, meta::CommentOrLines, token, ErrorChumsky, Meta};
+use crate::utils::location::{Located as _, Location, SourceId};
+use crate::utils::rational::Rational;
 use chumsky::prelude::*;
 use chumsky::text::{newline, whitespace};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use std::rc::Rc;
 
 // ===========================================================================
@@ -175,6 +177,122 @@ pub fn number<'src>() -> impl Parser<'src, &'src str, BigUint, ErrorChumsky<'src
     choice((number_hex(), number_oct(), number_bin(), number_dec())).labelled("number")
 }
 
+/// lex sign directly followed by a number
+fn number_sign<'src>() -> impl Parser<'src, &'src str, token::NumberSign, ErrorChumsky<'src>> {
+    just('+')
+        .to(token::NumberSign::Plus)
+        .or(just('-').to(token::NumberSign::Minus))
+}
+
+/// lex signed number, the sign is directly followed by the number
+/// ```ebnf
+/// INTEGER := ("+" | "-") NUMBER
+/// ```
+pub fn integer<'src>(
+) -> impl Parser<'src, &'src str, (token::NumberSign, BigUint), ErrorChumsky<'src>> {
+    number_sign().then(number()).labelled("integer")
+}
+
+/// lex decimal digits after the point of a rational
+fn digits_dec<'src>() -> impl Parser<'src, &'src str, String, ErrorChumsky<'src>> {
+    digit()
+        .then(
+            just('_')
+                .repeated()
+                .ignore_then(digit())
+                .repeated()
+                .collect::<String>(),
+        )
+        .map(|(digit1, digits2)| format!("{digit1}{digits2}"))
+}
+
+/// largest exponent of scientific notation, larger powers of ten are too slow to compute
+const EXPONENT_MAX: i32 = 4096;
+
+/// lex exponent of scientific notation, at most [`EXPONENT_MAX`] in absolute value
+/// ```ebnf
+/// EXPONENT := ("e" | "E") ("+" | "-")? NUMBER_DEC
+/// ```
+fn exponent<'src>() -> impl Parser<'src, &'src str, i32, ErrorChumsky<'src>> {
+    let sign = just('+').to(1).or(just('-').to(-1)).or_not();
+    one_of("eE")
+        .ignore_then(sign)
+        .then(number_dec())
+        .validate(|(sign, n), e, emitter| match i32::try_from(n) {
+            Ok(n) if n <= EXPONENT_MAX => sign.unwrap_or(1) * n,
+            _ => {
+                emitter.emit(Rich::custom(e.span(), "exponent too large"));
+                0
+            }
+        })
+}
+
+/// lex rational number, in decimal or scientific notation, with an optional sign
+/// ```ebnf
+/// RATIONAL := ("+" | "-")?
+///     ( NUMBER_DEC "." NUMBER_DEC EXPONENT?
+///     | NUMBER_DEC EXPONENT )
+/// ```
+pub fn rational<'src>(
+) -> impl Parser<'src, &'src str, (Option<token::NumberSign>, Rational), ErrorChumsky<'src>> {
+    let with_point = number_dec()
+        .then_ignore(just('.'))
+        .then(digits_dec())
+        .then(exponent().or_not())
+        .map(|((integral, decimals), exponent)| {
+            let scale = BigInt::from(10u8).pow(decimals.len() as u32);
+            let decimals_value =
+                BigInt::parse_bytes(decimals.as_bytes(), 10).expect("Failed to parse number");
+            let mantissa = BigInt::from(integral) * scale + decimals_value;
+            Rational::scientific(mantissa, exponent.unwrap_or(0) - decimals.len() as i32)
+        });
+    let without_point = number_dec()
+        .then(exponent())
+        .map(|(mantissa, exponent)| Rational::scientific(BigInt::from(mantissa), exponent));
+    number_sign()
+        .or_not()
+        .then(with_point.or(without_point))
+        .labelled("rational")
+}
+
+/// a sign directly after an operand (without space) is an operator and not
+/// the sign of a number: `x-1` is `x - 1` while `f -1` is `f (-1)`
+pub fn split_signs(tokens: Vec<MetaToken>) -> Vec<MetaToken> {
+    let mut res: Vec<MetaToken> = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let after_operand = res.last().is_some_and(|prev| {
+            let is_operand = matches!(
+                prev.value,
+                Token::Identifier(_)
+                    | Token::Number(_)
+                    | Token::Integer(_, _)
+                    | Token::Rational(_, _)
+                    | Token::Character(_)
+                    | Token::Operator(token::Operator::RParen)
+            );
+            is_operand && prev.loc().to_simple_span().end == token.loc().to_simple_span().start
+        });
+        let (sign, unsigned) = match &token.value {
+            Token::Integer(sign, n) if after_operand => (*sign, Token::Number(n.clone())),
+            Token::Rational(Some(sign), q) if after_operand => {
+                (*sign, Token::Rational(None, q.clone()))
+            }
+            _ => {
+                res.push(token);
+                continue;
+            }
+        };
+        // no space before the sign, so no comment or lines to keep
+        let span = token.loc().to_simple_span();
+        let id = token.loc().id().clone();
+        let sign_loc = Location::new(id.clone(), span.start, span.start + 1);
+        let number_loc = Location::new(id, span.start + 1, span.end);
+        res.push(Meta::new(Token::Operator(sign.operator()), sign_loc));
+        res.push(Meta::new(unsigned, number_loc));
+    }
+    res
+}
+
 // ===========================================================================
 // Character
 // ===========================================================================
@@ -270,6 +388,33 @@ pub fn character<'src>() -> impl Parser<'src, &'src str, char, ErrorChumsky<'src
         .labelled("character")
 }
 
+// ===========================================================================
+// String
+// ===========================================================================
+
+/// lex string
+/// ```ebnf
+/// STRING_LIT := ESCAPE_CHAR | <ANY> except "\"" and "\\"
+/// STRING := "\"" STRING_LIT* "\""
+/// ```
+pub fn string<'src>() -> impl Parser<'src, &'src str, String, ErrorChumsky<'src>> {
+    let quote = just('"').labelled("\"");
+    let string_lit = choice((
+        escape_char(),
+        any().filter(|c: &char| *c != '"' && *c != '\\'),
+    ));
+    quote
+        .ignore_then(string_lit.repeated().collect::<String>())
+        .then(quote.or_not())
+        .validate(|(s, closing), e, emitter| {
+            if closing.is_none() {
+                emitter.emit(Rich::custom(e.span(), "string not closed"));
+            }
+            s
+        })
+        .labelled("string")
+}
+
 // ===========================================================================
 // Keyword
 // ===========================================================================
@@ -277,15 +422,64 @@ pub fn character<'src>() -> impl Parser<'src, &'src str, char, ErrorChumsky<'src
 // ===========================================================================
 // Operator
 // ===========================================================================
-/// lex operators
+/// lex projections directly after an operand (without space), a dot is a projection
+/// when it is directly followed by an identifier that is not a command keyword
+/// ```ebnf
+/// PROJECTION := "." &(IDENTIFIER - COMMAND)
+/// ```
+pub fn projections<'src>(
+    source_id: SourceId,
+    offset: usize,
+) -> impl Parser<'src, &'src str, Vec<MetaToken>, ErrorChumsky<'src>> {
+    let field = identifier().filter(|id| !keyword::COMMANDS.contains(&id.as_str()));
+    just('.')
+        .then(field.rewind())
+        .map_with(move |_, e| {
+            let span: SimpleSpan = e.span();
+            let loc = Location::new(source_id.clone(), span.start, span.end).with_offset(offset);
+            Meta::new(Token::Operator(token::Operator::Projection), loc)
+        })
+        .repeated()
+        .collect()
+}
+
+/// lex operators, projections are lexed after operands (see [`projections`])
 pub fn operator<'src>() -> impl Parser<'src, &'src str, token::Operator, ErrorChumsky<'src>> {
     choice((
         just("?:").to(token::Operator::TypeOf),
         just("?").to(token::Operator::Help),
         just(":=").to(token::Operator::EqDef),
+        just("-->").to(token::Operator::Implies),
+        just("->").to(token::Operator::Arrow),
+        just("=>").to(token::Operator::FatArrow),
+        just("<>").to(token::Operator::NotEqual),
+        just("<=").to(token::Operator::LessEqual),
+        just("<-").to(token::Operator::Assign),
+        just(">=").to(token::Operator::GreaterEqual),
+        just("&&").to(token::Operator::And),
+        just("||").to(token::Operator::Or),
+        just("/\\").to(token::Operator::Conj),
+        just("\\/").to(token::Operator::Disj),
         just(':').to(token::Operator::Colon),
         just('$').to(token::Operator::Eval),
-        just('(').to(token::Operator::LParen),
-        just(')').to(token::Operator::RParen),
+        choice((
+            just('(').to(token::Operator::LParen),
+            just(')').to(token::Operator::RParen),
+            just('[').to(token::Operator::LBracket),
+            just(']').to(token::Operator::RBracket),
+            just('!').to(token::Operator::Bang),
+            just('{').to(token::Operator::LBrace),
+            just('}').to(token::Operator::RBrace),
+            just(';').to(token::Operator::Semicolon),
+        )),
+        just(',').to(token::Operator::Comma),
+        just('*').to(token::Operator::Star),
+        just('|').to(token::Operator::Bar),
+        just('<').to(token::Operator::Less),
+        just('>').to(token::Operator::Greater),
+        just('+').to(token::Operator::Plus),
+        just('-').to(token::Operator::Minus),
+        just('/').to(token::Operator::Slash),
+        just('=').to(token::Operator::Equal),
     ))
 }
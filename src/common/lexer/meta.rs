@@ -1,5 +1,5 @@
 use super::comment::Comment;
-use crate::parser::cst::{AsCharacter, AsIdentifier, AsNumber};
+use crate::parser::cst::{AsCharacter, AsIdentifier, AsInteger, AsNumber, AsRational, AsString};
 use crate::typer::ast::Documentation;
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
@@ -75,6 +75,19 @@ impl<T> Meta<T> {
         }
     }
 
+    /// merge value of `other` into value, location is extended up to `other`
+    /// and items before `other` are dropped
+    pub fn merge<U, F>(self, other: Meta<U>, f: F) -> Self
+    where
+        F: FnOnce(T, U) -> T,
+    {
+        Meta {
+            value: f(self.value, other.value),
+            before: self.before,
+            loc: self.loc.union(other.loc),
+        }
+    }
+
     /// just pretty meta
     pub fn pretty_meta(&self, theme: &Theme) -> Doc<'_> {
         let mut last_is_comment = false;
@@ -170,6 +183,24 @@ where
     }
 }
 
+impl<T> AsInteger for Meta<T>
+where
+    T: AsInteger,
+{
+    fn as_integer(&self) -> &num_bigint::BigInt {
+        self.value.as_integer()
+    }
+}
+
+impl<T> AsRational for Meta<T>
+where
+    T: AsRational,
+{
+    fn as_rational(&self) -> &crate::utils::rational::Rational {
+        self.value.as_rational()
+    }
+}
+
 impl<T> AsCharacter for Meta<T>
 where
     T: AsCharacter,
@@ -178,3 +209,12 @@ where
         self.value.as_character()
     }
 }
+
+impl<T> AsString for Meta<T>
+where
+    T: AsString,
+{
+    fn as_string(&self) -> &str {
+        self.value.as_string()
+    }
+}
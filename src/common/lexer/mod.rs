@@ -4,6 +4,7 @@ use lexing::WithMeta as _;
 
 pub mod comment;
 pub mod error;
+pub mod keyword;
 pub mod lexing;
 pub mod meta;
 pub mod token;
@@ -22,13 +23,27 @@ pub fn lexer<'src>(
 ) -> impl Parser<'src, &'src str, Vec<MetaToken>, ErrorChumsky<'src>> {
     use token::Token;
 
-    let token = choice((
-        lexing::operator().map(Token::Operator),
+    // an operand may be directly followed by projections
+    let operand = choice((
+        lexing::rational().map(|(sign, q)| Token::Rational(sign, q)),
+        lexing::integer().map(|(sign, n)| Token::Integer(sign, n)),
         lexing::identifier().map(Token::Identifier),
         lexing::number().map(Token::Number),
         lexing::character().map(Token::Character),
+        lexing::string().map(Token::String),
+        just(')').to(Token::Operator(token::Operator::RParen)),
+        just('}').to(Token::Operator(token::Operator::RBrace)),
     ))
-    .with_meta(source_id.clone(), offset);
+    .with_meta(source_id.clone(), offset)
+    .then(lexing::projections(source_id.clone(), offset))
+    .map(|(operand, mut projections)| {
+        projections.insert(0, operand);
+        projections
+    });
+    let operator = lexing::operator()
+        .map(Token::Operator)
+        .with_meta(source_id.clone(), offset)
+        .map(|operator| vec![operator]);
 
     let token_dot = just('.')
         .to(Token::Operator(token::Operator::Dot))
@@ -39,13 +54,15 @@ pub fn lexer<'src>(
         .to(Token::EndOfInput)
         .with_meta(source_id.clone(), offset);
 
-    token
+    operand
+        .or(operator)
         .repeated()
         .collect::<Vec<_>>()
         .then(choice((token_dot, token_end)))
-        .map(move |(mut tokens, end)| {
+        .map(move |(tokens, end)| {
+            let mut tokens = tokens.concat();
             tokens.push(end);
-            tokens
+            lexing::split_signs(tokens)
         })
 }
 
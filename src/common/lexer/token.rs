@@ -1,3 +1,4 @@
+use crate::utils::rational::Rational;
 use num_bigint::BigUint;
 
 use crate::utils::{
@@ -18,6 +19,34 @@ pub enum Operator {
     TypeOf,
     Help,
     Dot,
+    Arrow,
+    FatArrow,
+    Comma,
+    Star,
+    Bar,
+    Less,
+    Greater,
+    Plus,
+    Minus,
+    Slash,
+    Equal,
+    NotEqual,
+    LessEqual,
+    GreaterEqual,
+    And,
+    Or,
+    Conj,
+    Disj,
+    Implies,
+    Assign,
+    Bang,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    Semicolon,
+    /// dot followed by a field name, not the end of a command
+    Projection,
 }
 
 impl std::fmt::Display for Operator {
@@ -31,6 +60,33 @@ impl std::fmt::Display for Operator {
             Operator::TypeOf => write!(f, "?:"),
             Operator::Help => write!(f, "?"),
             Operator::Dot => write!(f, "."),
+            Operator::Arrow => write!(f, "->"),
+            Operator::FatArrow => write!(f, "=>"),
+            Operator::Comma => write!(f, ","),
+            Operator::Star => write!(f, "*"),
+            Operator::Bar => write!(f, "|"),
+            Operator::Less => write!(f, "<"),
+            Operator::Greater => write!(f, ">"),
+            Operator::Plus => write!(f, "+"),
+            Operator::Minus => write!(f, "-"),
+            Operator::Slash => write!(f, "/"),
+            Operator::Equal => write!(f, "="),
+            Operator::NotEqual => write!(f, "<>"),
+            Operator::LessEqual => write!(f, "<="),
+            Operator::GreaterEqual => write!(f, ">="),
+            Operator::And => write!(f, "&&"),
+            Operator::Or => write!(f, "||"),
+            Operator::Conj => write!(f, "/\\"),
+            Operator::Disj => write!(f, "\\/"),
+            Operator::Implies => write!(f, "-->"),
+            Operator::Assign => write!(f, "<-"),
+            Operator::Bang => write!(f, "!"),
+            Operator::LBracket => write!(f, "["),
+            Operator::RBracket => write!(f, "]"),
+            Operator::LBrace => write!(f, "{{"),
+            Operator::RBrace => write!(f, "}}"),
+            Operator::Semicolon => write!(f, ";"),
+            Operator::Projection => write!(f, ".<field>"),
         }
     }
 }
@@ -41,11 +97,45 @@ impl Pretty for Operator {
     }
 }
 
+/// sign written directly before a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberSign {
+    Plus,
+    Minus,
+}
+
+impl NumberSign {
+    /// operator written as the sign
+    pub fn operator(self) -> Operator {
+        match self {
+            NumberSign::Plus => Operator::Plus,
+            NumberSign::Minus => Operator::Minus,
+        }
+    }
+
+    /// apply sign to a number
+    pub fn apply<T: std::ops::Neg<Output = T>>(self, n: T) -> T {
+        match self {
+            NumberSign::Plus => n,
+            NumberSign::Minus => -n,
+        }
+    }
+}
+
+impl std::fmt::Display for NumberSign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.operator())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
     Identifier(String),
     Number(BigUint),
+    Integer(NumberSign, BigUint),
+    Rational(Option<NumberSign>, Rational),
     Character(char),
+    String(String),
     Operator(Operator),
     EndOfInput,
 }
@@ -55,7 +145,11 @@ impl std::fmt::Display for Token {
         match self {
             Token::Identifier(s) => write!(f, "{s}"),
             Token::Number(n) => write!(f, "{n}"),
+            Token::Integer(sign, n) => write!(f, "{sign}{n}"),
+            Token::Rational(Some(sign), q) => write!(f, "{sign}{q}"),
+            Token::Rational(None, q) => write!(f, "{q}"),
             Token::Character(c) => write!(f, "'{c}'"),
+            Token::String(s) => write!(f, "{s:?}"),
             Token::Operator(op) => write!(f, "{op}"),
             Token::EndOfInput => write!(f, "end of input"),
         }
@@ -74,11 +168,28 @@ impl Pretty for Token {
                 .append(theme.number(n))
                 .append(Doc::text(")"))
                 .group(),
+            Token::Integer(sign, n) => Doc::nil()
+                .append(Doc::text("INTEGER("))
+                .append(Doc::text(sign.to_string()))
+                .append(theme.number(n))
+                .append(Doc::text(")"))
+                .group(),
+            Token::Rational(sign, q) => Doc::nil()
+                .append(Doc::text("RATIONAL("))
+                .append(Doc::text(sign.map(|s| s.to_string()).unwrap_or_default()))
+                .append(q.pretty(theme))
+                .append(Doc::text(")"))
+                .group(),
             Token::Character(c) => Doc::nil()
                 .append(Doc::text("CHARACTER('"))
                 .append(theme.character(*c))
                 .append(Doc::text("')"))
                 .group(),
+            Token::String(s) => Doc::nil()
+                .append(Doc::text("STRING("))
+                .append(theme.string(s))
+                .append(Doc::text(")"))
+                .group(),
             Token::Operator(op) => Doc::nil()
                 .append(Doc::text("OPERATOR("))
                 .append(op.pretty(theme))
@@ -45,7 +45,7 @@ impl Backend {
             let text = params.text.clone();
             tokio::task::spawn_blocking(move || {
                 let mut interpreter = Interpreter::new(uri, text);
-                interpreter.run();
+                interpreter.run_file();
                 let document = interpreter.document();
                 let diags = interpreter.diagnostics().to_vec();
                 (document, diags)
@@ -27,14 +27,20 @@ struct SymbolRange {
 #[derive(Debug, Clone)]
 pub struct SymbolInfo {
     pub symbol: Symbol,
+    /// modules exporting the definition, outermost first
+    pub path: Vec<String>,
     /// documentation
     pub doc: Option<MarkedString>,
     /// kind of identifier
     pub kind: IdentifierKind,
+    /// type variables bound by definition
+    pub binders: Vec<String>,
     /// type of indentifier
     pub ty: String,
-    /// definition location
-    pub def_range: Range,
+    /// clauses of contract of definition
+    pub contract: Vec<String>,
+    /// definition location, `None` when defined in another file
+    pub def_range: Option<Range>,
     /// location of references
     pub refs_range: Vec<Range>,
 }
@@ -67,7 +73,9 @@ impl Document {
     pub fn add_symbol(&mut self, symbol_info: SymbolInfo) {
         let symbol = symbol_info.symbol.clone();
 
-        self.add_symbol_range(symbol.clone(), symbol_info.def_range);
+        if let Some(range) = symbol_info.def_range {
+            self.add_symbol_range(symbol.clone(), range);
+        }
         for range in &symbol_info.refs_range {
             self.add_symbol_range(symbol.clone(), *range);
         }
@@ -101,12 +109,35 @@ impl Document {
         let symbol_info = self.symbols.get(&range.symbol)?;
         let ty_string = {
             let op = match symbol_info.kind {
-                IdentifierKind::Expr => ":",
+                IdentifierKind::Expr | IdentifierKind::Theorem => ":",
                 IdentifierKind::Type => ":=",
             };
+            let binders = match symbol_info.kind {
+                _ if symbol_info.binders.is_empty() => String::new(),
+                IdentifierKind::Expr => format!(" <{}>", symbol_info.binders.join(" ")),
+                IdentifierKind::Type | IdentifierKind::Theorem => {
+                    format!(" {}", symbol_info.binders.join(" "))
+                }
+            };
             MarkedString::from_language_code(
                 "startlang".to_string(),
-                format!("{} {} {}", range.symbol.name(), op, symbol_info.ty.clone()),
+                format!(
+                    "{}{} {} {}{}",
+                    symbol_info
+                        .path
+                        .iter()
+                        .map(|module| format!("{module}."))
+                        .collect::<String>()
+                        + range.symbol.name(),
+                    binders,
+                    op,
+                    symbol_info.ty.clone(),
+                    symbol_info
+                        .contract
+                        .iter()
+                        .map(|clause| format!("\n  {clause}"))
+                        .collect::<String>()
+                ),
             )
         };
         let contents = match &symbol_info.doc {
@@ -147,12 +178,15 @@ mod tests {
 
         let symbol_info = SymbolInfo {
             symbol: symbol.clone(),
+            path: Vec::new(),
             doc: Some(MarkedString::from_markdown(
                 "This is my variable".to_string(),
             )),
             kind: IdentifierKind::Expr,
+            binders: Vec::new(),
             ty: "Nat".to_string(),
-            def_range: Range {
+            contract: Vec::new(),
+            def_range: Some(Range {
                 start: Position {
                     line: 0,
                     character: 0,
@@ -161,7 +195,7 @@ mod tests {
                     line: 0,
                     character: 5,
                 },
-            },
+            }),
             refs_range: vec![Range {
                 start: Position {
                     line: 1,
@@ -202,4 +236,89 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_document_hover_contract() {
+        let mut doc = Document::default();
+        let id_builder = IdentifierBuilder::default();
+        let id = id_builder.get("f");
+        let symbol = Arc::new(id.as_ref().clone());
+        let range = Range {
+            start: Position {
+                line: 0,
+                character: 0,
+            },
+            end: Position {
+                line: 0,
+                character: 1,
+            },
+        };
+
+        doc.add_symbol(SymbolInfo {
+            symbol,
+            path: Vec::new(),
+            doc: None,
+            kind: IdentifierKind::Expr,
+            binders: Vec::new(),
+            ty: "ℕ -> ℕ".to_string(),
+            contract: vec!["requires x > 0".to_string(), "ensures r >= x".to_string()],
+            def_range: Some(range),
+            refs_range: Vec::new(),
+        });
+
+        let hover = doc.get_hover(&Position {
+            line: 0,
+            character: 0,
+        });
+        assert_eq!(
+            hover.unwrap().contents,
+            HoverContents::Scalar(MarkedString::from_language_code(
+                "startlang".to_string(),
+                "f : ℕ -> ℕ\n  requires x > 0\n  ensures r >= x".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_document_hover_module() {
+        let mut doc = Document::default();
+        let mut id_builder = IdentifierBuilder::default();
+        id_builder.enter("M");
+        let id = id_builder.build("x");
+        let symbol = Arc::new(id.as_ref().clone());
+        let range = Range {
+            start: Position {
+                line: 0,
+                character: 0,
+            },
+            end: Position {
+                line: 0,
+                character: 3,
+            },
+        };
+
+        doc.add_symbol(SymbolInfo {
+            symbol,
+            path: id_builder.path(),
+            doc: None,
+            kind: IdentifierKind::Expr,
+            binders: Vec::new(),
+            ty: "ℕ".to_string(),
+            contract: Vec::new(),
+            def_range: Some(range),
+            refs_range: Vec::new(),
+        });
+
+        let hover = doc.get_hover(&Position {
+            line: 0,
+            character: 1,
+        });
+        assert_eq!(
+            hover.unwrap().contents,
+            HoverContents::Scalar(MarkedString::from_language_code(
+                "startlang".to_string(),
+                "M.x : ℕ".to_string()
+            ))
+        );
+    }
 }
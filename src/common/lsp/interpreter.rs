@@ -1,19 +1,21 @@
 use super::backend::Backend;
 use super::document::Document;
 use super::position_memo::PositionMemo;
+use crate::interpreter::cache;
 use crate::interpreter::flag::{DebugFlag, Flag};
+use crate::interpreter::require::Requires;
 use crate::interpreter::{self, Interpreter as _};
 use crate::lsp::document::SymbolInfo;
 use crate::typer::Typer;
 use crate::utils::error::{ErrorCode, ErrorReport};
 use crate::utils::location::{Located, SourceId};
-use crate::utils::pretty::Pretty;
+use crate::utils::pretty::{self, Pretty};
 use crate::utils::theme::{MessageTheme, Theme};
 use crate::vm;
 use ariadne::Span as _;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tower_lsp::lsp_types::{Diagnostic, Url};
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticRelatedInformation, Url};
 
 #[derive(Debug)]
 pub struct Interpreter {
@@ -24,14 +26,25 @@ pub struct Interpreter {
     debug_lexer: bool,
     debug_parser: bool,
     debug_typer: bool,
+    obligations: bool,
+    requires: Requires,
     diagnostics: Vec<Diagnostic>,
+    /// errors of required files, related to the next error
+    required_errors: Vec<DiagnosticRelatedInformation>,
     position_memo: PositionMemo,
 }
 
 impl Interpreter {
     pub fn new(url: Url, content: String) -> Self {
-        let mut interpreter = Interpreter::stdlib();
-        interpreter.run();
+        let (typer, vm) = cache::stdlib();
+        let mut interpreter = Interpreter {
+            typer,
+            vm,
+            ..Interpreter::stdlib()
+        };
+        if let Ok(path) = url.to_file_path() {
+            interpreter.requires = Requires::with_root(&path);
+        }
         interpreter.source_id = SourceId::Url(url.to_string());
         interpreter.position_memo = PositionMemo::new(content);
         interpreter
@@ -48,7 +61,19 @@ impl Interpreter {
             debug_lexer: false,
             debug_parser: false,
             debug_typer: false,
+            obligations: false,
+            requires: Requires::default(),
             diagnostics: Vec::new(),
+            required_errors: Vec::new(),
+        }
+    }
+
+    /// get url of source
+    fn url(&self) -> Option<Url> {
+        match &self.source_id {
+            SourceId::Url(url) => url.parse().ok(),
+            SourceId::File(path) => Url::from_file_path(path.canonicalize().ok()?).ok(),
+            SourceId::Unknown | SourceId::Repl => None,
         }
     }
 
@@ -65,25 +90,49 @@ impl Interpreter {
         let mut document = Document::default();
         let env = self.typer.env();
         for info in env.iter() {
-            if info.loc_def.id() != self.source_id() {
-                //TODO: include location from other files
+            let refs_range = info
+                .loc_refs
+                .iter()
+                .filter(|loc| loc.id() == &self.source_id)
+                .map(|loc| self.position_memo.range(loc))
+                .collect::<Vec<_>>();
+            let def_range = if info.loc_def.id() == self.source_id() {
+                Some(self.position_memo.range(&info.loc_def))
+            } else if refs_range.is_empty() {
                 continue;
-            }
+            } else {
+                None
+            };
             document.add_symbol(SymbolInfo {
                 symbol: Arc::new(info.id.as_ref().clone()),
+                path: info.path.clone(),
                 doc: info
                     .doc
                     .clone()
                     .map(|doc| doc.to_string())
                     .map(MarkedString::from_markdown),
                 kind: info.kind,
-                ty: info.ty.make_string(&theme),
-                def_range: self.position_memo.range(&info.loc_def),
-                refs_range: info
-                    .loc_refs
+                binders: info
+                    .binders
                     .iter()
-                    .map(|loc| self.position_memo.range(loc))
-                    .collect::<Vec<_>>(),
+                    .map(|id| id.name().to_string())
+                    .collect(),
+                ty: match env.get_statement(&info.id) {
+                    Some(statement) => statement.make_string(&theme),
+                    None => info.ty.make_string(&theme),
+                },
+                contract: env
+                    .get_contract(&info.id)
+                    .map(|contract| {
+                        contract
+                            .clauses()
+                            .iter()
+                            .map(|clause| clause.make_string(&theme))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                def_range,
+                refs_range,
             });
         }
         document
@@ -123,11 +172,60 @@ impl interpreter::Interpreter for Interpreter {
         &mut self.vm
     }
 
+    fn mut_requires(&mut self) -> &mut Requires {
+        &mut self.requires
+    }
+
+    fn source_dir(&self) -> PathBuf {
+        let path = match &self.source_id {
+            SourceId::Url(url) => url
+                .parse::<Url>()
+                .ok()
+                .and_then(|url| url.to_file_path().ok()),
+            SourceId::File(path) => Some(path.clone()),
+            SourceId::Unknown | SourceId::Repl => None,
+        };
+        path.and_then(|path| path.parent().map(PathBuf::from))
+            .unwrap_or_default()
+    }
+
+    fn run_required(&mut self, path: PathBuf, content: String, typer: Typer) -> (i32, Typer) {
+        let mut required = Interpreter {
+            source_id: SourceId::File(path),
+            position_memo: PositionMemo::new(content),
+            typer,
+            vm: std::mem::take(&mut self.vm),
+            requires: std::mem::take(&mut self.requires),
+            ..Interpreter::stdlib()
+        };
+        required.run_file();
+        self.vm = std::mem::take(&mut required.vm);
+        self.requires = std::mem::take(&mut required.requires);
+        if let Some(uri) = required.url() {
+            let errors = required.diagnostics.iter().filter(|diag| {
+                diag.severity == Some(tower_lsp::lsp_types::DiagnosticSeverity::ERROR)
+            });
+            for diag in errors {
+                self.required_errors.push(DiagnosticRelatedInformation {
+                    location: tower_lsp::lsp_types::Location {
+                        uri: uri.clone(),
+                        range: diag.range,
+                    },
+                    message: diag.message.clone(),
+                });
+                self.required_errors
+                    .extend(diag.related_information.iter().flatten().cloned());
+            }
+        }
+        (required.err_code, std::mem::take(&mut required.typer))
+    }
+
     fn set_flag(&mut self, b: bool, flag: Flag) {
         match flag {
             Flag::Debug(DebugFlag::Lexer) => self.debug_lexer = b,
             Flag::Debug(DebugFlag::Parser) => self.debug_parser = b,
             Flag::Debug(DebugFlag::Typer) => self.debug_typer = b,
+            Flag::Obligations => self.obligations = b,
         }
     }
 
@@ -139,6 +237,10 @@ impl interpreter::Interpreter for Interpreter {
         }
     }
 
+    fn is_active_obligations(&self) -> bool {
+        self.obligations
+    }
+
     fn print<Doc>(&mut self, doc: &Doc)
     where
         Doc: Pretty + Located,
@@ -167,6 +269,8 @@ impl interpreter::Interpreter for Interpreter {
 
     fn print_summay(&self, _: &crate::typer::ast::ExpressionDefinition) {}
 
+    fn print_goals(&self, _: &crate::typer::tactic::Goals) {}
+
     fn eprint<E>(&mut self, err: &E)
     where
         E: ErrorReport + ErrorCode,
@@ -178,25 +282,23 @@ impl interpreter::Interpreter for Interpreter {
             start: self.position_memo.position(loc.start()),
             end: self.position_memo.position(loc.end()),
         };
-        let message = err
-            .text()
-            .map(|msg| msg.make_string(&theme))
-            .unwrap_or_else(|| err.head().make_string(&theme));
-
-        let related_information = err
-            .note()
-            .map(|msg| {
-                let uri = if let SourceId::Url(uri) = self.source_id.clone() {
-                    uri.parse().unwrap()
-                } else {
-                    unreachable!("source id is url")
-                };
-                DiagnosticRelatedInformation {
-                    location: Location { uri, range },
-                    message: msg.make_string(&theme),
-                }
+        // text and note name the same unknown types the same way
+        let (text, note) = pretty::message(|| {
+            let text = err.text().map(|msg| msg.make_string(&theme));
+            (text, err.note().map(|msg| msg.make_string(&theme)))
+        });
+        let message = text.unwrap_or_else(|| err.head().make_string(&theme));
+
+        let related_information = note
+            .zip(self.url())
+            .map(|(msg, uri)| DiagnosticRelatedInformation {
+                location: Location { uri, range },
+                message: msg,
             })
-            .map(|info| vec![info]);
+            .into_iter()
+            .chain(std::mem::take(&mut self.required_errors))
+            .collect::<Vec<_>>();
+        let related_information = Some(related_information).filter(|infos| !infos.is_empty());
 
         let diag = Diagnostic {
             range,
@@ -17,21 +17,24 @@ impl PositionMemo {
         }
     }
 
-    /// get position with binary search
+    /// get position with binary search,
+    /// character is counted in utf-16 code units as expected by lsp
     fn get(&mut self, offset: usize) -> Position {
         let line = self
             .lines_offset
             .binary_search_by(|&start| start.cmp(&offset))
             .unwrap_or_else(|x| x - 1);
 
-        let col = offset - self.lines_offset[line];
+        let col = self.content[self.lines_offset[line]..offset]
+            .encode_utf16()
+            .count();
         Position {
             line: line as u32,
             character: col as u32,
         }
     }
 
-    /// compute position wich is after the last line computed
+    /// compute lines until the one of offset, then get its position
     fn compute(&mut self, offset: usize) -> Position {
         debug_assert!(
             offset <= self.content.len(),
@@ -40,25 +43,15 @@ impl PositionMemo {
         );
 
         let last_offset = self.lines_offset.last().cloned().unwrap();
-        let mut line = self.lines_offset.len() - 1;
-        let mut col = 0;
-        for (k, chr) in (last_offset..).zip(self.content[last_offset..].chars()) {
-            if offset > k {
-                col += 1;
-            }
+        for (k, chr) in self.content[last_offset..].char_indices() {
             if chr == '\n' {
-                self.lines_offset.push(k + 1);
-                if offset <= k {
+                self.lines_offset.push(last_offset + k + 1);
+                if offset <= last_offset + k {
                     break;
                 }
-                line += 1;
-                col = 0;
             }
         }
-        Position {
-            line: line as u32,
-            character: col as u32,
-        }
+        self.get(offset)
     }
 
     pub fn position(&mut self, offset: usize) -> Position {
@@ -164,4 +157,31 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn position_memo_unicode() {
+        // offsets are in bytes, characters in utf-16 code units
+        let mut memo = PositionMemo::new("α β\nγδ 𝔹 ε".to_string());
+        assert_eq!(
+            memo.position(3),
+            Position {
+                line: 0,
+                character: 2
+            }
+        );
+        assert_eq!(
+            memo.position(10),
+            Position {
+                line: 1,
+                character: 2
+            }
+        );
+        assert_eq!(
+            memo.position(15),
+            Position {
+                line: 1,
+                character: 5
+            }
+        );
+    }
 }
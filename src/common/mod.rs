@@ -1,3 +1,4 @@
+pub mod build;
 pub mod file_interpreter;
 pub mod format;
 pub mod interpreter;
@@ -1,4 +1,8 @@
-use super::{expression, help, operator, Expression, ExpressionDefinition, TypeDefinition};
+use super::{
+    expression, help, module, operator, tactic, Expression, ExpressionDefinition,
+    FixpointDefinition, Instance, Theorem, TypeDefinition,
+};
+use crate::lexer::keyword;
 use crate::lexer::meta::Meta;
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
@@ -23,6 +27,44 @@ impl Pretty for DefinitionKeywordT {
     }
 }
 
+// ============================================================================
+// Fixpoint Keyword
+// ============================================================================
+#[derive(Debug)]
+pub enum FixpointKeywordT {
+    Fixpoint,
+    Rec,
+}
+pub type FixpointKeyword = Meta<FixpointKeywordT>;
+
+impl Pretty for FixpointKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            FixpointKeywordT::Fixpoint => theme.keyword(&"Fixpoint"),
+            FixpointKeywordT::Rec => theme.keyword(&"rec"),
+        }
+    }
+}
+
+// ============================================================================
+// Mutual Keyword
+// ============================================================================
+#[derive(Debug)]
+pub enum MutualKeywordT {
+    With,
+    And,
+}
+pub type MutualKeyword = Meta<MutualKeywordT>;
+
+impl Pretty for MutualKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            MutualKeywordT::With => theme.keyword(&"with"),
+            MutualKeywordT::And => theme.keyword(&"and"),
+        }
+    }
+}
+
 // ============================================================================
 // Type Keyword
 // ============================================================================
@@ -42,6 +84,110 @@ impl Pretty for TypeKeywordT {
     }
 }
 
+// ============================================================================
+// Class Keyword
+// ============================================================================
+#[derive(Debug)]
+pub struct ClassKeywordT();
+pub type ClassKeyword = Meta<ClassKeywordT>;
+
+impl Pretty for ClassKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"Class")
+    }
+}
+
+// ============================================================================
+// Instance Keyword
+// ============================================================================
+#[derive(Debug)]
+pub struct InstanceKeywordT();
+pub type InstanceKeyword = Meta<InstanceKeywordT>;
+
+impl Pretty for InstanceKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"Instance")
+    }
+}
+
+// ============================================================================
+// Theorem Keyword
+// ============================================================================
+#[derive(Debug)]
+pub enum TheoremKeywordT {
+    Theorem,
+    Lemma,
+}
+pub type TheoremKeyword = Meta<TheoremKeywordT>;
+
+impl Pretty for TheoremKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            TheoremKeywordT::Theorem => theme.keyword(&"Theorem"),
+            TheoremKeywordT::Lemma => theme.keyword(&"Lemma"),
+        }
+    }
+}
+
+// ============================================================================
+// Proof Keyword
+// ============================================================================
+#[derive(Debug)]
+pub enum ProofKeywordT {
+    Proof,
+    Qed,
+    Abort,
+}
+pub type ProofKeyword = Meta<ProofKeywordT>;
+
+impl Pretty for ProofKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            ProofKeywordT::Proof => theme.keyword(&"Proof"),
+            ProofKeywordT::Qed => theme.keyword(&"Qed"),
+            ProofKeywordT::Abort => theme.keyword(&"Abort"),
+        }
+    }
+}
+
+// ============================================================================
+// Tactic Keyword
+// ============================================================================
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TacticKeywordT {
+    Intro,
+    Apply,
+    Exact,
+    Exists,
+    Split,
+    Left,
+    Right,
+    Reflexivity,
+}
+pub type TacticKeyword = Meta<TacticKeywordT>;
+
+impl TacticKeywordT {
+    /// name of tactic
+    pub fn name(&self) -> &'static str {
+        match self {
+            TacticKeywordT::Intro => keyword::INTRO,
+            TacticKeywordT::Apply => keyword::APPLY,
+            TacticKeywordT::Exact => keyword::EXACT,
+            TacticKeywordT::Exists => keyword::EXISTS,
+            TacticKeywordT::Split => keyword::SPLIT,
+            TacticKeywordT::Left => keyword::LEFT,
+            TacticKeywordT::Right => keyword::RIGHT,
+            TacticKeywordT::Reflexivity => keyword::REFLEXIVITY,
+        }
+    }
+}
+
+impl Pretty for TacticKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&self.name())
+    }
+}
+
 // ============================================================================
 // Eval Keyword
 // ============================================================================
@@ -80,6 +226,19 @@ impl Pretty for TypeOfKeywordT {
     }
 }
 
+// ============================================================================
+// Assert Keyword
+// ============================================================================
+#[derive(Debug)]
+pub struct AssertKeywordT();
+pub type AssertKeyword = Meta<AssertKeywordT>;
+
+impl Pretty for AssertKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"Assert")
+    }
+}
+
 // ============================================================================
 // Help Keyword
 // ============================================================================
@@ -125,6 +284,64 @@ impl Pretty for UnsetKeywordT {
     }
 }
 
+// ============================================================================
+// Module Keyword
+// ============================================================================
+#[derive(Debug)]
+pub enum ModuleKeywordT {
+    Module,
+    End,
+}
+pub type ModuleKeyword = Meta<ModuleKeywordT>;
+
+impl Pretty for ModuleKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            ModuleKeywordT::Module => theme.keyword(&"Module"),
+            ModuleKeywordT::End => theme.keyword(&"End"),
+        }
+    }
+}
+
+// ============================================================================
+// Open Keyword
+// ============================================================================
+#[derive(Debug)]
+pub struct OpenKeywordT();
+pub type OpenKeyword = Meta<OpenKeywordT>;
+
+impl Pretty for OpenKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"Open")
+    }
+}
+
+// ============================================================================
+// Require Keyword
+// ============================================================================
+#[derive(Debug)]
+pub struct RequireKeywordT();
+pub type RequireKeyword = Meta<RequireKeywordT>;
+
+impl Pretty for RequireKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"Require")
+    }
+}
+
+// ============================================================================
+// Private Keyword
+// ============================================================================
+#[derive(Debug)]
+pub struct PrivateKeywordT();
+pub type PrivateKeyword = Meta<PrivateKeywordT>;
+
+impl Pretty for PrivateKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"Private")
+    }
+}
+
 // ============================================================================
 // Command Kind
 // ============================================================================
@@ -135,10 +352,34 @@ pub enum CommandKind {
         keyword: DefinitionKeyword,
         def: Box<ExpressionDefinition>,
     },
+    Fixpoint {
+        keyword: FixpointKeyword,
+        def: Box<FixpointDefinition>,
+    },
     TypeDefinition {
         keyword: TypeKeyword,
         def: TypeDefinition,
     },
+    /// class, a record type of methods with one type parameter
+    Class {
+        keyword: ClassKeyword,
+        def: TypeDefinition,
+    },
+    Instance {
+        keyword: InstanceKeyword,
+        def: Box<Instance>,
+    },
+    Theorem {
+        keyword: TheoremKeyword,
+        def: Box<Theorem>,
+    },
+    Proof {
+        keyword: ProofKeyword,
+    },
+    Tactic {
+        keyword: TacticKeyword,
+        args: tactic::TacticArgs,
+    },
     Eval {
         keyword: EvalKeyword,
         expr: Expression,
@@ -147,6 +388,10 @@ pub enum CommandKind {
         keyword: TypeOfKeyword,
         expr: Expression,
     },
+    Assert {
+        keyword: AssertKeyword,
+        expr: Expression,
+    },
     Help {
         keyword: HelpKeyword,
         var: help::Variable,
@@ -159,27 +404,70 @@ pub enum CommandKind {
         keyword: UnsetKeyword,
         var: expression::Variable,
     },
+    Module {
+        keyword: ModuleKeyword,
+        name: module::Name,
+    },
+    Open {
+        keyword: OpenKeyword,
+        name: module::Name,
+    },
+    Require {
+        keyword: RequireKeyword,
+        path: module::Path,
+    },
+    /// definition hidden outside of its module
+    Private {
+        keyword: PrivateKeyword,
+        kind: Box<CommandKind>,
+    },
 }
 
 impl Pretty for CommandKind {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         let doc_keyword = match self {
             CommandKind::ExpressionDefinition { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Fixpoint { keyword, .. } => keyword.pretty(theme),
             CommandKind::TypeDefinition { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Class { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Instance { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Theorem { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Proof { keyword } => keyword.pretty(theme),
+            CommandKind::Tactic { keyword, .. } => keyword.pretty(theme),
             CommandKind::Eval { keyword, .. } => keyword.pretty(theme),
             CommandKind::TypeOf { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Assert { keyword, .. } => keyword.pretty(theme),
             CommandKind::Help { keyword, .. } => keyword.pretty(theme),
             CommandKind::Set { keyword, .. } => keyword.pretty(theme),
             CommandKind::UnSet { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Module { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Open { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Require { keyword, .. } => keyword.pretty(theme),
+            CommandKind::Private { keyword, .. } => keyword.pretty(theme),
         };
         let doc_content = match self {
             CommandKind::ExpressionDefinition { def, .. } => def.pretty(theme),
+            CommandKind::Fixpoint { def, .. } => def.pretty(theme),
             CommandKind::TypeDefinition { def, .. } => def.pretty(theme),
+            CommandKind::Class { def, .. } => def.pretty(theme),
+            CommandKind::Instance { def, .. } => def.pretty(theme),
+            CommandKind::Theorem { def, .. } => def.pretty(theme),
+            CommandKind::Proof { .. }
+            | CommandKind::Tactic {
+                args: tactic::TacticArgs::None,
+                ..
+            } => return doc_keyword,
+            CommandKind::Tactic { args, .. } => args.pretty(theme),
             CommandKind::Eval { expr, .. } => expr.pretty(theme),
             CommandKind::TypeOf { expr, .. } => expr.pretty(theme),
+            CommandKind::Assert { expr, .. } => expr.pretty(theme),
             CommandKind::Help { var, .. } => var.pretty(theme),
             CommandKind::Set { var, .. } => var.pretty(theme),
             CommandKind::UnSet { var, .. } => var.pretty(theme),
+            CommandKind::Module { name, .. } => name.pretty(theme),
+            CommandKind::Open { name, .. } => name.pretty(theme),
+            CommandKind::Require { path, .. } => path.pretty(theme),
+            CommandKind::Private { kind, .. } => kind.pretty(theme),
         };
 
         Doc::nil()
@@ -193,21 +481,43 @@ impl Located for CommandKind {
     fn loc(&self) -> Location {
         let loc_keyword = match self {
             CommandKind::ExpressionDefinition { keyword, .. } => keyword.loc(),
+            CommandKind::Fixpoint { keyword, .. } => keyword.loc(),
             CommandKind::TypeDefinition { keyword, .. } => keyword.loc(),
+            CommandKind::Class { keyword, .. } => keyword.loc(),
+            CommandKind::Instance { keyword, .. } => keyword.loc(),
+            CommandKind::Theorem { keyword, .. } => keyword.loc(),
+            CommandKind::Proof { keyword } => keyword.loc(),
+            CommandKind::Tactic { keyword, .. } => keyword.loc(),
             CommandKind::Eval { keyword, .. } => keyword.loc(),
             CommandKind::TypeOf { keyword, .. } => keyword.loc(),
+            CommandKind::Assert { keyword, .. } => keyword.loc(),
             CommandKind::Help { keyword, .. } => keyword.loc(),
             CommandKind::Set { keyword, .. } => keyword.loc(),
             CommandKind::UnSet { keyword, .. } => keyword.loc(),
+            CommandKind::Module { keyword, .. } => keyword.loc(),
+            CommandKind::Open { keyword, .. } => keyword.loc(),
+            CommandKind::Require { keyword, .. } => keyword.loc(),
+            CommandKind::Private { keyword, .. } => keyword.loc(),
         };
         let loc_content = match self {
             CommandKind::ExpressionDefinition { def, .. } => def.loc(),
+            CommandKind::Fixpoint { def, .. } => def.loc(),
             CommandKind::TypeDefinition { def, .. } => def.loc(),
+            CommandKind::Class { def, .. } => def.loc(),
+            CommandKind::Instance { def, .. } => def.loc(),
+            CommandKind::Theorem { def, .. } => def.loc(),
+            CommandKind::Proof { keyword } => keyword.loc(),
+            CommandKind::Tactic { keyword, args } => args.loc().unwrap_or(keyword.loc()),
             CommandKind::Eval { expr, .. } => expr.loc(),
             CommandKind::TypeOf { expr, .. } => expr.loc(),
+            CommandKind::Assert { expr, .. } => expr.loc(),
             CommandKind::Help { var, .. } => var.loc(),
             CommandKind::Set { var, .. } => var.loc(),
             CommandKind::UnSet { var, .. } => var.loc(),
+            CommandKind::Module { name, .. } => name.loc(),
+            CommandKind::Open { name, .. } => name.loc(),
+            CommandKind::Require { path, .. } => path.loc(),
+            CommandKind::Private { kind, .. } => kind.loc(),
         };
         loc_keyword.union(loc_content)
     }
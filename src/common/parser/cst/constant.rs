@@ -1,9 +1,10 @@
-use super::{AsCharacter, AsNumber};
+use super::{AsCharacter, AsInteger, AsNumber, AsRational, AsString};
 use crate::lexer::meta::Meta;
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
+use crate::utils::rational;
 use crate::utils::theme::{Doc, Theme};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 
 // ============================================================================
 // Number
@@ -30,6 +31,56 @@ impl Pretty for NumberT {
     }
 }
 
+// ============================================================================
+// Integer
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct IntegerT(BigInt);
+pub type Integer = Meta<IntegerT>;
+
+impl From<BigInt> for IntegerT {
+    fn from(value: BigInt) -> Self {
+        Self(value)
+    }
+}
+
+impl AsInteger for IntegerT {
+    fn as_integer(&self) -> &BigInt {
+        &self.0
+    }
+}
+
+impl Pretty for IntegerT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.integer(&self.0)
+    }
+}
+
+// ============================================================================
+// Rational
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct RationalT(rational::Rational);
+pub type Rational = Meta<RationalT>;
+
+impl From<rational::Rational> for RationalT {
+    fn from(value: rational::Rational) -> Self {
+        Self(value)
+    }
+}
+
+impl AsRational for RationalT {
+    fn as_rational(&self) -> &rational::Rational {
+        &self.0
+    }
+}
+
+impl Pretty for RationalT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.0.pretty(theme)
+    }
+}
+
 // ============================================================================
 // Character
 // ============================================================================
@@ -55,6 +106,31 @@ impl Pretty for CharacterT {
     }
 }
 
+// ============================================================================
+// String
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct StringT(String);
+pub type Str = Meta<StringT>;
+
+impl From<String> for StringT {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl AsString for StringT {
+    fn as_string(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Pretty for StringT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.string(&self.0)
+    }
+}
+
 // ============================================================================
 // Builtin
 // ============================================================================
@@ -62,6 +138,11 @@ impl Pretty for CharacterT {
 pub enum BuiltinT {
     True,
     False,
+    IntOfNat,
+    NatOfInt,
+    StringLength,
+    StringAppend,
+    StringGet,
 }
 
 impl Pretty for BuiltinT {
@@ -69,6 +150,11 @@ impl Pretty for BuiltinT {
         match self {
             BuiltinT::True => theme.expr_var(&"__Constant_true__"),
             BuiltinT::False => theme.expr_var(&"__Constant_false__"),
+            BuiltinT::IntOfNat => theme.expr_var(&"__Function_int_of_nat__"),
+            BuiltinT::NatOfInt => theme.expr_var(&"__Function_nat_of_int__"),
+            BuiltinT::StringLength => theme.expr_var(&"__Function_string_length__"),
+            BuiltinT::StringAppend => theme.expr_var(&"__Function_string_append__"),
+            BuiltinT::StringGet => theme.expr_var(&"__Function_string_get__"),
         }
     }
 }
@@ -83,7 +169,10 @@ pub type Builtin = Meta<BuiltinT>;
 pub enum Constant {
     Builtin(Builtin),
     Number(Number),
+    Integer(Integer),
+    Rational(Rational),
     Character(Character),
+    String(Str),
 }
 
 impl From<Builtin> for Constant {
@@ -98,17 +187,38 @@ impl From<Number> for Constant {
     }
 }
 
+impl From<Integer> for Constant {
+    fn from(value: Integer) -> Self {
+        Constant::Integer(value)
+    }
+}
+
+impl From<Rational> for Constant {
+    fn from(value: Rational) -> Self {
+        Constant::Rational(value)
+    }
+}
+
 impl From<Character> for Constant {
     fn from(value: Character) -> Self {
         Constant::Character(value)
     }
 }
 
+impl From<Str> for Constant {
+    fn from(value: Str) -> Self {
+        Constant::String(value)
+    }
+}
+
 impl Located for Constant {
     fn loc(&self) -> Location {
         match self {
             Constant::Number(n) => n.loc(),
+            Constant::Integer(i) => i.loc(),
+            Constant::Rational(q) => q.loc(),
             Constant::Character(c) => c.loc(),
+            Constant::String(s) => s.loc(),
             Constant::Builtin(b) => b.loc(),
         }
     }
@@ -118,7 +228,10 @@ impl Pretty for Constant {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         match self {
             Constant::Number(n) => n.pretty(theme),
+            Constant::Integer(i) => i.pretty(theme),
+            Constant::Rational(q) => q.pretty(theme),
             Constant::Character(c) => c.pretty(theme),
+            Constant::String(s) => s.pretty(theme),
             Constant::Builtin(b) => b.pretty(theme),
         }
     }
@@ -1,6 +1,6 @@
-use super::parenthesis::PrettyPrecedence;
+use super::parenthesis::{PrettyPrecedence, Unit};
 use super::AsIdentifier;
-use super::{operator, parenthesis::Parenthesed, Constant, Type};
+use super::{operator, parenthesis::Parenthesed, record, Constant, Pattern, Type};
 use crate::lexer::meta::Meta;
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
@@ -31,6 +31,105 @@ impl Pretty for VariableT {
         theme.expr_var(&self.0)
     }
 }
+// ============================================================================
+// Conditional Keyword
+// ============================================================================
+#[derive(Debug, Clone)]
+pub enum ConditionalKeywordT {
+    If,
+    Then,
+    Else,
+}
+pub type ConditionalKeyword = Meta<ConditionalKeywordT>;
+
+impl Pretty for ConditionalKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            ConditionalKeywordT::If => theme.keyword(&"if"),
+            ConditionalKeywordT::Then => theme.keyword(&"then"),
+            ConditionalKeywordT::Else => theme.keyword(&"else"),
+        }
+    }
+}
+
+// ============================================================================
+// Let Keyword
+// ============================================================================
+#[derive(Debug, Clone)]
+pub enum LetKeywordT {
+    Let,
+    In,
+}
+pub type LetKeyword = Meta<LetKeywordT>;
+
+impl Pretty for LetKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            LetKeywordT::Let => theme.keyword(&"let"),
+            LetKeywordT::In => theme.keyword(&"in"),
+        }
+    }
+}
+
+// ============================================================================
+// Quantifier Keyword
+// ============================================================================
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantifierKeywordT {
+    Forall,
+    Exists,
+}
+pub type QuantifierKeyword = Meta<QuantifierKeywordT>;
+
+impl Pretty for QuantifierKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            QuantifierKeywordT::Forall => theme.keyword(&"forall"),
+            QuantifierKeywordT::Exists => theme.keyword(&"exists"),
+        }
+    }
+}
+
+// ============================================================================
+// Branch
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct Branch {
+    pub bar: operator::Bar,
+    pub pattern: Pattern,
+    pub arrow: operator::FatArrow,
+    pub body: Expression5,
+}
+
+impl Branch {
+    /// pretty branch, with the body parenthesized if it would capture next branches
+    fn pretty_branch(&self, last: bool, theme: &Theme) -> Doc<'_> {
+        let prec = if !last && self.body.ends_with_match() {
+            10
+        } else {
+            11
+        };
+        Doc::nil()
+            .append(self.bar.pretty(theme))
+            .append(Doc::space())
+            .append(self.pattern.pretty(theme))
+            .append(Doc::space())
+            .append(self.arrow.pretty(theme))
+            .append(
+                Doc::line()
+                    .append(self.body.pretty_precedence(prec, theme))
+                    .nest(2),
+            )
+            .group()
+    }
+}
+
+impl Located for Branch {
+    fn loc(&self) -> Location {
+        self.bar.loc().union(self.body.loc())
+    }
+}
+
 // ============================================================================
 // Expression
 // ============================================================================
@@ -43,32 +142,142 @@ pub enum Expression0 {
     /// constant
     Constant(Constant),
 
+    /// unit value
+    Unit(Unit),
+
     /// parenthesized expression
     Paren(Parenthesed<operator::LParenT, Box<Expression>, operator::RParenT>),
+
+    /// record or update of a record
+    Record(record::Record),
+
+    /// projection on a field of a record (assoc left)
+    Projection {
+        expr: Box<Expression0>,
+        dot: operator::Projection,
+        field: record::Field,
+    },
 }
 
 #[derive(Debug, Clone)]
 pub enum Expression1 {
+    /// application (assoc left)
+    Application {
+        fun: Box<Expression1>,
+        arg: Expression0,
+    },
+    /// allocation or read of a reference
+    Reference {
+        op: operator::ReferenceOperator,
+        expr: Box<Expression1>,
+    },
+    Expression0(Expression0),
+}
+
+#[derive(Debug, Clone)]
+pub enum Expression2 {
+    /// binary operation, see [`operator::BinaryOperatorT`] for precedences
+    Binary {
+        left: Box<Expression2>,
+        op: operator::BinaryOperator,
+        right: Box<Expression2>,
+    },
+    /// prefix operation
+    Unary {
+        op: operator::UnaryOperator,
+        expr: Box<Expression2>,
+    },
+    /// write of a reference
+    Assign {
+        reference: Box<Expression2>,
+        arrow: operator::Assign,
+        value: Box<Expression2>,
+    },
+    Expression1(Expression1),
+}
+
+#[derive(Debug, Clone)]
+pub enum Expression3 {
     TypedExpression {
-        expr: Expression0,
+        expr: Expression2,
         colon: operator::Colon,
-        ty: Type,
+        ty: Box<Type>,
     },
-    Expression0(Expression0),
+    Expression2(Expression2),
+}
+
+#[derive(Debug, Clone)]
+pub enum Expression4 {
+    /// tuple
+    Tuple {
+        first: Expression3,
+        rest: Vec<(operator::Comma, Expression3)>,
+    },
+    Expression3(Expression3),
 }
 
-pub type Expression = Expression1;
+#[derive(Debug, Clone)]
+pub enum Expression5 {
+    /// abstraction
+    Abstraction {
+        pattern: Pattern,
+        arrow: operator::FatArrow,
+        body: Box<Expression5>,
+    },
+    /// pattern matching
+    Match {
+        branches: Vec<Branch>,
+    },
+    /// conditional
+    If {
+        kw_if: ConditionalKeyword,
+        cond: Box<Expression5>,
+        kw_then: ConditionalKeyword,
+        then_branch: Box<Expression5>,
+        kw_else: ConditionalKeyword,
+        else_branch: Box<Expression5>,
+    },
+    /// local binding
+    Let {
+        kw_let: LetKeyword,
+        pattern: Pattern,
+        eq_def: operator::EqDef,
+        value: Box<Expression5>,
+        kw_in: LetKeyword,
+        body: Box<Expression5>,
+    },
+    /// quantified proposition
+    Quantifier {
+        kw: QuantifierKeyword,
+        vars: Vec<Variable>,
+        typed: Option<(operator::Colon, Box<Type>)>,
+        comma: operator::Comma,
+        body: Box<Expression5>,
+    },
+    Expression4(Expression4),
+}
+
+pub type Expression = Expression5;
 
 impl PrettyPrecedence for Expression0 {
     fn precedence(&self) -> u8 {
-        0
+        match self {
+            Expression0::Paren(parent) => parent.precedence(),
+            _ => 0,
+        }
     }
 
     fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
         match self {
             Expression0::Variable(var) => var.pretty(theme),
             Expression0::Constant(constant) => constant.pretty(theme),
+            Expression0::Unit(unit) => unit.pretty(theme),
             Expression0::Paren(parent) => parent.pretty_precedence(prec, theme),
+            Expression0::Record(record) => record.pretty(theme),
+            Expression0::Projection { expr, dot, field } => Doc::nil()
+                .append(expr.pretty_precedence(0, theme))
+                .append(dot.pretty(theme))
+                .append(field.pretty(theme)),
         }
     }
 }
@@ -78,35 +287,330 @@ impl Located for Expression0 {
         match self {
             Expression0::Variable(var) => var.loc(),
             Expression0::Constant(constant) => constant.loc(),
+            Expression0::Unit(unit) => unit.loc(),
             Expression0::Paren(parent) => parent.loc(),
+            Expression0::Record(record) => record.loc(),
+            Expression0::Projection { expr, field, .. } => expr.loc().union(field.loc()),
         }
     }
 }
 
 impl PrettyPrecedence for Expression1 {
     fn precedence(&self) -> u8 {
-        1
+        match self {
+            Expression1::Application { .. } | Expression1::Reference { .. } => 1,
+            Expression1::Expression0(expr) => expr.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Expression1::Application { fun, arg } => Doc::nil()
+                .append(fun.pretty_precedence(1, theme))
+                .append(Doc::line().append(arg.pretty_precedence(0, theme)).nest(2))
+                .group(),
+            Expression1::Reference { op, expr } => {
+                let space = match op.value {
+                    operator::ReferenceOperatorT::Alloc => Doc::space(),
+                    operator::ReferenceOperatorT::Deref => Doc::nil(),
+                };
+                Doc::nil()
+                    .append(op.pretty(theme))
+                    .append(space)
+                    .append(expr.pretty_precedence(1, theme))
+            }
+            Expression1::Expression0(expr) => expr.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Expression1 {
+    fn loc(&self) -> Location {
+        match self {
+            Expression1::Application { fun, arg } => fun.loc().union(arg.loc()),
+            Expression1::Reference { op, expr } => op.loc().union(expr.loc()),
+            Expression1::Expression0(expr) => expr.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Expression2 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression2::Binary { op, .. } => op.value.precedence(),
+            Expression2::Unary { op, .. } => op.value.precedence(),
+            Expression2::Assign { arrow, .. } => arrow.value.precedence(),
+            Expression2::Expression1(expr) => expr.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Expression2::Binary { left, op, right } => {
+                let (prec_left, prec_right) = op.value.operands_precedence();
+                Doc::nil()
+                    .append(left.pretty_precedence(prec_left, theme))
+                    .append(Doc::space())
+                    .append(op.pretty(theme))
+                    .append(
+                        Doc::line()
+                            .append(right.pretty_precedence(prec_right, theme))
+                            .nest(2),
+                    )
+                    .group()
+            }
+            Expression2::Unary { op, expr } => Doc::nil()
+                .append(op.pretty(theme))
+                .append(Doc::space())
+                .append(expr.pretty_precedence(op.value.operand_precedence(), theme)),
+            Expression2::Assign {
+                reference,
+                arrow,
+                value,
+            } => Doc::nil()
+                .append(reference.pretty_precedence(8, theme))
+                .append(Doc::space())
+                .append(arrow.pretty(theme))
+                .append(
+                    Doc::line()
+                        .append(value.pretty_precedence(8, theme))
+                        .nest(2),
+                )
+                .group(),
+            Expression2::Expression1(expr) => expr.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Expression2 {
+    fn loc(&self) -> Location {
+        match self {
+            Expression2::Binary { left, right, .. } => left.loc().union(right.loc()),
+            Expression2::Unary { op, expr } => op.loc().union(expr.loc()),
+            Expression2::Assign {
+                reference, value, ..
+            } => reference.loc().union(value.loc()),
+            Expression2::Expression1(expr) => expr.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Expression3 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression3::TypedExpression { .. } => 9,
+            Expression3::Expression2(expr) => expr.precedence(),
+        }
     }
 
     fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
         match self {
-            Expression1::TypedExpression { expr, colon, ty } => Doc::nil()
-                .append(expr.pretty(theme))
+            Expression3::TypedExpression { expr, colon, ty } => Doc::nil()
+                .append(expr.pretty_precedence(8, theme))
                 .append(Doc::space())
                 .append(colon.pretty(theme))
                 .append(Doc::space())
                 .append(ty.pretty(theme))
                 .group(),
-            Expression1::Expression0(expr) => expr.pretty_precedence(prec, theme),
+            Expression3::Expression2(expr) => expr.pretty_precedence(prec, theme),
         }
     }
 }
 
-impl Located for Expression1 {
+impl Located for Expression3 {
     fn loc(&self) -> Location {
         match self {
-            Expression1::TypedExpression { expr, ty, .. } => expr.loc().union(ty.loc()),
-            Expression1::Expression0(expr) => expr.loc(),
+            Expression3::TypedExpression { expr, ty, .. } => expr.loc().union(ty.loc()),
+            Expression3::Expression2(expr) => expr.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Expression4 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression4::Tuple { .. } => 10,
+            Expression4::Expression3(expr) => expr.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Expression4::Tuple { first, rest } => rest
+                .iter()
+                .fold(first.pretty_precedence(9, theme), |doc, (comma, expr)| {
+                    doc.append(comma.pretty(theme))
+                        .append(Doc::line())
+                        .append(expr.pretty_precedence(9, theme))
+                })
+                .group(),
+            Expression4::Expression3(expr) => expr.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Expression4 {
+    fn loc(&self) -> Location {
+        match self {
+            Expression4::Tuple { first, rest } => match rest.last() {
+                Some((_, last)) => first.loc().union(last.loc()),
+                None => first.loc(),
+            },
+            Expression4::Expression3(expr) => expr.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Expression5 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression5::Abstraction { .. } => 11,
+            Expression5::Match { .. } => 11,
+            Expression5::If { .. } => 11,
+            Expression5::Let { .. } => 11,
+            Expression5::Quantifier { .. } => 11,
+            Expression5::Expression4(expr) => expr.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Expression5::Abstraction {
+                pattern,
+                arrow,
+                body,
+            } => Doc::nil()
+                .append(pattern.pretty(theme))
+                .append(Doc::space())
+                .append(arrow.pretty(theme))
+                .append(
+                    Doc::line()
+                        .append(body.pretty_precedence(prec.min(11), theme))
+                        .nest(2),
+                )
+                .group(),
+            Expression5::Match { branches } => Doc::intersperse(
+                branches
+                    .iter()
+                    .enumerate()
+                    .map(|(i, branch)| branch.pretty_branch(i + 1 == branches.len(), theme)),
+                Doc::line(),
+            )
+            .group(),
+            Expression5::If {
+                kw_if,
+                cond,
+                kw_then,
+                then_branch,
+                kw_else,
+                else_branch,
+            } => Doc::nil()
+                .append(kw_if.pretty(theme))
+                .append(Doc::space())
+                .append(cond.pretty_precedence(11, theme))
+                .append(Doc::line())
+                .append(
+                    kw_then
+                        .pretty(theme)
+                        .append(Doc::line().append(then_branch.pretty_precedence(11, theme)))
+                        .nest(2)
+                        .group(),
+                )
+                .append(Doc::line())
+                .append(
+                    kw_else
+                        .pretty(theme)
+                        .append(
+                            Doc::line().append(else_branch.pretty_precedence(prec.min(11), theme)),
+                        )
+                        .nest(2)
+                        .group(),
+                )
+                .group(),
+            Expression5::Let {
+                kw_let,
+                pattern,
+                eq_def,
+                value,
+                kw_in,
+                body,
+            } => Doc::nil()
+                .append(
+                    kw_let
+                        .pretty(theme)
+                        .append(Doc::space())
+                        .append(pattern.pretty(theme))
+                        .append(Doc::space())
+                        .append(eq_def.pretty(theme))
+                        .append(Doc::line().append(value.pretty_precedence(11, theme)))
+                        .append(Doc::line())
+                        .append(kw_in.pretty(theme))
+                        .nest(2)
+                        .group(),
+                )
+                .append(Doc::line())
+                .append(body.pretty_precedence(prec.min(11), theme))
+                .group(),
+            Expression5::Quantifier {
+                kw,
+                vars,
+                typed,
+                comma,
+                body,
+            } => Doc::nil()
+                .append(kw.pretty(theme))
+                .append(Doc::space())
+                .append(Doc::intersperse(
+                    vars.iter().map(|var| var.pretty(theme)),
+                    Doc::space(),
+                ))
+                .append(match typed {
+                    Some((colon, ty)) => Doc::space()
+                        .append(colon.pretty(theme))
+                        .append(Doc::space())
+                        .append(ty.pretty(theme)),
+                    None => Doc::nil(),
+                })
+                .append(comma.pretty(theme))
+                .append(
+                    Doc::line()
+                        .append(body.pretty_precedence(prec.min(11), theme))
+                        .nest(2),
+                )
+                .group(),
+            Expression5::Expression4(expr) => expr.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Expression5 {
+    fn loc(&self) -> Location {
+        match self {
+            Expression5::Abstraction { pattern, body, .. } => pattern.loc().union(body.loc()),
+            Expression5::Match { branches } => match (branches.first(), branches.last()) {
+                (Some(first), Some(last)) => first.loc().union(last.loc()),
+                _ => Location::unknown(),
+            },
+            Expression5::If {
+                kw_if, else_branch, ..
+            } => kw_if.loc().union(else_branch.loc()),
+            Expression5::Let { kw_let, body, .. } => kw_let.loc().union(body.loc()),
+            Expression5::Quantifier { kw, body, .. } => kw.loc().union(body.loc()),
+            Expression5::Expression4(expr) => expr.loc(),
+        }
+    }
+}
+
+impl Expression5 {
+    /// check if a following branch would be captured by this expression
+    fn ends_with_match(&self) -> bool {
+        match self {
+            Expression5::Abstraction { body, .. } => body.ends_with_match(),
+            Expression5::Match { .. } => true,
+            Expression5::If { else_branch, .. } => else_branch.ends_with_match(),
+            Expression5::Let { body, .. } => body.ends_with_match(),
+            Expression5::Quantifier { body, .. } => body.ends_with_match(),
+            Expression5::Expression4(expr) => expr.precedence() > 10,
         }
     }
 }
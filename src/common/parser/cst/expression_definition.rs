@@ -1,4 +1,7 @@
-use super::{operator, Expression, Pattern, Type};
+use super::command::MutualKeyword;
+use super::expression::{self, Expression5};
+use super::{operator, ty, Expression, Pattern, Type};
+use crate::lexer::Meta;
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
@@ -28,18 +31,155 @@ impl Pretty for TypedBy {
     }
 }
 
+// ============================================================================
+// Type Binders
+// ============================================================================
+#[derive(Debug)]
+pub struct Binders {
+    pub less: operator::Less,
+    pub vars: Vec<ty::Variable>,
+    pub greater: operator::Greater,
+}
+
+impl Binders {
+    /// get bound type variables
+    pub fn vars(&self) -> &[ty::Variable] {
+        &self.vars
+    }
+}
+
+impl Pretty for Binders {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.less.pretty(theme))
+            .append(Doc::intersperse(
+                self.vars.iter().map(|var| var.pretty(theme)),
+                Doc::space(),
+            ))
+            .append(self.greater.pretty(theme))
+    }
+}
+
+// ============================================================================
+// Environment Parameters
+// ============================================================================
+/// reference `name : ty` of the environment used by a definition
+#[derive(Debug)]
+pub struct EnvParam {
+    pub name: expression::Variable,
+    pub colon: operator::Colon,
+    pub ty: Type,
+}
+
+impl Located for EnvParam {
+    fn loc(&self) -> Location {
+        self.name.loc().union(self.ty.loc())
+    }
+}
+
+impl Pretty for EnvParam {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.name.pretty(theme))
+            .append(Doc::space())
+            .append(self.colon.pretty(theme))
+            .append(Doc::space())
+            .append(self.ty.pretty(theme))
+    }
+}
+
+/// references of the environment used by a definition, `[r : ref ℕ, s : ref ℤ]`
+#[derive(Debug)]
+pub struct EnvParams {
+    pub l_bracket: operator::LBracket,
+    pub params: Vec<EnvParam>,
+    pub r_bracket: operator::RBracket,
+}
+
+impl Pretty for EnvParams {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.l_bracket.pretty(theme))
+            .append(Doc::intersperse(
+                self.params.iter().map(|param| param.pretty(theme)),
+                theme.operator(&",").append(Doc::space()),
+            ))
+            .append(self.r_bracket.pretty(theme))
+    }
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContractKeywordT {
+    Requires,
+    Ensures,
+}
+pub type ContractKeyword = Meta<ContractKeywordT>;
+
+impl Pretty for ContractKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            ContractKeywordT::Requires => theme.keyword(&"requires"),
+            ContractKeywordT::Ensures => theme.keyword(&"ensures"),
+        }
+    }
+}
+
+/// clause `requires expr` or `ensures expr` of a contract
+#[derive(Debug, Clone)]
+pub struct Contract {
+    pub keyword: ContractKeyword,
+    pub expr: Expression,
+}
+
+impl Located for Contract {
+    fn loc(&self) -> Location {
+        self.keyword.loc().union(self.expr.loc())
+    }
+}
+
+impl Pretty for Contract {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.keyword.pretty(theme))
+            .append(Doc::space())
+            .append(self.expr.pretty(theme).group())
+    }
+}
+
 // ============================================================================
 // Expression Definition
 // ============================================================================
 #[derive(Debug)]
 pub struct ExpressionDefinition {
+    pub env: Option<EnvParams>,
     pub pattern: Pattern,
+    pub binders: Option<Binders>,
     pub typed_by: Option<TypedBy>,
+    pub contracts: Vec<Contract>,
     pub eq_def: operator::EqDef,
     pub body: Expression,
 }
 
 impl ExpressionDefinition {
+    /// get bound type variables of definition
+    pub fn binders(&self) -> &[ty::Variable] {
+        match self.binders {
+            Some(ref binders) => binders.vars(),
+            None => &[],
+        }
+    }
+
+    /// get references of the environment used by definition
+    pub fn env(&self) -> &[EnvParam] {
+        match self.env {
+            Some(ref env) => &env.params,
+            None => &[],
+        }
+    }
+
     /// get optal type of definition
     pub fn typed_by(&self) -> Option<&Type> {
         match self.typed_by {
@@ -51,7 +191,10 @@ impl ExpressionDefinition {
 
 impl Located for ExpressionDefinition {
     fn loc(&self) -> Location {
-        self.pattern.loc().union(self.body.loc())
+        match &self.env {
+            Some(env) => env.l_bracket.loc().union(self.body.loc()),
+            None => self.pattern.loc().union(self.body.loc()),
+        }
     }
 }
 
@@ -67,12 +210,80 @@ impl Pretty for ExpressionDefinition {
             }
         };
 
-        let doc_body = Doc::softline().append(self.body.pretty(theme).group());
+        let doc_contracts = Doc::concat(
+            self.contracts
+                .iter()
+                .map(|contract| Doc::line().append(contract.pretty(theme)).nest(2)),
+        );
+
+        let doc_binders = match &self.binders {
+            Some(binders) => Doc::space().append(binders.pretty(theme)),
+            None => Doc::nil(),
+        };
+
+        let doc_env = match &self.env {
+            Some(env) => env.pretty(theme).append(Doc::space()),
+            None => Doc::nil(),
+        };
+
+        let doc_body = match &self.body {
+            Expression5::Match { .. } => Doc::line().append(self.body.pretty(theme)).group(),
+            _ => Doc::softline().append(self.body.pretty(theme).group()),
+        };
+
+        let doc_head = doc_env
+            .append(self.pattern.pretty(theme))
+            .append(doc_binders)
+            .append(doc_typed_by);
+        // contracts stay on the line of the head when they fit
+        let doc_head = if self.contracts.is_empty() {
+            doc_head
+        } else {
+            doc_head.append(doc_contracts).group()
+        };
 
         Doc::nil()
-            .append((self.pattern.pretty(theme)).append(doc_typed_by))
+            .append(doc_head)
             .append(Doc::space())
             .append(self.eq_def.pretty(theme))
             .append(doc_body)
     }
 }
+
+// ============================================================================
+// Fixpoint Definition
+// ============================================================================
+#[derive(Debug)]
+pub struct FixpointDefinition {
+    pub first: ExpressionDefinition,
+    pub rest: Vec<(MutualKeyword, ExpressionDefinition)>,
+}
+
+impl FixpointDefinition {
+    /// get mutually recursive definitions, in order
+    pub fn definitions(&self) -> impl Iterator<Item = &ExpressionDefinition> {
+        std::iter::once(&self.first).chain(self.rest.iter().map(|(_, def)| def))
+    }
+}
+
+impl Located for FixpointDefinition {
+    fn loc(&self) -> Location {
+        match self.rest.last() {
+            Some((_, last)) => self.first.loc().union(last.loc()),
+            None => self.first.loc(),
+        }
+    }
+}
+
+impl Pretty for FixpointDefinition {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.rest
+            .iter()
+            .fold(self.first.pretty(theme), |doc, (keyword, def)| {
+                doc.append(Doc::hardline())
+                    .append(keyword.pretty(theme))
+                    .append(Doc::space())
+                    .append(def.pretty(theme))
+            })
+    }
+}
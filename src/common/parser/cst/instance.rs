@@ -0,0 +1,34 @@
+use super::{operator, Expression, Type};
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ============================================================================
+// Instance
+// ============================================================================
+/// instance of a class for a type `Eq ℕ := { eq := ... }`
+#[derive(Debug)]
+pub struct Instance {
+    /// class applied to the type of the instance
+    pub ty: Type,
+    pub eq_def: operator::EqDef,
+    /// dictionary of the instance, a record of the methods of the class
+    pub body: Expression,
+}
+
+impl Located for Instance {
+    fn loc(&self) -> Location {
+        self.ty.loc().union(self.body.loc())
+    }
+}
+
+impl Pretty for Instance {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.ty.pretty(theme))
+            .append(Doc::space())
+            .append(self.eq_def.pretty(theme))
+            .append(Doc::softline().append(self.body.pretty(theme).group()))
+            .nest(2)
+    }
+}
@@ -4,9 +4,14 @@ pub mod expression;
 pub mod expression_definition;
 pub mod file;
 pub mod help;
+pub mod instance;
+pub mod module;
 pub mod operator;
 pub mod parenthesis;
 pub mod pattern;
+pub mod record;
+pub mod tactic;
+pub mod theorem;
 pub mod ty;
 pub mod ty_definition;
 
@@ -15,9 +20,12 @@ pub use command::CommandKind;
 pub use constant::Constant;
 pub use expression::Expression;
 pub use expression_definition::ExpressionDefinition;
+pub use expression_definition::FixpointDefinition;
 pub use file::EndOfFile;
 pub use file::File;
+pub use instance::Instance;
 pub use pattern::Pattern;
+pub use theorem::Theorem;
 pub use ty::Type;
 pub use ty_definition::TypeDefinition;
 
@@ -31,7 +39,22 @@ pub trait AsNumber {
     fn as_number(&self) -> &num_bigint::BigUint;
 }
 
+pub trait AsInteger {
+    /// get the signed number as a reference to BigInt
+    fn as_integer(&self) -> &num_bigint::BigInt;
+}
+
+pub trait AsRational {
+    /// get the rational number
+    fn as_rational(&self) -> &crate::utils::rational::Rational;
+}
+
 pub trait AsCharacter {
     /// get character as a char
     fn as_character(&self) -> char;
 }
+
+pub trait AsString {
+    /// get string as a str
+    fn as_string(&self) -> &str;
+}
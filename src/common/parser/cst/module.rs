@@ -0,0 +1,58 @@
+use super::{AsIdentifier, AsString};
+use crate::lexer::Meta;
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ============================================================================
+// Name
+// ============================================================================
+/// name of module, qualified by its parent modules `M.N`
+#[derive(Debug, Clone)]
+pub struct NameT(String);
+pub type Name = Meta<NameT>;
+
+impl From<String> for NameT {
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+impl AsIdentifier for NameT {
+    /// get name of module
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Pretty for NameT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.def_var(&self.0)
+    }
+}
+
+// ============================================================================
+// Path
+// ============================================================================
+/// path of a required file, relative to the requiring file `"lib/list.st"`
+#[derive(Debug, Clone)]
+pub struct PathT(String);
+pub type Path = Meta<PathT>;
+
+impl From<String> for PathT {
+    fn from(path: String) -> Self {
+        Self(path)
+    }
+}
+
+impl AsString for PathT {
+    /// get path as written
+    fn as_string(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Pretty for PathT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.string(&self.0)
+    }
+}
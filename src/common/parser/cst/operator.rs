@@ -33,7 +33,7 @@ impl Pretty for ColonT {
 // ============================================================================
 #[derive(Debug, Clone)]
 pub struct LParenT();
-//pub type LParen = Meta<LParenT>;
+pub type LParen = Meta<LParenT>;
 
 impl Pretty for LParenT {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
@@ -46,7 +46,7 @@ impl Pretty for LParenT {
 // ============================================================================
 #[derive(Debug, Clone)]
 pub struct RParenT();
-//pub type RParen = Meta<RParenT>;
+pub type RParen = Meta<RParenT>;
 
 impl Pretty for RParenT {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
@@ -66,3 +66,397 @@ impl Pretty for DotT {
         theme.operator(&".")
     }
 }
+
+// ============================================================================
+// Arrow
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct ArrowT();
+pub type Arrow = Meta<ArrowT>;
+
+impl Pretty for ArrowT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"->")
+    }
+}
+
+// ============================================================================
+// Fat Arrow
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct FatArrowT();
+pub type FatArrow = Meta<FatArrowT>;
+
+impl Pretty for FatArrowT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"=>")
+    }
+}
+
+// ============================================================================
+// Comma
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct CommaT();
+pub type Comma = Meta<CommaT>;
+
+impl Pretty for CommaT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&",")
+    }
+}
+
+// ============================================================================
+// Star
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct StarT();
+pub type Star = Meta<StarT>;
+
+impl Pretty for StarT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"*")
+    }
+}
+
+// ============================================================================
+// Bar
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct BarT();
+pub type Bar = Meta<BarT>;
+
+impl Pretty for BarT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"|")
+    }
+}
+
+// ============================================================================
+// Less
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct LessT();
+pub type Less = Meta<LessT>;
+
+impl Pretty for LessT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"<")
+    }
+}
+
+// ============================================================================
+// Greater
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct GreaterT();
+pub type Greater = Meta<GreaterT>;
+
+impl Pretty for GreaterT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&">")
+    }
+}
+
+// ============================================================================
+// LBracket
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct LBracketT();
+pub type LBracket = Meta<LBracketT>;
+
+impl Pretty for LBracketT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"[")
+    }
+}
+
+// ============================================================================
+// RBracket
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct RBracketT();
+pub type RBracket = Meta<RBracketT>;
+
+impl Pretty for RBracketT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"]")
+    }
+}
+
+// ============================================================================
+// LBrace
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct LBraceT();
+pub type LBrace = Meta<LBraceT>;
+
+impl Pretty for LBraceT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"{")
+    }
+}
+
+// ============================================================================
+// RBrace
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct RBraceT();
+pub type RBrace = Meta<RBraceT>;
+
+impl Pretty for RBraceT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"}")
+    }
+}
+
+// ============================================================================
+// Semicolon
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct SemicolonT();
+pub type Semicolon = Meta<SemicolonT>;
+
+impl Pretty for SemicolonT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&";")
+    }
+}
+
+// ============================================================================
+// Projection
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct ProjectionT();
+pub type Projection = Meta<ProjectionT>;
+
+impl Pretty for ProjectionT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&".")
+    }
+}
+
+// ============================================================================
+// Assign
+// ============================================================================
+/// write of a reference, looser than every binary operator, not associative
+#[derive(Debug, Clone)]
+pub struct AssignT();
+pub type Assign = Meta<AssignT>;
+
+impl AssignT {
+    /// precedence level of assignment, see [`BinaryOperatorT`]
+    pub fn precedence(&self) -> u8 {
+        9
+    }
+}
+
+impl Pretty for AssignT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.operator(&"<-")
+    }
+}
+
+// ============================================================================
+// Binary Operator
+// ============================================================================
+/// associativity of a binary operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+    None,
+}
+
+/// binary operators of expressions, by precedence from the tightest to the loosest
+///
+/// | precedence | operators                      | associativity |
+/// |------------|--------------------------------|---------------|
+/// | 2          | `-` (prefix, on application)   |               |
+/// | 2          | `*` `/` `mod`                  | left          |
+/// | 3          | `+` `-`                        | left          |
+/// | 4          | `=` `<>` `<` `<=` `>` `>=`     | none          |
+/// | 5          | `not` (prefix)                 |               |
+/// | 6          | `&&` `/\`                      | right         |
+/// | 7          | `\|\|` `\/`                    | right         |
+/// | 8          | `-->`                          | right         |
+/// | 9          | `<-` (see [`AssignT`])         | none          |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperatorT {
+    Mul,
+    Div,
+    Mod,
+    Add,
+    Sub,
+    Eq,
+    NotEq,
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+    And,
+    Or,
+    Conj,
+    Disj,
+    Implies,
+}
+pub type BinaryOperator = Meta<BinaryOperatorT>;
+
+impl BinaryOperatorT {
+    /// precedence level of operator, a lower level binds tighter
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::Mul | Self::Div | Self::Mod => 2,
+            Self::Add | Self::Sub => 3,
+            Self::Eq
+            | Self::NotEq
+            | Self::Less
+            | Self::LessEq
+            | Self::Greater
+            | Self::GreaterEq => 4,
+            Self::And | Self::Conj => 6,
+            Self::Or | Self::Disj => 7,
+            Self::Implies => 8,
+        }
+    }
+
+    /// associativity of operator
+    pub fn associativity(&self) -> Associativity {
+        match self {
+            Self::Mul | Self::Div | Self::Mod | Self::Add | Self::Sub => Associativity::Left,
+            Self::Eq
+            | Self::NotEq
+            | Self::Less
+            | Self::LessEq
+            | Self::Greater
+            | Self::GreaterEq => Associativity::None,
+            Self::And | Self::Or | Self::Conj | Self::Disj | Self::Implies => Associativity::Right,
+        }
+    }
+
+    /// precedences of left and right operands
+    pub fn operands_precedence(&self) -> (u8, u8) {
+        let prec = self.precedence();
+        match self.associativity() {
+            Associativity::Left => (prec, prec - 1),
+            Associativity::Right => (prec - 1, prec),
+            Associativity::None => (prec - 1, prec - 1),
+        }
+    }
+
+    /// operator is a logical connective between propositions
+    pub fn is_connective(&self) -> bool {
+        matches!(self, Self::Conj | Self::Disj | Self::Implies)
+    }
+}
+
+impl std::fmt::Display for BinaryOperatorT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mul => write!(f, "*"),
+            Self::Div => write!(f, "/"),
+            Self::Mod => write!(f, "mod"),
+            Self::Add => write!(f, "+"),
+            Self::Sub => write!(f, "-"),
+            Self::Eq => write!(f, "="),
+            Self::NotEq => write!(f, "<>"),
+            Self::Less => write!(f, "<"),
+            Self::LessEq => write!(f, "<="),
+            Self::Greater => write!(f, ">"),
+            Self::GreaterEq => write!(f, ">="),
+            Self::And => write!(f, "&&"),
+            Self::Or => write!(f, "||"),
+            Self::Conj => write!(f, "/\\"),
+            Self::Disj => write!(f, "\\/"),
+            Self::Implies => write!(f, "-->"),
+        }
+    }
+}
+
+impl Pretty for BinaryOperatorT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Self::Mod => theme.keyword(&self.to_string()),
+            _ => theme.operator(&self.to_string()),
+        }
+    }
+}
+
+// ============================================================================
+// Unary Operator
+// ============================================================================
+/// prefix operators of expressions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOperatorT {
+    Not,
+    Neg,
+}
+pub type UnaryOperator = Meta<UnaryOperatorT>;
+
+impl UnaryOperatorT {
+    /// precedence level of operator, see [`BinaryOperatorT`]
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::Not => 5,
+            Self::Neg => 2,
+        }
+    }
+
+    /// precedence of operand, `-` only applies to an application without parenthesis
+    pub fn operand_precedence(&self) -> u8 {
+        match self {
+            Self::Not => 5,
+            Self::Neg => 1,
+        }
+    }
+}
+
+impl std::fmt::Display for UnaryOperatorT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Not => write!(f, "not"),
+            Self::Neg => write!(f, "-"),
+        }
+    }
+}
+
+impl Pretty for UnaryOperatorT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Self::Not => theme.keyword(&self.to_string()),
+            Self::Neg => theme.operator(&self.to_string()),
+        }
+    }
+}
+
+// ============================================================================
+// Reference Operator
+// ============================================================================
+/// prefix operators on references, they bind tighter than binary operators
+/// but looser than application
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceOperatorT {
+    /// allocation of a new reference
+    Alloc,
+    /// read of a reference
+    Deref,
+}
+pub type ReferenceOperator = Meta<ReferenceOperatorT>;
+
+impl std::fmt::Display for ReferenceOperatorT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alloc => write!(f, "ref"),
+            Self::Deref => write!(f, "!"),
+        }
+    }
+}
+
+impl Pretty for ReferenceOperatorT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Self::Alloc => theme.keyword(&self.to_string()),
+            Self::Deref => theme.operator(&self.to_string()),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::operator;
 use crate::lexer::meta::Meta;
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
@@ -62,6 +63,37 @@ where
     }
 }
 
+// ============================================================================
+// unit
+// ============================================================================
+/// empty parentheses `()`, the unit value or the unit type
+#[derive(Debug, Clone)]
+pub struct Unit {
+    l_paren: operator::LParen,
+    r_paren: operator::RParen,
+}
+
+impl Unit {
+    /// Create a new unit
+    pub fn new(l_paren: operator::LParen, r_paren: operator::RParen) -> Self {
+        Self { l_paren, r_paren }
+    }
+}
+
+impl Located for Unit {
+    fn loc(&self) -> Location {
+        self.l_paren.loc().union(self.r_paren.loc())
+    }
+}
+
+impl Pretty for Unit {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.l_paren.pretty(theme))
+            .append(self.r_paren.pretty(theme))
+    }
+}
+
 // ============================================================================
 // Level of expression
 // ============================================================================
@@ -3,7 +3,8 @@ use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
 
-use super::AsIdentifier;
+use super::parenthesis::{Parenthesed, PrettyPrecedence};
+use super::{operator, AsIdentifier, Type};
 
 // ============================================================================
 // Variable
@@ -31,33 +32,177 @@ impl AsIdentifier for VariableT {
     }
 }
 
+// ============================================================================
+// Typed Pattern
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct TypedPattern {
+    pub pattern: Box<Pattern>,
+    pub colon: operator::Colon,
+    pub ty: Type,
+}
+
+impl PrettyPrecedence for TypedPattern {
+    fn precedence(&self) -> u8 {
+        3
+    }
+
+    fn pretty_precedence(&self, _: u8, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.pattern.pretty(theme))
+            .append(Doc::space())
+            .append(self.colon.pretty(theme))
+            .append(Doc::space())
+            .append(self.ty.pretty(theme))
+            .group()
+    }
+}
+
+impl Located for TypedPattern {
+    fn loc(&self) -> Location {
+        self.pattern.loc().union(self.ty.loc())
+    }
+}
+
 // ============================================================================
 // Pattern
 // ============================================================================
 
-#[derive(Debug)]
-pub enum Pattern {
+#[derive(Debug, Clone)]
+pub enum Pattern0 {
+    /// variable pattern
     Variable(Variable),
+
+    /// typed pattern
+    Typed(Box<Parenthesed<operator::LParenT, TypedPattern, operator::RParenT>>),
+
+    /// parenthesized pattern
+    Paren(Box<Parenthesed<operator::LParenT, Box<Pattern>, operator::RParenT>>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Pattern1 {
+    /// constructor pattern
+    Constructor {
+        name: Variable,
+        args: Vec<Pattern0>,
+    },
+    Pattern0(Pattern0),
+}
+
+#[derive(Debug, Clone)]
+pub enum Pattern2 {
+    /// tuple pattern
+    Tuple {
+        first: Pattern1,
+        rest: Vec<(operator::Comma, Pattern1)>,
+    },
+    Pattern1(Pattern1),
 }
 
-impl From<Variable> for Pattern {
+pub type Pattern = Pattern2;
+
+impl From<Variable> for Pattern0 {
     fn from(variable: Variable) -> Self {
-        Pattern::Variable(variable)
+        Pattern0::Variable(variable)
     }
 }
 
-impl Pretty for Pattern {
-    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+impl PrettyPrecedence for Pattern0 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Pattern0::Paren(paren) => paren.precedence(),
+            _ => 0,
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Pattern0::Variable(var) => var.pretty(theme),
+            Pattern0::Typed(typed) => typed.pretty_precedence(0, theme),
+            Pattern0::Paren(paren) => paren.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Pattern0 {
+    fn loc(&self) -> Location {
+        match self {
+            Pattern0::Variable(var) => var.loc(),
+            Pattern0::Typed(typed) => typed.loc(),
+            Pattern0::Paren(paren) => paren.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Pattern1 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Pattern1::Constructor { .. } => 1,
+            Pattern1::Pattern0(pattern) => pattern.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Pattern1::Constructor { name, args } => args
+                .iter()
+                .fold(name.pretty(theme), |doc, arg| {
+                    doc.append(Doc::line())
+                        .append(arg.pretty_precedence(0, theme))
+                })
+                .group(),
+            Pattern1::Pattern0(pattern) => pattern.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Pattern1 {
+    fn loc(&self) -> Location {
+        match self {
+            Pattern1::Constructor { name, args } => match args.last() {
+                Some(last) => name.loc().union(last.loc()),
+                None => name.loc(),
+            },
+            Pattern1::Pattern0(pattern) => pattern.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Pattern2 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Pattern2::Tuple { .. } => 2,
+            Pattern2::Pattern1(pattern) => pattern.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
         match self {
-            Pattern::Variable(var) => var.pretty(theme),
+            Pattern2::Tuple { first, rest } => rest
+                .iter()
+                .fold(
+                    first.pretty_precedence(1, theme),
+                    |doc, (comma, pattern)| {
+                        doc.append(comma.pretty(theme))
+                            .append(Doc::line())
+                            .append(pattern.pretty_precedence(1, theme))
+                    },
+                )
+                .group(),
+            Pattern2::Pattern1(pattern) => pattern.pretty_precedence(prec, theme),
         }
     }
 }
 
-impl Located for Pattern {
+impl Located for Pattern2 {
     fn loc(&self) -> Location {
         match self {
-            Pattern::Variable(var) => var.loc(),
+            Pattern2::Tuple { first, rest } => match rest.last() {
+                Some((_, last)) => first.loc().union(last.loc()),
+                None => first.loc(),
+            },
+            Pattern2::Pattern1(pattern) => pattern.loc(),
         }
     }
 }
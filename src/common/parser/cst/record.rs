@@ -0,0 +1,190 @@
+use super::parenthesis::PrettyPrecedence;
+use super::{operator, AsIdentifier, Expression, Type};
+use crate::lexer::meta::Meta;
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ============================================================================
+// Field
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct FieldT(String);
+pub type Field = Meta<FieldT>;
+
+impl From<String> for FieldT {
+    fn from(name: String) -> Self {
+        Self(name)
+    }
+}
+
+impl AsIdentifier for FieldT {
+    /// get name of field
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Pretty for FieldT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.expr_var(&self.0)
+    }
+}
+
+// ============================================================================
+// With Keyword
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct WithKeywordT();
+pub type WithKeyword = Meta<WithKeywordT>;
+
+impl Pretty for WithKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"with")
+    }
+}
+
+/// pretty fields between braces `{ f1; f2 }`
+fn pretty_braces<'a>(
+    l_brace: &'a operator::LBrace,
+    head: Doc<'a>,
+    fields: impl Iterator<Item = Doc<'a>>,
+    r_brace: &'a operator::RBrace,
+    theme: &Theme,
+) -> Doc<'a> {
+    Doc::nil()
+        .append(l_brace.pretty(theme))
+        .append(Doc::space())
+        .append(head)
+        .append(Doc::intersperse(
+            fields,
+            theme.operator(&";").append(Doc::line()),
+        ))
+        .append(Doc::space())
+        .append(r_brace.pretty(theme))
+        .nest(2)
+        .group()
+}
+
+// ============================================================================
+// Record Declaration
+// ============================================================================
+/// field of a record type `x : τ`
+#[derive(Debug, Clone)]
+pub struct FieldDeclaration {
+    pub name: Field,
+    pub colon: operator::Colon,
+    pub ty: Type,
+}
+
+impl Pretty for FieldDeclaration {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.name.pretty(theme))
+            .append(Doc::space())
+            .append(self.colon.pretty(theme))
+            .append(Doc::space())
+            .append(self.ty.pretty(theme))
+            .group()
+    }
+}
+
+impl Located for FieldDeclaration {
+    fn loc(&self) -> Location {
+        self.name.loc().union(self.ty.loc())
+    }
+}
+
+/// fields of a record type `{ x : τ1; y : τ2 }`
+#[derive(Debug, Clone)]
+pub struct RecordDeclaration {
+    pub l_brace: operator::LBrace,
+    pub fields: Vec<FieldDeclaration>,
+    pub r_brace: operator::RBrace,
+}
+
+impl Pretty for RecordDeclaration {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        pretty_braces(
+            &self.l_brace,
+            Doc::nil(),
+            self.fields.iter().map(|field| field.pretty(theme)),
+            &self.r_brace,
+            theme,
+        )
+    }
+}
+
+impl Located for RecordDeclaration {
+    fn loc(&self) -> Location {
+        self.l_brace.loc().union(self.r_brace.loc())
+    }
+}
+
+// ============================================================================
+// Record Expression
+// ============================================================================
+/// value of a field `x := e`
+#[derive(Debug, Clone)]
+pub struct FieldValue {
+    pub name: Field,
+    pub eq_def: operator::EqDef,
+    pub value: Box<Expression>,
+}
+
+impl Pretty for FieldValue {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.name.pretty(theme))
+            .append(Doc::space())
+            .append(self.eq_def.pretty(theme))
+            .append(
+                Doc::line()
+                    .append(self.value.pretty_precedence(11, theme))
+                    .nest(2),
+            )
+            .group()
+    }
+}
+
+impl Located for FieldValue {
+    fn loc(&self) -> Location {
+        self.name.loc().union(self.value.loc())
+    }
+}
+
+/// record `{ x := e1; y := e2 }` or update of a record `{ e with x := e1 }`
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub l_brace: operator::LBrace,
+    /// updated record
+    pub base: Option<(Box<Expression>, WithKeyword)>,
+    pub fields: Vec<FieldValue>,
+    pub r_brace: operator::RBrace,
+}
+
+impl Pretty for Record {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let head = match &self.base {
+            Some((base, kw_with)) => Doc::nil()
+                .append(base.pretty_precedence(11, theme))
+                .append(Doc::space())
+                .append(kw_with.pretty(theme))
+                .append(Doc::line()),
+            None => Doc::nil(),
+        };
+        pretty_braces(
+            &self.l_brace,
+            head,
+            self.fields.iter().map(|field| field.pretty(theme)),
+            &self.r_brace,
+            theme,
+        )
+    }
+}
+
+impl Located for Record {
+    fn loc(&self) -> Location {
+        self.l_brace.loc().union(self.r_brace.loc())
+    }
+}
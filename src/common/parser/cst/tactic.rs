@@ -0,0 +1,43 @@
+use super::{pattern, Expression};
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ============================================================================
+// Tactic Arguments
+// ============================================================================
+#[derive(Debug)]
+pub enum TacticArgs {
+    /// tactic without argument, like `split`
+    None,
+    /// names introduced by `intro`
+    Names(Vec<pattern::Variable>),
+    /// proof or witness given to `apply`, `exact` or `exists`
+    Expr(Box<Expression>),
+}
+
+impl TacticArgs {
+    /// location of arguments, if any
+    pub fn loc(&self) -> Option<Location> {
+        match self {
+            TacticArgs::None => None,
+            TacticArgs::Names(names) => names
+                .iter()
+                .map(|name| name.loc())
+                .reduce(|loc, other| loc.union(other)),
+            TacticArgs::Expr(expr) => Some(expr.loc()),
+        }
+    }
+}
+
+impl Pretty for TacticArgs {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            TacticArgs::None => Doc::nil(),
+            TacticArgs::Names(names) => {
+                Doc::intersperse(names.iter().map(|name| name.pretty(theme)), Doc::line()).group()
+            }
+            TacticArgs::Expr(expr) => expr.pretty(theme).group(),
+        }
+    }
+}
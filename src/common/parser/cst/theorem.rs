@@ -0,0 +1,46 @@
+use super::{expression, operator, Expression};
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ============================================================================
+// Theorem
+// ============================================================================
+#[derive(Debug)]
+pub struct Theorem {
+    pub name: expression::Variable,
+    pub colon: operator::Colon,
+    pub statement: Expression,
+    /// proof term, or `None` when the proof is given by tactics
+    pub proof: Option<(operator::EqDef, Expression)>,
+}
+
+impl Located for Theorem {
+    fn loc(&self) -> Location {
+        match &self.proof {
+            Some((_, proof)) => self.name.loc().union(proof.loc()),
+            None => self.name.loc().union(self.statement.loc()),
+        }
+    }
+}
+
+impl Pretty for Theorem {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let doc = Doc::nil()
+            .append(self.name.pretty(theme))
+            .append(Doc::space())
+            .append(self.colon.pretty(theme))
+            .append(
+                Doc::softline()
+                    .append(self.statement.pretty(theme).group())
+                    .nest(2),
+            );
+        match &self.proof {
+            Some((eq_def, proof)) => doc
+                .append(Doc::space())
+                .append(eq_def.pretty(theme))
+                .append(Doc::softline().append(proof.pretty(theme).group())),
+            None => doc,
+        }
+    }
+}
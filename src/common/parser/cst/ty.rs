@@ -3,7 +3,8 @@ use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
 
-use super::AsIdentifier;
+use super::parenthesis::{Parenthesed, PrettyPrecedence, Unit};
+use super::{operator, AsIdentifier};
 
 // ============================================================================
 // Variable
@@ -38,8 +39,12 @@ impl Pretty for VariableT {
 #[derive(Debug, Clone)]
 pub enum BuiltinT {
     Nat,
+    Int,
+    Rat,
     Bool,
     Char,
+    String,
+    Prop,
 }
 pub type Builtin = Meta<BuiltinT>;
 
@@ -47,35 +52,218 @@ impl Pretty for BuiltinT {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         match self {
             BuiltinT::Nat => theme.ty_var(&"__Type_Nat__"),
+            BuiltinT::Int => theme.ty_var(&"__Type_Int__"),
+            BuiltinT::Rat => theme.ty_var(&"__Type_Rat__"),
             BuiltinT::Bool => theme.ty_var(&"__Type_Bool__"),
             BuiltinT::Char => theme.ty_var(&"__Type_Char__"),
+            BuiltinT::String => theme.ty_var(&"__Type_String__"),
+            BuiltinT::Prop => theme.ty_var(&"__Type_Prop__"),
         }
     }
 }
 
+// ============================================================================
+// Reference Keyword
+// ============================================================================
+#[derive(Debug, Clone)]
+pub struct RefKeywordT();
+pub type RefKeyword = Meta<RefKeywordT>;
+
+impl Pretty for RefKeywordT {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.keyword(&"ref")
+    }
+}
+
 // ============================================================================
 // Type
 // ============================================================================
 #[derive(Debug, Clone)]
-pub enum Type {
+pub enum Type0 {
+    /// builtin type
     Builtin(Builtin),
+
+    /// type variable
     Variable(Variable),
+
+    /// unit type
+    Unit(Unit),
+
+    /// parenthesized type
+    Paren(Parenthesed<operator::LParenT, Box<Type>, operator::RParenT>),
 }
 
-impl Pretty for Type {
-    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+#[derive(Debug, Clone)]
+pub enum Type1 {
+    /// type application (assoc left)
+    Application {
+        fun: Variable,
+        args: Vec<Type0>,
+    },
+    /// reference type
+    Reference {
+        kw: RefKeyword,
+        ty: Type0,
+    },
+    Type0(Type0),
+}
+
+#[derive(Debug, Clone)]
+pub enum Type2 {
+    /// product type
+    Product {
+        first: Type1,
+        rest: Vec<(operator::Star, Type1)>,
+    },
+    Type1(Type1),
+}
+
+#[derive(Debug, Clone)]
+pub enum Type3 {
+    /// arrow type (assoc right)
+    Arrow {
+        arg: Type2,
+        arrow: operator::Arrow,
+        ret: Box<Type3>,
+    },
+    Type2(Type2),
+}
+
+pub type Type = Type3;
+
+impl PrettyPrecedence for Type0 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Type0::Paren(paren) => paren.precedence(),
+            _ => 0,
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Type0::Builtin(builtin) => builtin.pretty(theme),
+            Type0::Unit(unit) => unit.pretty(theme),
+            Type0::Variable(var) => var.pretty(theme),
+            Type0::Paren(paren) => paren.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Type0 {
+    fn loc(&self) -> Location {
+        match self {
+            Type0::Builtin(builtin) => builtin.loc(),
+            Type0::Unit(unit) => unit.loc(),
+            Type0::Variable(var) => var.loc(),
+            Type0::Paren(paren) => paren.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Type1 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Type1::Application { .. } | Type1::Reference { .. } => 1,
+            Type1::Type0(ty) => ty.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Type1::Application { fun, args } => args
+                .iter()
+                .fold(fun.pretty(theme), |doc, arg| {
+                    doc.append(Doc::softline())
+                        .append(arg.pretty_precedence(0, theme))
+                })
+                .group()
+                .nest(2),
+            Type1::Reference { kw, ty } => Doc::nil()
+                .append(kw.pretty(theme))
+                .append(Doc::softline().append(ty.pretty_precedence(0, theme)))
+                .group()
+                .nest(2),
+            Type1::Type0(ty) => ty.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Type1 {
+    fn loc(&self) -> Location {
+        match self {
+            Type1::Application { fun, args } => match args.last() {
+                Some(last) => fun.loc().union(last.loc()),
+                None => fun.loc(),
+            },
+            Type1::Reference { kw, ty } => kw.loc().union(ty.loc()),
+            Type1::Type0(ty) => ty.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Type2 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Type2::Product { .. } => 2,
+            Type2::Type1(ty) => ty.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        match self {
+            Type2::Product { first, rest } => rest
+                .iter()
+                .fold(first.pretty_precedence(1, theme), |doc, (star, ty)| {
+                    doc.append(Doc::space())
+                        .append(star.pretty(theme))
+                        .append(Doc::softline())
+                        .append(ty.pretty_precedence(1, theme))
+                })
+                .group(),
+            Type2::Type1(ty) => ty.pretty_precedence(prec, theme),
+        }
+    }
+}
+
+impl Located for Type2 {
+    fn loc(&self) -> Location {
+        match self {
+            Type2::Product { first, rest } => match rest.last() {
+                Some((_, last)) => first.loc().union(last.loc()),
+                None => first.loc(),
+            },
+            Type2::Type1(ty) => ty.loc(),
+        }
+    }
+}
+
+impl PrettyPrecedence for Type3 {
+    fn precedence(&self) -> u8 {
+        match self {
+            Type3::Arrow { .. } => 3,
+            Type3::Type2(ty) => ty.precedence(),
+        }
+    }
+
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
         match self {
-            Type::Variable(var) => var.pretty(theme),
-            Type::Builtin(builtin) => builtin.pretty(theme),
+            Type3::Arrow { arg, arrow, ret } => Doc::nil()
+                .append(arg.pretty_precedence(2, theme))
+                .append(Doc::space())
+                .append(arrow.pretty(theme))
+                .append(Doc::softline())
+                .append(ret.pretty_precedence(3, theme))
+                .group(),
+            Type3::Type2(ty) => ty.pretty_precedence(prec, theme),
         }
     }
 }
 
-impl Located for Type {
+impl Located for Type3 {
     fn loc(&self) -> Location {
         match self {
-            Type::Variable(var) => var.loc(),
-            Type::Builtin(builtin) => builtin.loc(),
+            Type3::Arrow { arg, ret, .. } => arg.loc().union(ret.loc()),
+            Type3::Type2(ty) => ty.loc(),
         }
     }
 }
@@ -1,30 +1,135 @@
-use super::{operator, ty};
+use super::parenthesis::PrettyPrecedence;
+use super::{operator, pattern, record, ty};
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
 
+// ============================================================================
+// Constructor
+// ============================================================================
+#[derive(Debug)]
+pub enum ConstructorArgs {
+    /// constructor with its full type `A : τ`
+    Typed {
+        colon: operator::Colon,
+        ty: Box<ty::Type>,
+    },
+    /// constructor with types of its arguments `A τ1 τ2`
+    Args(Vec<ty::Type0>),
+}
+
+#[derive(Debug)]
+pub struct Constructor {
+    pub bar: operator::Bar,
+    pub name: pattern::Variable,
+    pub args: ConstructorArgs,
+}
+
+impl Pretty for Constructor {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let doc_args = match &self.args {
+            ConstructorArgs::Typed { colon, ty } => Doc::space()
+                .append(colon.pretty(theme))
+                .append(Doc::space())
+                .append(ty.pretty(theme)),
+            ConstructorArgs::Args(tys) => tys.iter().fold(Doc::nil(), |doc, ty| {
+                doc.append(Doc::space())
+                    .append(ty.pretty_precedence(0, theme))
+            }),
+        };
+        Doc::nil()
+            .append(self.bar.pretty(theme))
+            .append(Doc::space())
+            .append(self.name.pretty(theme))
+            .append(doc_args)
+            .group()
+    }
+}
+
+impl Located for Constructor {
+    fn loc(&self) -> Location {
+        let loc = self.bar.loc().union(self.name.loc());
+        match &self.args {
+            ConstructorArgs::Typed { ty, .. } => loc.union(ty.loc()),
+            ConstructorArgs::Args(tys) => match tys.last() {
+                Some(ty) => loc.union(ty.loc()),
+                None => loc,
+            },
+        }
+    }
+}
+
+// ============================================================================
+// Type Definition
+// ============================================================================
+#[derive(Debug)]
+pub enum TypeDefinitionBody {
+    /// alias of a type
+    Alias(Box<ty::Type>),
+    /// union of constructors
+    Union(Vec<Constructor>),
+    /// record with named fields
+    Record(record::RecordDeclaration),
+}
+
+impl Pretty for TypeDefinitionBody {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            TypeDefinitionBody::Alias(ty) => {
+                Doc::softline().append(ty.pretty(theme).group()).nest(2)
+            }
+            TypeDefinitionBody::Union(constructors) => Doc::line()
+                .append(Doc::intersperse(
+                    constructors
+                        .iter()
+                        .map(|constructor| constructor.pretty(theme)),
+                    Doc::line(),
+                ))
+                .group(),
+            TypeDefinitionBody::Record(record) => {
+                Doc::line().append(record.pretty(theme)).nest(2).group()
+            }
+        }
+    }
+}
+
+impl Located for TypeDefinitionBody {
+    fn loc(&self) -> Location {
+        match self {
+            TypeDefinitionBody::Alias(ty) => ty.loc(),
+            TypeDefinitionBody::Union(constructors) => constructors
+                .iter()
+                .map(Located::loc)
+                .reduce(|loc1, loc2| loc1.union(loc2))
+                .unwrap_or_else(Location::unknown),
+            TypeDefinitionBody::Record(record) => record.loc(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TypeDefinition {
     pub name: ty::Variable,
+    pub params: Vec<ty::Variable>,
     pub eq_def: operator::EqDef,
-    pub ty: ty::Type,
+    pub body: TypeDefinitionBody,
 }
 
 impl Pretty for TypeDefinition {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
-        let doc_ty = Doc::softline()
-            .append(self.ty.pretty(theme).group())
-            .nest(2);
         Doc::nil()
             .append(self.name.pretty(theme))
+            .append(self.params.iter().fold(Doc::nil(), |doc, param| {
+                doc.append(Doc::space()).append(param.pretty(theme))
+            }))
             .append(Doc::space())
             .append(self.eq_def.pretty(theme))
-            .append(doc_ty)
+            .append(self.body.pretty(theme))
     }
 }
 
 impl Located for TypeDefinition {
     fn loc(&self) -> Location {
-        self.name.loc().union(self.ty.loc())
+        self.name.loc().union(self.body.loc())
     }
 }
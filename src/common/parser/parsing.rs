@@ -1,9 +1,11 @@
 use super::cst;
 use super::ErrorChumsky;
+use crate::lexer::keyword;
 use crate::lexer::meta::Meta;
 use crate::lexer::token::{MetaToken, Operator, Token};
 use chumsky::input::ValueInput;
 use chumsky::prelude::*;
+use num_bigint::BigInt;
 
 // ===========================================================================
 // Operator
@@ -11,7 +13,7 @@ use chumsky::prelude::*;
 pub fn operator<'tokens, I, Op>(
     op: Operator,
     res: Op,
-) -> impl Parser<'tokens, I, Meta<Op>, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, Meta<Op>, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
     Op: Clone,
@@ -21,26 +23,104 @@ where
     }
 }
 
+/// identifiers reserved as keywords inside commands
+const KEYWORDS: &[&str] = &[
+    "with", "and", "mod", "not", "if", "then", "else", "let", "in", "forall", "exists", "requires",
+    "ensures", "ref",
+];
+
+/// identifier is not reserved as a keyword
+fn is_not_keyword(name: &str) -> bool {
+    !KEYWORDS.contains(&name)
+}
+
+/// parse name qualified by the modules containing it
+/// ```ebfn
+/// qualified_name := IDENTIFIER (PROJECTION IDENTIFIER)*
+///```
+fn qualified_name<'tokens, I>(
+) -> impl Parser<'tokens, I, Meta<String>, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    let name = select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} if is_not_keyword(s) =>
+            meta.clone().map(|_| s.clone())
+    };
+    let projection = operator(Operator::Projection, ()).labelled(".<field>");
+    name.foldl(projection.ignore_then(name).repeated(), |path, name| {
+        path.merge(name, |path, name| format!("{path}.{name}"))
+    })
+}
+
 // ===========================================================================
 // Pattern
 // ===========================================================================
 
 /// parse pattern
 /// ```ebfn
-/// pattern :=
-/// | IDENTIFIER
+/// pattern@0 :=
+/// | qualified_name
+/// | "(" pattern ")"
+/// | "(" pattern COLON type ")"
+///
+/// pattern@1 :=
+/// | qualified_name pattern@0+
+/// | pattern@0
+///
+/// pattern@2 :=
+/// | pattern@1 (COMMA pattern@1)+
+/// | pattern@1
+///
+/// pattern := pattern@2
 ///```
-pub fn pattern<'tokens, I>() -> impl Parser<'tokens, I, cst::Pattern, ErrorChumsky<'tokens>>
+pub fn pattern<'tokens, I>() -> impl Parser<'tokens, I, cst::Pattern, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
-    let variable = select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} =>
-            meta.clone().map(|_| cst::pattern::VariableT::from(s.clone()))
-    }
-    .labelled("pattern variable")
-    .map(cst::Pattern::from);
+    use cst::parenthesis::Parenthesed;
+    use cst::pattern::{Pattern0, Pattern1, Pattern2, TypedPattern};
+
+    recursive(|pattern| {
+        let variable = qualified_name()
+            .map(|name| name.map(cst::pattern::VariableT::from))
+            .labelled("pattern variable");
+
+        let parens = {
+            let l_paren = operator(Operator::LParen, cst::operator::LParenT()).labelled("(");
+            let r_paren = operator(Operator::RParen, cst::operator::RParenT()).labelled(")");
+            let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
+            l_paren
+                .then(pattern.map(Box::new))
+                .then(colon.then(ty()).or_not())
+                .then(r_paren)
+                .map(|(((l_paren, pattern), typed), r_paren)| match typed {
+                    Some((colon, ty)) => {
+                        let typed = TypedPattern { pattern, colon, ty };
+                        Pattern0::Typed(Box::new(Parenthesed::new(l_paren, typed, r_paren)))
+                    }
+                    None => Pattern0::Paren(Box::new(Parenthesed::new(l_paren, pattern, r_paren))),
+                })
+        };
+
+        let pattern0 = choice((variable.clone().map(Pattern0::from), parens));
+        let constructor = variable
+            .then(pattern0.clone().repeated().at_least(1).collect::<Vec<_>>())
+            .map(|(name, args)| Pattern1::Constructor { name, args });
+        let pattern1 = choice((constructor, pattern0.map(Pattern1::Pattern0)));
 
-    variable.labelled("pattern")
+        let comma = operator(Operator::Comma, cst::operator::CommaT()).labelled(",");
+        pattern1
+            .clone()
+            .then(comma.then(pattern1).repeated().collect::<Vec<_>>())
+            .map(|(first, rest)| {
+                if rest.is_empty() {
+                    Pattern2::Pattern1(first)
+                } else {
+                    Pattern2::Tuple { first, rest }
+                }
+            })
+    })
+    .labelled("pattern")
 }
 
 // ===========================================================================
@@ -51,23 +131,50 @@ where
 /// ```ebfn
 /// constant :=
 /// | NUMBER
+/// | INTEGER
+/// | RATIONAL
 /// | CHARACTER
+/// | STRING
 ///```
-pub fn constant<'tokens, I>() -> impl Parser<'tokens, I, cst::Constant, ErrorChumsky<'tokens>>
+pub fn constant<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::Constant, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
-    use cst::constant::{BuiltinT, CharacterT, Constant, NumberT};
+    use cst::constant::{BuiltinT, CharacterT, Constant, IntegerT, NumberT, RationalT, StringT};
     let number = select! {ref meta @ Meta{ value: Token::Number(ref n), ..} =>
             meta.clone().map(|_| NumberT::from(n.clone()))
     }
     .map(Constant::from);
 
+    let integer = select! {ref meta @ Meta{ value: Token::Integer(sign, ref n), ..} =>
+            meta.clone().map(|_| IntegerT::from(sign.apply(BigInt::from(n.clone()))))
+    }
+    .map(Constant::from);
+
+    let rational = select! {ref meta @ Meta{ value: Token::Rational(sign, ref q), ..} =>
+            meta.clone().map(|_| match sign {
+                Some(sign) => RationalT::from(sign.apply(q.clone())),
+                None => RationalT::from(q.clone()),
+            })
+    }
+    .map(Constant::from);
+
     let builtin = select! {
         ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Constant_true__" =>
             meta.clone().map(|_| BuiltinT::True),
         ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Constant_false__" =>
-            meta.clone().map(|_| BuiltinT::False)
+            meta.clone().map(|_| BuiltinT::False),
+        ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Function_int_of_nat__" =>
+            meta.clone().map(|_| BuiltinT::IntOfNat),
+        ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Function_nat_of_int__" =>
+            meta.clone().map(|_| BuiltinT::NatOfInt),
+        ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Function_string_length__" =>
+            meta.clone().map(|_| BuiltinT::StringLength),
+        ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Function_string_append__" =>
+            meta.clone().map(|_| BuiltinT::StringAppend),
+        ref meta @ Meta{ value: Token::Identifier(ref b), ..} if b == "__Function_string_get__" =>
+            meta.clone().map(|_| BuiltinT::StringGet)
     }
     .map(Constant::from);
 
@@ -76,7 +183,12 @@ where
     }
     .map(Constant::from);
 
-    choice((builtin, number, character)).labelled("constant")
+    let string = select! {ref meta @ Meta{ value: Token::String(ref s), ..} =>
+        meta.clone().map(|_| StringT::from(s.clone()))
+    }
+    .map(Constant::from);
+
+    choice((builtin, number, integer, rational, character, string)).labelled("constant")
 }
 
 /// parse variable
@@ -84,14 +196,78 @@ where
 /// variable := IDENTIFIER
 ///```
 pub fn variable<'tokens, I>(
-) -> impl Parser<'tokens, I, cst::expression::Variable, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, cst::expression::Variable, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     use cst::expression::VariableT;
-    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} =>
+    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} if is_not_keyword(s) =>
             meta.clone().map(|_| VariableT::from(s.clone()))
     }
+    .labelled("variable")
+}
+
+/// parse field name of a record
+pub fn field<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::record::Field, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::record::FieldT;
+    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} if is_not_keyword(s) =>
+            meta.clone().map(|_| FieldT::from(s.clone()))
+    }
+    .labelled("field")
+}
+
+/// parse record or update of a record
+/// ```ebfn
+/// field_value := field EQ_DEF expression
+/// record := L_BRACE (expression "with")? field_value (SEMICOLON field_value)* SEMICOLON? R_BRACE
+///```
+fn record<'tokens, I>(
+    expr: impl Parser<'tokens, I, cst::Expression, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::record::Record, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::record::{FieldValue, Record, WithKeywordT};
+
+    let l_brace = operator(Operator::LBrace, cst::operator::LBraceT()).labelled("{");
+    let r_brace = operator(Operator::RBrace, cst::operator::RBraceT()).labelled("}");
+    let semicolon = operator(Operator::Semicolon, cst::operator::SemicolonT()).labelled(";");
+    let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
+    let kw_with = select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "with" =>
+            meta.clone().map(|_| WithKeywordT())
+    }
+    .labelled("with");
+
+    let field_value =
+        field()
+            .then(eq_def)
+            .then(expr.clone().map(Box::new))
+            .map(|((name, eq_def), value)| FieldValue {
+                name,
+                eq_def,
+                value,
+            });
+    l_brace
+        .then(expr.map(Box::new).then(kw_with).or_not())
+        .then(
+            field_value
+                .separated_by(semicolon)
+                .allow_trailing()
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .then(r_brace)
+        .map(|(((l_brace, base), fields), r_brace)| Record {
+            l_brace,
+            base,
+            fields,
+            r_brace,
+        })
 }
 
 /// parse expression0
@@ -99,109 +275,622 @@ where
 /// expression0 :=
 /// | constant
 /// | variable
+/// | "(" ")"
 /// | "(" expression ")"
+/// | record
+/// | expression0 PROJECTION field
 ///```
 fn expression0<'tokens, I>(
-    expr: impl Parser<'tokens, I, cst::Expression, ErrorChumsky<'tokens>>,
-) -> impl Parser<'tokens, I, cst::expression::Expression0, ErrorChumsky<'tokens>>
+    expr: impl Parser<'tokens, I, cst::Expression, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::expression::Expression0, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     use cst::expression::Expression0;
-    use cst::parenthesis::Parenthesed;
+    use cst::parenthesis::{Parenthesed, Unit};
 
     let constant = constant().map(Expression0::Constant);
     let variable = variable().map(Expression0::Variable);
-    let parens = {
-        let l_paren = operator(Operator::LParen, cst::operator::LParenT()).labelled("(");
-        let r_paren = operator(Operator::RParen, cst::operator::RParenT()).labelled(")");
-        l_paren
-            .then(expr.map(Box::new))
-            .then(r_paren)
-            .map(|((l_paren, expr), r_paren)| Parenthesed::new(l_paren, expr, r_paren))
-            .map(Expression0::Paren)
-    };
+    let l_paren = operator(Operator::LParen, cst::operator::LParenT()).labelled("(");
+    let r_paren = operator(Operator::RParen, cst::operator::RParenT()).labelled(")");
+    let unit = l_paren
+        .clone()
+        .then(r_paren.clone())
+        .map(|(l_paren, r_paren)| Expression0::Unit(Unit::new(l_paren, r_paren)));
+    let parens = l_paren
+        .then(expr.clone().map(Box::new))
+        .then(r_paren)
+        .map(|((l_paren, expr), r_paren)| Parenthesed::new(l_paren, expr, r_paren))
+        .map(Expression0::Paren);
+
+    let record = record(expr).map(Expression0::Record);
+    let projection =
+        operator(Operator::Projection, cst::operator::ProjectionT()).labelled(".<field>");
 
-    choice((constant, variable, parens))
+    choice((constant, variable, unit, parens, record)).foldl(
+        projection.then(field()).repeated(),
+        |expr, (dot, field)| Expression0::Projection {
+            expr: Box::new(expr),
+            dot,
+            field,
+        },
+    )
+}
+
+/// parse prefix operator on references
+fn reference_operator<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::operator::ReferenceOperator, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::operator::ReferenceOperatorT;
+    select! {
+        ref meta @ Meta{ value: Token::Identifier(ref id), ..} if id == "ref" =>
+            meta.clone().map(|_| ReferenceOperatorT::Alloc),
+        ref meta @ Meta{ value: Token::Operator(Operator::Bang), ..} =>
+            meta.clone().map(|_| ReferenceOperatorT::Deref),
+    }
+    .labelled("reference operator")
 }
 
 /// parse expression1
 /// ```ebfn
 /// expr@1 :=
-/// | expr@0 COLON ty
+/// | ("ref" | BANG) expr@1
+/// | expr@1 expr@0
 /// | expr@0
 ///```
 fn expression1<'tokens, I>(
     expr0: impl Parser<'tokens, I, cst::expression::Expression0, ErrorChumsky<'tokens>> + Clone,
-) -> impl Parser<'tokens, I, cst::expression::Expression1, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, cst::expression::Expression1, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     use cst::expression::Expression1;
 
+    let application =
+        expr0
+            .clone()
+            .map(Expression1::Expression0)
+            .foldl(expr0.repeated(), |fun, arg| Expression1::Application {
+                fun: Box::new(fun),
+                arg,
+            });
+    reference_operator()
+        .repeated()
+        .foldr(application, |op, expr| Expression1::Reference {
+            op,
+            expr: Box::new(expr),
+        })
+}
+
+/// parse binary operator of precedence `prec`, see [`cst::operator::BinaryOperatorT`]
+fn binary_operator<'tokens, I>(
+    prec: u8,
+) -> impl Parser<'tokens, I, cst::operator::BinaryOperator, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::operator::BinaryOperatorT;
+    select! {
+        ref meta @ Meta{ value: Token::Operator(ref op), ..} => match op {
+            Operator::Star => Some(meta.clone().map(|_| BinaryOperatorT::Mul)),
+            Operator::Slash => Some(meta.clone().map(|_| BinaryOperatorT::Div)),
+            Operator::Plus => Some(meta.clone().map(|_| BinaryOperatorT::Add)),
+            Operator::Minus => Some(meta.clone().map(|_| BinaryOperatorT::Sub)),
+            Operator::Equal => Some(meta.clone().map(|_| BinaryOperatorT::Eq)),
+            Operator::NotEqual => Some(meta.clone().map(|_| BinaryOperatorT::NotEq)),
+            Operator::Less => Some(meta.clone().map(|_| BinaryOperatorT::Less)),
+            Operator::LessEqual => Some(meta.clone().map(|_| BinaryOperatorT::LessEq)),
+            Operator::Greater => Some(meta.clone().map(|_| BinaryOperatorT::Greater)),
+            Operator::GreaterEqual => Some(meta.clone().map(|_| BinaryOperatorT::GreaterEq)),
+            Operator::And => Some(meta.clone().map(|_| BinaryOperatorT::And)),
+            Operator::Or => Some(meta.clone().map(|_| BinaryOperatorT::Or)),
+            Operator::Conj => Some(meta.clone().map(|_| BinaryOperatorT::Conj)),
+            Operator::Disj => Some(meta.clone().map(|_| BinaryOperatorT::Disj)),
+            Operator::Implies => Some(meta.clone().map(|_| BinaryOperatorT::Implies)),
+            _ => None,
+        },
+        ref meta @ Meta{ value: Token::Identifier(ref id), ..} if id == "mod" =>
+            Some(meta.clone().map(|_| BinaryOperatorT::Mod)),
+    }
+    .try_map(move |op, span| match op {
+        Some(op) if op.value.precedence() == prec => Ok(op),
+        _ => Err(Rich::custom(span, "expected operator")),
+    })
+    .labelled("operator")
+}
+
+/// parse prefix operator of precedence `prec`
+fn unary_operator<'tokens, I>(
+    prec: u8,
+) -> impl Parser<'tokens, I, cst::operator::UnaryOperator, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::operator::UnaryOperatorT;
+    select! {
+        ref meta @ Meta{ value: Token::Operator(Operator::Minus), ..} =>
+            meta.clone().map(|_| UnaryOperatorT::Neg),
+        ref meta @ Meta{ value: Token::Identifier(ref id), ..} if id == "not" =>
+            meta.clone().map(|_| UnaryOperatorT::Not),
+    }
+    .try_map(move |op, span| match op {
+        op if op.value.precedence() == prec => Ok(op),
+        _ => Err(Rich::custom(span, "expected operator")),
+    })
+    .labelled("operator")
+}
+
+/// parse expression2, operators by precedence (see [`cst::operator::BinaryOperatorT`])
+/// ```ebfn
+/// expr@2.1 := MINUS expr@2.1 | expr@1
+/// expr@2.2 := expr@2.2 (STAR | SLASH | "mod") expr@2.1 | expr@2.1
+/// expr@2.3 := expr@2.3 (PLUS | MINUS) expr@2.2 | expr@2.2
+/// expr@2.4 := expr@2.3 (EQUAL | NOT_EQUAL | LESS | LESS_EQUAL | GREATER | GREATER_EQUAL) expr@2.3
+///           | expr@2.3
+/// expr@2.5 := "not" expr@2.5 | expr@2.4
+/// expr@2.6 := expr@2.5 (AND | CONJ) expr@2.6 | expr@2.5
+/// expr@2.7 := expr@2.6 (OR | DISJ) expr@2.7 | expr@2.6
+/// expr@2.8 := expr@2.7 IMPLIES expr@2.8 | expr@2.7
+/// expr@2.9 := expr@2.8 ASSIGN expr@2.8 | expr@2.8
+///
+/// expr@2 := expr@2.9
+///```
+fn expression2<'tokens, I>(
+    expr1: impl Parser<'tokens, I, cst::expression::Expression1, ErrorChumsky<'tokens>>
+        + Clone
+        + 'tokens,
+) -> impl Parser<'tokens, I, cst::expression::Expression2, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan> + 'tokens,
+{
+    use cst::expression::Expression2;
+
+    let binary = |left, op, right| Expression2::Binary {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    };
+    let left_assoc = |operand: Boxed<'tokens, 'tokens, I, Expression2, ErrorChumsky<'tokens>>,
+                      prec| {
+        operand
+            .clone()
+            .foldl(
+                binary_operator(prec).then(operand).repeated(),
+                move |left, (op, right)| binary(left, op, right),
+            )
+            .boxed()
+    };
+    let right_assoc = |operand: Boxed<'tokens, 'tokens, I, Expression2, ErrorChumsky<'tokens>>,
+                       prec| {
+        operand
+            .clone()
+            .then(binary_operator(prec))
+            .repeated()
+            .foldr(operand, move |(left, op), right| binary(left, op, right))
+            .boxed()
+    };
+
+    let unary = |op, expr| Expression2::Unary {
+        op,
+        expr: Box::new(expr),
+    };
+
+    let expr2_1 = unary_operator(2)
+        .repeated()
+        .foldr(expr1.map(Expression2::Expression1), unary)
+        .boxed();
+    let expr2_2 = left_assoc(expr2_1, 2);
+    let expr2_3 = left_assoc(expr2_2, 3);
+    let expr2_4 = expr2_3
+        .clone()
+        .then(binary_operator(4).then(expr2_3).or_not())
+        .map(move |(left, right)| match right {
+            Some((op, right)) => binary(left, op, right),
+            None => left,
+        })
+        .boxed();
+    let expr2_5 = unary_operator(5).repeated().foldr(expr2_4, unary).boxed();
+    let expr2_6 = right_assoc(expr2_5, 6);
+    let expr2_7 = right_assoc(expr2_6, 7);
+    let expr2_8 = right_assoc(expr2_7, 8);
+    let assign = operator(Operator::Assign, cst::operator::AssignT()).labelled("operator");
+    expr2_8
+        .clone()
+        .then(assign.then(expr2_8).or_not())
+        .map(|(reference, value)| match value {
+            Some((arrow, value)) => Expression2::Assign {
+                reference: Box::new(reference),
+                arrow,
+                value: Box::new(value),
+            },
+            None => reference,
+        })
+}
+
+/// parse expression3
+/// ```ebfn
+/// expr@3 :=
+/// | expr@2 COLON ty
+/// | expr@2
+///```
+fn expression3<'tokens, I>(
+    expr2: impl Parser<'tokens, I, cst::expression::Expression2, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::expression::Expression3, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression::Expression3;
+
     let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
-    let type_restriction = (expr0.clone())
-        .then(colon)
-        .then(ty())
-        .map(|((expr, colon), ty)| Expression1::TypedExpression { expr, colon, ty });
+    expr2
+        .then(colon.then(ty()).or_not())
+        .map(|(expr, type_restriction)| match type_restriction {
+            Some((colon, ty)) => Expression3::TypedExpression {
+                expr,
+                colon,
+                ty: Box::new(ty),
+            },
+            None => Expression3::Expression2(expr),
+        })
+}
+
+/// parse expression4
+/// ```ebfn
+/// expr@4 :=
+/// | expr@3 (COMMA expr@3)+
+/// | expr@3
+///```
+fn expression4<'tokens, I>(
+    expr3: impl Parser<'tokens, I, cst::expression::Expression3, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::expression::Expression4, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression::Expression4;
+
+    let comma = operator(Operator::Comma, cst::operator::CommaT()).labelled(",");
+    expr3
+        .clone()
+        .then(comma.then(expr3).repeated().collect::<Vec<_>>())
+        .map(|(first, rest)| {
+            if rest.is_empty() {
+                Expression4::Expression3(first)
+            } else {
+                Expression4::Tuple { first, rest }
+            }
+        })
+}
+
+/// parse keyword of conditional
+fn keyword_conditional<'tokens, I>(
+    keyword: cst::expression::ConditionalKeywordT,
+) -> impl Parser<'tokens, I, cst::expression::ConditionalKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression::ConditionalKeywordT;
+    let name = match keyword {
+        ConditionalKeywordT::If => "if",
+        ConditionalKeywordT::Then => "then",
+        ConditionalKeywordT::Else => "else",
+    };
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == name =>
+            meta.clone().map(|_| keyword.clone()),
+    }
+    .labelled(name)
+}
 
-    let expr0_in1 = expr0.map(Expression1::Expression0);
+/// parse keyword of local binding
+fn keyword_let<'tokens, I>(
+    keyword: cst::expression::LetKeywordT,
+) -> impl Parser<'tokens, I, cst::expression::LetKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression::LetKeywordT;
+    let name = match keyword {
+        LetKeywordT::Let => "let",
+        LetKeywordT::In => "in",
+    };
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == name =>
+            meta.clone().map(|_| keyword.clone()),
+    }
+    .labelled(name)
+}
+
+/// parse keyword of quantifier
+fn keyword_quantifier<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::expression::QuantifierKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression::QuantifierKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "forall" =>
+            meta.clone().map(|_| QuantifierKeywordT::Forall),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "exists" =>
+            meta.clone().map(|_| QuantifierKeywordT::Exists),
+    }
+    .labelled("quantifier")
+}
 
-    choice((type_restriction, expr0_in1))
+/// parse expression5
+/// ```ebfn
+/// expr@5 :=
+/// | pattern FAT_ARROW expr@5
+/// | (BAR pattern FAT_ARROW expr@5)+
+/// | "if" expr@5 "then" expr@5 "else" expr@5
+/// | "let" pattern EQ_DEF expr@5 "in" expr@5
+/// | ("forall" | "exists") variable+ (COLON type)? COMMA expr@5
+/// | expr@4
+///```
+fn expression5<'tokens, I>(
+    expr4: impl Parser<'tokens, I, cst::expression::Expression4, ErrorChumsky<'tokens>> + Clone,
+    expr: impl Parser<'tokens, I, cst::Expression, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::expression::Expression5, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression::{Branch, ConditionalKeywordT, Expression5, LetKeywordT};
+
+    let arrow = operator(Operator::FatArrow, cst::operator::FatArrowT()).labelled("=>");
+    let abstraction = pattern()
+        .then(arrow.clone())
+        .then(expr.clone().map(Box::new))
+        .map(|((pattern, arrow), body)| Expression5::Abstraction {
+            pattern,
+            arrow,
+            body,
+        });
+
+    let conditional = keyword_conditional(ConditionalKeywordT::If)
+        .then(expr.clone().map(Box::new))
+        .then(keyword_conditional(ConditionalKeywordT::Then))
+        .then(expr.clone().map(Box::new))
+        .then(keyword_conditional(ConditionalKeywordT::Else))
+        .then(expr.clone().map(Box::new))
+        .map(
+            |(((((kw_if, cond), kw_then), then_branch), kw_else), else_branch)| Expression5::If {
+                kw_if,
+                cond,
+                kw_then,
+                then_branch,
+                kw_else,
+                else_branch,
+            },
+        );
+
+    let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
+    let binding = keyword_let(LetKeywordT::Let)
+        .then(pattern())
+        .then(eq_def)
+        .then(expr.clone().map(Box::new))
+        .then(keyword_let(LetKeywordT::In))
+        .then(expr.clone().map(Box::new))
+        .map(
+            |(((((kw_let, pattern), eq_def), value), kw_in), body)| Expression5::Let {
+                kw_let,
+                pattern,
+                eq_def,
+                value,
+                kw_in,
+                body,
+            },
+        );
+
+    let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
+    let comma = operator(Operator::Comma, cst::operator::CommaT()).labelled(",");
+    let quantifier = keyword_quantifier()
+        .then(variable().repeated().at_least(1).collect::<Vec<_>>())
+        .then(colon.then(ty().map(Box::new)).or_not())
+        .then(comma)
+        .then(expr.clone().map(Box::new))
+        .map(
+            |((((kw, vars), typed), comma), body)| Expression5::Quantifier {
+                kw,
+                vars,
+                typed,
+                comma,
+                body,
+            },
+        );
+
+    let bar = operator(Operator::Bar, cst::operator::BarT()).labelled("|");
+    let branches = bar
+        .then(pattern())
+        .then(arrow)
+        .then(expr)
+        .map(|(((bar, pattern), arrow), body)| Branch {
+            bar,
+            pattern,
+            arrow,
+            body,
+        })
+        .repeated()
+        .at_least(1)
+        .collect::<Vec<_>>()
+        .map(|branches| Expression5::Match { branches });
+
+    let expr4_in5 = expr4.map(Expression5::Expression4);
+
+    choice((
+        quantifier,
+        abstraction,
+        branches,
+        conditional,
+        binding,
+        expr4_in5,
+    ))
 }
+
 /// parse expression
 /// ```ebfn
-/// expr0 :=
+/// expr@0 :=
 /// | variable
 /// | constant
-/// | "(" expr@1 ")"
+/// | "(" expr@5 ")"
 ///
 /// expr@1 :=
-/// | expr@0 colon ty
+/// | ("ref" | "!") expr@1
+/// | expr@1 expr@0
 /// | expr@0
+///
+/// expr@2 :=
+/// | expr@2 binary_operator expr@2   (see precedence table)
+/// | "not" expr@2
+/// | expr@2 "<-" expr@2
+/// | expr@1
+///
+/// expr@3 :=
+/// | expr@2 colon ty
+/// | expr@2
+///
+/// expr@4 :=
+/// | expr@3 ("," expr@3)+
+/// | expr@3
+///
+/// expr@5 :=
+/// | pattern "=>" expr@5
+/// | ("|" pattern "=>" expr@5)+
+/// | "if" expr@5 "then" expr@5 "else" expr@5
+/// | "let" pattern ":=" expr@5 "in" expr@5
+/// | ("forall" | "exists") variable+ (":" ty)? "," expr@5
+/// | expr@4
 ///```
-pub fn expression<'tokens, I>() -> impl Parser<'tokens, I, cst::Expression, ErrorChumsky<'tokens>>
+pub fn expression<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::Expression, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     recursive(move |expr| {
         let expr0 = expression0(expr.clone()).boxed();
         let expr1 = expression1(expr0).boxed();
-        expr1
+        let expr2 = expression2(expr1).boxed();
+        let expr3 = expression3(expr2).boxed();
+        let expr4 = expression4(expr3).boxed();
+        expression5(expr4, expr)
     })
     .labelled("expression")
 }
 
+/// parse keyword of contract clause
+fn keyword_contract<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::expression_definition::ContractKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::expression_definition::ContractKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "requires" =>
+            meta.clone().map(|_| ContractKeywordT::Requires),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "ensures" =>
+            meta.clone().map(|_| ContractKeywordT::Ensures),
+    }
+    .labelled("requires")
+}
+
 /// parse expression definition
 /// ```ebfn
-/// expr_definition := pattern (colon type)? EQ_DEF expression
+/// env_param := variable COLON type
+/// env_params := L_BRACKET env_param (COMMA env_param)* R_BRACKET
+///
+/// contract := ("requires" | "ensures") expression
+///
+/// expr_definition :=
+///     env_params? pattern (LESS type_variable+ GREATER)? (colon type)? contract* EQ_DEF expression
 ///```
 pub fn expression_definition<'tokens, I>(
-) -> impl Parser<'tokens, I, cst::ExpressionDefinition, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, cst::ExpressionDefinition, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
-    use cst::expression_definition::{ExpressionDefinition, TypedBy};
+    use cst::expression_definition::{
+        Binders, Contract, EnvParam, EnvParams, ExpressionDefinition, TypedBy,
+    };
 
+    let env = {
+        let l_bracket = operator(Operator::LBracket, cst::operator::LBracketT()).labelled("[");
+        let r_bracket = operator(Operator::RBracket, cst::operator::RBracketT()).labelled("]");
+        let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
+        let comma = operator(Operator::Comma, cst::operator::CommaT()).labelled(",");
+        let param = variable()
+            .then(colon)
+            .then(ty())
+            .map(|((name, colon), ty)| EnvParam { name, colon, ty });
+        l_bracket
+            .then(param.separated_by(comma).at_least(1).collect::<Vec<_>>())
+            .then(r_bracket)
+            .map(|((l_bracket, params), r_bracket)| EnvParams {
+                l_bracket,
+                params,
+                r_bracket,
+            })
+    };
+
+    let binders = {
+        let less = operator(Operator::Less, cst::operator::LessT()).labelled("<");
+        let greater = operator(Operator::Greater, cst::operator::GreaterT()).labelled(">");
+        less.then(ty_variable().repeated().at_least(1).collect::<Vec<_>>())
+            .then(greater)
+            .map(|((less, vars), greater)| Binders {
+                less,
+                vars,
+                greater,
+            })
+    };
     let typed_by = {
         let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
         colon.then(ty()).map(|(colon, ty)| TypedBy { colon, ty })
     };
+    let contract = keyword_contract()
+        .then(expression())
+        .map(|(keyword, expr)| Contract { keyword, expr });
     let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
-    pattern()
+    env.or_not()
+        .then(pattern())
+        .then(binders.or_not())
         .then(typed_by.or_not())
+        .then(contract.repeated().collect::<Vec<_>>())
         .then(eq_def)
         .then(expression())
         .map(
-            |(((pattern, typed_by), eq_def), body)| ExpressionDefinition {
-                pattern,
-                typed_by,
-                eq_def,
-                body,
+            |((((((env, pattern), binders), typed_by), contracts), eq_def), body)| {
+                ExpressionDefinition {
+                    env,
+                    pattern,
+                    binders,
+                    typed_by,
+                    contracts,
+                    eq_def,
+                    body,
+                }
             },
         )
 }
 
+/// parse mutually recursive definitions
+/// ```ebfn
+/// fixpoint_definition := expr_definition (keyword_mutual expr_definition)*
+///```
+pub fn fixpoint_definition<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::FixpointDefinition, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    expression_definition()
+        .then(
+            keyword_mutual()
+                .then(expression_definition())
+                .repeated()
+                .collect::<Vec<_>>(),
+        )
+        .map(|(first, rest)| cst::FixpointDefinition { first, rest })
+}
+
 // ===========================================================================
 // Type
 // ===========================================================================
@@ -209,18 +898,33 @@ where
 /// ```ebfn
 /// type_variable := IDENTIFIER
 /// ```
-pub fn ty_variable<'tokens, I>() -> impl Parser<'tokens, I, cst::ty::Variable, ErrorChumsky<'tokens>>
+pub fn ty_variable<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::ty::Variable, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
-    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} =>
+    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} if is_not_keyword(s) =>
             meta.clone().map(|_| cst::ty::VariableT::from(s.clone()))
     }
     .labelled("type variable")
 }
 
+/// parse use of a type variable, qualified by the modules containing it
+/// ```ebfn
+/// type_name := qualified_name
+/// ```
+fn ty_name<'tokens, I>() -> impl Parser<'tokens, I, cst::ty::Variable, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    qualified_name()
+        .map(|name| name.map(cst::ty::VariableT::from))
+        .labelled("type variable")
+}
+
 /// parse type builtin
-pub fn ty_builtin<'tokens, I>() -> impl Parser<'tokens, I, cst::ty::Builtin, ErrorChumsky<'tokens>>
+pub fn ty_builtin<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::ty::Builtin, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
@@ -228,44 +932,325 @@ where
         ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_Nat__" =>
             meta.clone().map(|_| cst::ty::BuiltinT::Nat),
 
+        ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_Int__" =>
+            meta.clone().map(|_| cst::ty::BuiltinT::Int),
+
+        ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_Rat__" =>
+            meta.clone().map(|_| cst::ty::BuiltinT::Rat),
+
         ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_Bool__" =>
             meta.clone().map(|_| cst::ty::BuiltinT::Bool),
 
         ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_Char__" =>
             meta.clone().map(|_| cst::ty::BuiltinT::Char),
+
+        ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_String__" =>
+            meta.clone().map(|_| cst::ty::BuiltinT::String),
+
+        ref meta @ Meta{ value: Token::Identifier(ref s), ..} if s == "__Type_Prop__" =>
+            meta.clone().map(|_| cst::ty::BuiltinT::Prop),
     }
     .labelled("builtin variable")
 }
 
+/// parse type0
+/// ```ebfn
+/// type@0 :=
+/// | type_builtin
+/// | type_name
+/// | "(" ")"
+/// | "(" type ")"
+/// ```
+fn ty0<'tokens, I>(
+    ty: impl Parser<'tokens, I, cst::Type, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::ty::Type0, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::parenthesis::{Parenthesed, Unit};
+    use cst::ty::Type0;
+
+    let builtin = ty_builtin().map(Type0::Builtin);
+    let var = ty_name().map(Type0::Variable);
+    let l_paren = operator(Operator::LParen, cst::operator::LParenT()).labelled("(");
+    let r_paren = operator(Operator::RParen, cst::operator::RParenT()).labelled(")");
+    let unit = l_paren
+        .clone()
+        .then(r_paren.clone())
+        .map(|(l_paren, r_paren)| Type0::Unit(Unit::new(l_paren, r_paren)));
+    let parens = l_paren
+        .then(ty.map(Box::new))
+        .then(r_paren)
+        .map(|((l_paren, ty), r_paren)| Parenthesed::new(l_paren, ty, r_paren))
+        .map(Type0::Paren);
+
+    choice((builtin, var, unit, parens))
+}
+
+/// parse type1
+/// ```ebfn
+/// type@1 :=
+/// | type_name type@0+
+/// | "ref" type@0
+/// | type@0
+/// ```
+fn ty1<'tokens, I>(
+    ty0: impl Parser<'tokens, I, cst::ty::Type0, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::ty::Type1, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::ty::Type1;
+
+    let application = ty_name()
+        .then(ty0.clone().repeated().at_least(1).collect::<Vec<_>>())
+        .map(|(fun, args)| Type1::Application { fun, args });
+    let kw_ref = select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "ref" =>
+            meta.clone().map(|_| cst::ty::RefKeywordT()),
+    }
+    .labelled("ref");
+    let reference = kw_ref
+        .then(ty0.clone())
+        .map(|(kw, ty)| Type1::Reference { kw, ty });
+    choice((application, reference, ty0.map(Type1::Type0)))
+}
+
+/// parse type2
+/// ```ebfn
+/// type@2 :=
+/// | type@1 (STAR type@1)+
+/// | type@1
+/// ```
+fn ty2<'tokens, I>(
+    ty1: impl Parser<'tokens, I, cst::ty::Type1, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::ty::Type2, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::ty::Type2;
+
+    let star = operator(Operator::Star, cst::operator::StarT()).labelled("*");
+    ty1.clone()
+        .then(star.then(ty1).repeated().collect::<Vec<_>>())
+        .map(|(first, rest)| {
+            if rest.is_empty() {
+                Type2::Type1(first)
+            } else {
+                Type2::Product { first, rest }
+            }
+        })
+}
+
+/// parse type3
+/// ```ebfn
+/// type@3 :=
+/// | type@2 ARROW type@3
+/// | type@2
+/// ```
+fn ty3<'tokens, I>(
+    ty2: impl Parser<'tokens, I, cst::ty::Type2, ErrorChumsky<'tokens>> + Clone,
+) -> impl Parser<'tokens, I, cst::ty::Type3, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::ty::Type3;
+
+    let arrow = operator(Operator::Arrow, cst::operator::ArrowT()).labelled("->");
+    ty2.clone()
+        .then(arrow)
+        .repeated()
+        .foldr(ty2.map(Type3::Type2), |(arg, arrow), ret| Type3::Arrow {
+            arg,
+            arrow,
+            ret: Box::new(ret),
+        })
+}
+
 /// parse type
 /// ```ebfn
-/// type :=
-/// | type_variable
+/// type := type@3
 /// ```
-pub fn ty<'tokens, I>() -> impl Parser<'tokens, I, cst::Type, ErrorChumsky<'tokens>>
+pub fn ty<'tokens, I>() -> impl Parser<'tokens, I, cst::Type, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
-    let builtin = ty_builtin().map(cst::Type::Builtin);
-    let var = ty_variable().map(cst::Type::Variable);
+    recursive(|ty| {
+        let ty0 = ty0(ty).boxed();
+        let ty1 = ty1(ty0).boxed();
+        let ty2 = ty2(ty1).boxed();
+        ty3(ty2)
+    })
+    .labelled("type")
+}
 
-    choice((builtin, var)).labelled("type")
+/// parse constructor
+/// ```ebfn
+/// constructor :=
+/// | BAR IDENTIFIER COLON type
+/// | BAR IDENTIFIER type@0*
+/// ```
+fn constructor<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::ty_definition::Constructor, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::ty_definition::{Constructor, ConstructorArgs};
+
+    let bar = operator(Operator::Bar, cst::operator::BarT()).labelled("|");
+    let name = select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} =>
+            meta.clone().map(|_| cst::pattern::VariableT::from(s.clone()))
+    }
+    .labelled("constructor");
+    let typed = {
+        let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
+        colon.then(ty()).map(|(colon, ty)| ConstructorArgs::Typed {
+            colon,
+            ty: Box::new(ty),
+        })
+    };
+    let args = ty0(ty())
+        .repeated()
+        .collect::<Vec<_>>()
+        .map(ConstructorArgs::Args);
+
+    bar.then(name)
+        .then(choice((typed, args)))
+        .map(|((bar, name), args)| Constructor { bar, name, args })
+}
+
+/// parse record declaration
+/// ```ebfn
+/// field_declaration := field COLON type
+/// record_declaration :=
+///     L_BRACE field_declaration (SEMICOLON field_declaration)* SEMICOLON? R_BRACE
+/// ```
+fn record_declaration<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::record::RecordDeclaration, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::record::{FieldDeclaration, RecordDeclaration};
+
+    let l_brace = operator(Operator::LBrace, cst::operator::LBraceT()).labelled("{");
+    let r_brace = operator(Operator::RBrace, cst::operator::RBraceT()).labelled("}");
+    let semicolon = operator(Operator::Semicolon, cst::operator::SemicolonT()).labelled(";");
+    let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
+    let field = field()
+        .then(colon)
+        .then(ty())
+        .map(|((name, colon), ty)| FieldDeclaration { name, colon, ty });
+    l_brace
+        .then(
+            field
+                .separated_by(semicolon)
+                .allow_trailing()
+                .at_least(1)
+                .collect::<Vec<_>>(),
+        )
+        .then(r_brace)
+        .map(|((l_brace, fields), r_brace)| RecordDeclaration {
+            l_brace,
+            fields,
+            r_brace,
+        })
 }
 
 /// parse type definition
 /// ```ebfn
-/// type_definition := type_variable EQ_DEF type
+/// type_definition :=
+/// | type_variable type_variable* EQ_DEF type
+/// | type_variable type_variable* EQ_DEF constructor+
+/// | type_variable type_variable* EQ_DEF record_declaration
 /// ```
 pub fn type_definition<'tokens, I>(
-) -> impl Parser<'tokens, I, cst::TypeDefinition, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, cst::TypeDefinition, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
+    use cst::ty_definition::TypeDefinitionBody;
+
     let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
+    let body = choice((
+        record_declaration().map(TypeDefinitionBody::Record),
+        constructor()
+            .repeated()
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(TypeDefinitionBody::Union),
+        ty().map(Box::new).map(TypeDefinitionBody::Alias),
+    ));
     ty_variable()
+        .then(ty_variable().repeated().collect::<Vec<_>>())
         .then(eq_def)
-        .then(ty())
-        .map(|((name, eq_def), ty)| cst::TypeDefinition { name, eq_def, ty })
+        .then(body)
+        .map(|(((name, params), eq_def), body)| cst::TypeDefinition {
+            name,
+            params,
+            eq_def,
+            body,
+        })
+}
+
+/// parse class, a record type of methods with one type parameter
+/// ```ebfn
+/// class_definition := type_variable type_variable EQ_DEF record_declaration
+/// ```
+pub fn class_definition<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::TypeDefinition, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::ty_definition::TypeDefinitionBody;
+
+    let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
+    ty_variable()
+        .then(ty_variable())
+        .then(eq_def)
+        .then(record_declaration())
+        .map(|(((name, param), eq_def), record)| cst::TypeDefinition {
+            name,
+            params: vec![param],
+            eq_def,
+            body: TypeDefinitionBody::Record(record),
+        })
+}
+
+/// parse instance of a class
+/// ```ebfn
+/// instance := type EQ_DEF expression
+/// ```
+pub fn instance<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::Instance, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
+    ty().then(eq_def)
+        .then(expression())
+        .map(|((ty, eq_def), body)| cst::Instance { ty, eq_def, body })
+}
+
+/// parse theorem
+/// ```ebfn
+/// theorem := variable colon expression (EQ_DEF expression)?
+///```
+pub fn theorem<'tokens, I>() -> impl Parser<'tokens, I, cst::Theorem, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    let colon = operator(Operator::Colon, cst::operator::ColonT()).labelled(":");
+    let eq_def = operator(Operator::EqDef, cst::operator::EqDefT()).labelled(":=");
+    variable()
+        .then(colon)
+        .then(expression())
+        .then(eq_def.then(expression()).or_not())
+        .map(|(((name, colon), statement), proof)| cst::Theorem {
+            name,
+            colon,
+            statement,
+            proof,
+        })
 }
 
 // ===========================================================================
@@ -273,7 +1258,7 @@ where
 // ===========================================================================
 /// parse type
 /// ```ebfn
-/// help_variable := IDENTIFIER
+/// help_variable := IDENTIFIER (PROJECTION IDENTIFIER)*
 /// ```
 pub fn help_variable<'tokens, I>(
 ) -> impl Parser<'tokens, I, cst::help::Variable, ErrorChumsky<'tokens>>
@@ -281,29 +1266,193 @@ where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     use cst::help::VariableT;
-    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} =>
-            meta.clone().map(|_| VariableT::from(s.clone()))
-    }
+    let name = select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} =>
+            meta.clone().map(|_| s.clone())
+    };
+    let projection = operator(Operator::Projection, ()).labelled(".<field>");
+    name.foldl(projection.ignore_then(name).repeated(), |path, name| {
+        path.merge(name, |path, name| format!("{path}.{name}"))
+    })
+    .map(|name| name.map(VariableT::from))
     .labelled("help variable")
 }
+
+/// parse name of module
+/// ```ebfn
+/// module_name := IDENTIFIER
+/// ```
+fn module_name<'tokens, I>() -> impl Parser<'tokens, I, cst::module::Name, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::module::NameT;
+    select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} if is_not_keyword(s) =>
+            meta.clone().map(|_| NameT::from(s.clone()))
+    }
+    .labelled("module name")
+}
+
+/// parse name of module, qualified by its parent modules
+/// ```ebfn
+/// module_path := qualified_name
+/// ```
+fn module_path<'tokens, I>() -> impl Parser<'tokens, I, cst::module::Name, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    qualified_name()
+        .map(|name| name.map(cst::module::NameT::from))
+        .labelled("module name")
+}
+
+/// parse path of a required file
+/// ```ebfn
+/// require_path := STRING
+/// ```
+fn require_path<'tokens, I>() -> impl Parser<'tokens, I, cst::module::Path, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::module::PathT;
+    select! {ref meta @ Meta{ value: Token::String(ref s), ..} =>
+        meta.clone().map(|_| PathT::from(s.clone()))
+    }
+    .labelled("path")
+}
+
 // ===========================================================================
 // Command
 // ===========================================================================
 
 fn keyword_definition<'tokens, I>(
-) -> impl Parser<'tokens, I, cst::command::DefinitionKeyword, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, cst::command::DefinitionKeyword, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     use cst::command::DefinitionKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Def" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::DEF =>
             meta.clone().map(|_| DefinitionKeywordT::Def),
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Definition" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::DEFINITION =>
             meta.clone().map(|_| DefinitionKeywordT::Definition),
     }
 }
 
+fn keyword_fixpoint<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::FixpointKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::FixpointKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::FIXPOINT =>
+            meta.clone().map(|_| FixpointKeywordT::Fixpoint),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::REC =>
+            meta.clone().map(|_| FixpointKeywordT::Rec),
+    }
+}
+
+fn keyword_mutual<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::MutualKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::MutualKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "with" =>
+            meta.clone().map(|_| MutualKeywordT::With),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "and" =>
+            meta.clone().map(|_| MutualKeywordT::And),
+    }
+    .labelled("with")
+}
+
+fn keyword_theorem<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::TheoremKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::TheoremKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::THEOREM =>
+            meta.clone().map(|_| TheoremKeywordT::Theorem),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::LEMMA =>
+            meta.clone().map(|_| TheoremKeywordT::Lemma),
+    }
+}
+
+fn keyword_proof<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::ProofKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::ProofKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::PROOF =>
+            meta.clone().map(|_| ProofKeywordT::Proof),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::QED =>
+            meta.clone().map(|_| ProofKeywordT::Qed),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::ABORT =>
+            meta.clone().map(|_| ProofKeywordT::Abort),
+    }
+}
+
+/// parse keyword of tactic
+fn keyword_tactic<'tokens, I>(
+    keyword: cst::command::TacticKeywordT,
+) -> impl Parser<'tokens, I, cst::command::TacticKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword.name() =>
+            meta.clone().map(|_| keyword),
+    }
+    .labelled(keyword.name())
+}
+
+/// parse tactic
+/// ```ebfn
+/// tactic :=
+/// | "intro" IDENTIFIER+
+/// | ("apply" | "exact" | "exists") expression
+/// | "split" | "left" | "right" | "reflexivity"
+///```
+pub fn tactic<'tokens, I>() -> impl Parser<'tokens, I, cst::CommandKind, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::{CommandKind, TacticKeywordT};
+    use cst::tactic::TacticArgs;
+
+    let name =
+        select! {ref meta @ Meta{ value: Token::Identifier(ref s), ..} if is_not_keyword(s) =>
+                meta.clone().map(|_| cst::pattern::VariableT::from(s.clone()))
+        }
+        .labelled("variable");
+    let with_expr = choice((
+        keyword_tactic(TacticKeywordT::Apply),
+        keyword_tactic(TacticKeywordT::Exact),
+        keyword_tactic(TacticKeywordT::Exists),
+    ));
+    let without_args = choice((
+        keyword_tactic(TacticKeywordT::Split),
+        keyword_tactic(TacticKeywordT::Left),
+        keyword_tactic(TacticKeywordT::Right),
+        keyword_tactic(TacticKeywordT::Reflexivity),
+    ));
+    choice((
+        keyword_tactic(TacticKeywordT::Intro)
+            .then(name.repeated().at_least(1).collect())
+            .map(|(keyword, names)| (keyword, TacticArgs::Names(names))),
+        with_expr
+            .then(expression())
+            .map(|(keyword, expr)| (keyword, TacticArgs::Expr(Box::new(expr)))),
+        without_args.map(|keyword| (keyword, TacticArgs::None)),
+    ))
+    .map(|(keyword, args)| CommandKind::Tactic { keyword, args })
+}
+
 fn keyword_eval<'tokens, I>(
 ) -> impl Parser<'tokens, I, cst::command::EvalKeyword, ErrorChumsky<'tokens>>
 where
@@ -311,7 +1460,7 @@ where
 {
     use cst::command::EvalKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Eval" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::EVAL =>
             meta.clone().map(|_| EvalKeywordT::Eval),
         ref meta @ Meta{value: Token::Operator(Operator::Eval), ..} =>
             meta.clone().map(|_| EvalKeywordT::EvalOp)
@@ -325,7 +1474,7 @@ where
 {
     use cst::command::TypeOfKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "TypeOf" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::TYPE_OF =>
             meta.clone().map(|_| TypeOfKeywordT::TypeOf),
         ref meta @ Meta{value: Token::Operator(Operator::TypeOf), ..} =>
             meta.clone().map(|_| TypeOfKeywordT::TypeOfOp),
@@ -339,7 +1488,7 @@ where
 {
     use cst::command::HelpKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Help" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::HELP =>
             meta.clone().map(|_| HelpKeywordT::Help),
         ref meta @ Meta{value: Token::Operator(Operator::Help), ..} =>
             meta.clone().map(|_| HelpKeywordT::HelpOp),
@@ -347,19 +1496,31 @@ where
 }
 
 fn keyword_type<'tokens, I>(
-) -> impl Parser<'tokens, I, cst::command::TypeKeyword, ErrorChumsky<'tokens>>
+) -> impl Parser<'tokens, I, cst::command::TypeKeyword, ErrorChumsky<'tokens>> + Clone
 where
     I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
 {
     use cst::command::TypeKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Type" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::TYPE =>
             meta.clone().map(|_| TypeKeywordT::Type),
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Ty" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::TY =>
             meta.clone().map(|_| TypeKeywordT::Ty),
     }
 }
 
+fn keyword_assert<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::AssertKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::AssertKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::ASSERT =>
+                meta.clone().map(|_| AssertKeywordT()),
+    }
+}
+
 fn keyword_set<'tokens, I>(
 ) -> impl Parser<'tokens, I, cst::command::SetKeyword, ErrorChumsky<'tokens>>
 where
@@ -367,7 +1528,7 @@ where
 {
     use cst::command::SetKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Set" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::SET =>
                 meta.clone().map(|_| SetKeywordT()),
     }
 }
@@ -379,19 +1540,104 @@ where
 {
     use cst::command::UnsetKeywordT;
     select! {
-        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == "Unset" =>
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::UNSET =>
                 meta.clone().map(|_| UnsetKeywordT()),
     }
 }
+fn keyword_module<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::ModuleKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::ModuleKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::MODULE =>
+            meta.clone().map(|_| ModuleKeywordT::Module),
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::END =>
+            meta.clone().map(|_| ModuleKeywordT::End),
+    }
+}
+
+fn keyword_open<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::OpenKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::OpenKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::OPEN =>
+                meta.clone().map(|_| OpenKeywordT()),
+    }
+}
+
+fn keyword_require<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::RequireKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::RequireKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::REQUIRE =>
+                meta.clone().map(|_| RequireKeywordT()),
+    }
+}
+
+fn keyword_class<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::ClassKeyword, ErrorChumsky<'tokens>> + Clone
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::ClassKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::CLASS =>
+                meta.clone().map(|_| ClassKeywordT()),
+    }
+}
+
+fn keyword_instance<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::InstanceKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::InstanceKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::INSTANCE =>
+                meta.clone().map(|_| InstanceKeywordT()),
+    }
+}
+
+fn keyword_private<'tokens, I>(
+) -> impl Parser<'tokens, I, cst::command::PrivateKeyword, ErrorChumsky<'tokens>>
+where
+    I: ValueInput<'tokens, Token = MetaToken, Span = SimpleSpan>,
+{
+    use cst::command::PrivateKeywordT;
+    select! {
+        ref meta @ Meta{value: Token::Identifier(ref id), ..} if id == keyword::PRIVATE =>
+                meta.clone().map(|_| PrivateKeywordT()),
+    }
+}
+
 /// parse command
 /// ```ebfn
-/// command_kind :=
+/// definition :=
 /// | keyword_definition expr_definition
+/// | keyword_fixpoint fixpoint_definition
 /// | keyword_type type_definition
+/// | keyword_class class_definition
+///
+/// command_kind :=
+/// | definition
+/// | keyword_private definition
+/// | keyword_instance instance
+/// | keyword_theorem theorem
 /// | keyword_eval expr
 /// | keyword_typeof expr
 /// | keyword_set variable
 /// | keyword_unset variable
+/// | keyword_module module_name
+/// | keyword_open module_path
+/// | keyword_require require_path
 ///```
 pub fn command_kind<'tokens, I>() -> impl Parser<'tokens, I, cst::CommandKind, ErrorChumsky<'tokens>>
 where
@@ -399,19 +1645,43 @@ where
 {
     use cst::command::CommandKind;
 
-    choice((
+    let definition = choice((
         keyword_definition()
             .then(expression_definition().map(Box::new))
             .map(|(keyword, def)| CommandKind::ExpressionDefinition { keyword, def }),
+        keyword_fixpoint()
+            .then(fixpoint_definition().map(Box::new))
+            .map(|(keyword, def)| CommandKind::Fixpoint { keyword, def }),
         keyword_type()
             .then(type_definition())
             .map(|(keyword, def)| CommandKind::TypeDefinition { keyword, def }),
+        keyword_class()
+            .then(class_definition())
+            .map(|(keyword, def)| CommandKind::Class { keyword, def }),
+    ));
+
+    choice((
+        definition.clone(),
+        keyword_private()
+            .then(definition.map(Box::new))
+            .map(|(keyword, kind)| CommandKind::Private { keyword, kind }),
+        keyword_instance()
+            .then(instance().map(Box::new))
+            .map(|(keyword, def)| CommandKind::Instance { keyword, def }),
+        keyword_theorem()
+            .then(theorem().map(Box::new))
+            .map(|(keyword, def)| CommandKind::Theorem { keyword, def }),
+        keyword_proof().map(|keyword| CommandKind::Proof { keyword }),
+        tactic(),
         keyword_eval()
             .then(expression())
             .map(|(keyword, expr)| CommandKind::Eval { keyword, expr }),
         keyword_type_of()
             .then(expression())
             .map(|(keyword, expr)| CommandKind::TypeOf { keyword, expr }),
+        keyword_assert()
+            .then(expression())
+            .map(|(keyword, expr)| CommandKind::Assert { keyword, expr }),
         keyword_help()
             .then(help_variable())
             .map(|(keyword, var)| CommandKind::Help { keyword, var }),
@@ -421,6 +1691,15 @@ where
         keyword_unset()
             .then(variable())
             .map(|(keyword, var)| CommandKind::UnSet { keyword, var }),
+        keyword_module()
+            .then(module_name())
+            .map(|(keyword, name)| CommandKind::Module { keyword, name }),
+        keyword_open()
+            .then(module_path())
+            .map(|(keyword, name)| CommandKind::Open { keyword, name }),
+        keyword_require()
+            .then(require_path())
+            .map(|(keyword, path)| CommandKind::Require { keyword, path }),
     ))
 }
 
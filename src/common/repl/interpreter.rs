@@ -1,10 +1,12 @@
 use super::summary::SummaryDefinition;
 use crate::file_interpreter;
 use crate::interpreter;
+use crate::interpreter::cache;
 use crate::interpreter::flag::DebugFlag;
 use crate::interpreter::flag::Flag;
-use crate::interpreter::Interpreter as _;
+use crate::interpreter::require::Requires;
 use crate::typer::ast;
+use crate::typer::tactic;
 use crate::typer::Typer;
 use crate::utils::error::ErrorPrint;
 use crate::utils::location::SourceId;
@@ -12,6 +14,7 @@ use crate::utils::pretty::Pretty;
 use crate::utils::theme::Theme;
 use crate::vm::Env;
 use ariadne::Source;
+use std::path::PathBuf;
 
 pub struct Interpreter {
     all_content: String,
@@ -19,27 +22,28 @@ pub struct Interpreter {
     err_code: i32,
     typer: Typer,
     vm: Env,
+    requires: Requires,
     debug_lexer: bool,
     debug_parser: bool,
     debug_typer: bool,
+    obligations: bool,
     theme: Theme,
 }
 
 impl Default for Interpreter {
     fn default() -> Self {
-        let mut std_lib_interpreter = file_interpreter::Interpreter::stdlib();
-        std_lib_interpreter.run();
-        let typer = std_lib_interpreter.typer;
-        let vm = std_lib_interpreter.vm;
+        let (typer, vm) = cache::stdlib();
         Interpreter {
             all_content: String::new(),
             content: String::new(),
             err_code: 0,
             typer,
             vm,
+            requires: Requires::default(),
             debug_lexer: false,
             debug_parser: false,
             debug_typer: false,
+            obligations: false,
             theme: Theme::default_theme(),
         }
     }
@@ -86,11 +90,31 @@ impl interpreter::Interpreter for Interpreter {
         &mut self.vm
     }
 
+    fn mut_requires(&mut self) -> &mut Requires {
+        &mut self.requires
+    }
+
+    fn run_required(&mut self, path: PathBuf, content: String, typer: Typer) -> (i32, Typer) {
+        let mut required = file_interpreter::Interpreter::required(
+            path,
+            content,
+            typer,
+            std::mem::take(&mut self.vm),
+            std::mem::take(&mut self.requires),
+        );
+        required.run_file();
+        let code = required.get_error_code();
+        self.vm = required.vm;
+        self.requires = required.requires;
+        (code, required.typer)
+    }
+
     fn set_flag(&mut self, b: bool, flag: Flag) {
         match flag {
             Flag::Debug(DebugFlag::Lexer) => self.debug_lexer = b,
             Flag::Debug(DebugFlag::Parser) => self.debug_parser = b,
             Flag::Debug(DebugFlag::Typer) => self.debug_typer = b,
+            Flag::Obligations => self.obligations = b,
         }
     }
 
@@ -102,6 +126,10 @@ impl interpreter::Interpreter for Interpreter {
         }
     }
 
+    fn is_active_obligations(&self) -> bool {
+        self.obligations
+    }
+
     fn print<Doc>(&mut self, doc: &Doc)
     where
         Doc: Pretty,
@@ -114,6 +142,10 @@ impl interpreter::Interpreter for Interpreter {
         println!("       {}", summary.make_string(&self.theme));
     }
 
+    fn print_goals(&self, goals: &tactic::Goals) {
+        println!("{}", goals.make_string(&self.theme));
+    }
+
     fn eprint<E>(&mut self, error: &E)
     where
         E: ErrorPrint,
@@ -13,14 +13,52 @@ impl<'a> From<&'a ExpressionDefinition> for SummaryDefinition<'a> {
 fn pretty_pattern<'a>(pat: &Pattern, theme: &Theme) -> Doc<'a> {
     match pat {
         Pattern::Variable(var) => theme.def_var(&var.identifier().name()),
+        Pattern::Tuple(tuple) => Doc::intersperse(
+            tuple.patterns().iter().map(|pat| match pat {
+                Pattern::Tuple(_) => Doc::nil()
+                    .append(theme.operator(&"("))
+                    .append(pretty_pattern(pat, theme))
+                    .append(theme.operator(&")")),
+                _ => pretty_pattern(pat, theme),
+            }),
+            theme.operator(&",").append(Doc::space()),
+        ),
+        Pattern::Constructor(ctor) => {
+            ctor.args()
+                .iter()
+                .fold(theme.expr_var(&ctor.identifier().name()), |doc, pat| {
+                    let doc_pat = match pat {
+                        Pattern::Variable(_) => pretty_pattern(pat, theme),
+                        Pattern::Constructor(ctor) if ctor.args().is_empty() => {
+                            pretty_pattern(pat, theme)
+                        }
+                        _ => Doc::nil()
+                            .append(theme.operator(&"("))
+                            .append(pretty_pattern(pat, theme))
+                            .append(theme.operator(&")")),
+                    };
+                    doc.append(Doc::space()).append(doc_pat)
+                })
+        }
     }
 }
 
 impl Pretty for SummaryDefinition<'_> {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         let def = self.0;
+        let doc_binders = match def.binders() {
+            [] => Doc::nil(),
+            binders => Doc::space()
+                .append(theme.operator(&"<"))
+                .append(Doc::intersperse(
+                    binders.iter().map(|id| theme.ty_var(&id.name())),
+                    Doc::space(),
+                ))
+                .append(theme.operator(&">")),
+        };
         Doc::nil()
             .append(pretty_pattern(self.0.pattern(), theme))
+            .append(doc_binders)
             .append(Doc::space())
             .append(theme.operator(&":"))
             .append(Doc::space())
@@ -13,6 +13,13 @@ impl From<Vec<String>> for Documentation {
     }
 }
 
+impl Documentation {
+    /// get documentation lines
+    pub fn lines(&self) -> &[String] {
+        &self.doc
+    }
+}
+
 impl std::fmt::Display for Documentation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for line in &self.doc {
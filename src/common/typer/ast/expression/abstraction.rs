@@ -0,0 +1,96 @@
+use super::super::ty::{Type, TypeArrow, Typed, TypedMut};
+use super::super::Pattern;
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Expression Abstraction
+// ==========================================================================
+pub struct Abstraction {
+    /// binder of abstraction
+    pattern: Rc<Pattern>,
+    /// body of abstraction
+    body: Rc<Expression>,
+    /// type of abstraction
+    ty: Type,
+    /// location of abstraction
+    loc: Location,
+}
+
+impl Abstraction {
+    /// make a new abstraction, `ty_arg` is the type of the pattern
+    pub fn new(pattern: Pattern, ty_arg: Type, body: Expression) -> Self {
+        let ty = Type::Arrow(TypeArrow::new(ty_arg, body.ty().clone()));
+        Self {
+            pattern: Rc::new(pattern),
+            body: Rc::new(body),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get pattern of abstraction
+    pub fn pattern(&self) -> &Rc<Pattern> {
+        &self.pattern
+    }
+
+    /// get body of abstraction
+    pub fn body(&self) -> &Rc<Expression> {
+        &self.body
+    }
+
+    /// get type of argument
+    pub fn ty_arg(&self) -> &Type {
+        self.ty
+            .as_arrow()
+            .expect("abstraction has an arrow type")
+            .arg()
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Abstraction {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Abstraction {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Abstraction {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Abstraction {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Abstraction {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.operator(&"("))
+            .append(self.pattern.pretty(theme))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::space())
+            .append(self.ty_arg().pretty(theme))
+            .append(theme.operator(&")"))
+            .append(Doc::space())
+            .append(theme.operator(&"=>"))
+            .append(Doc::line().append(self.body.pretty(theme)).nest(2))
+            .group()
+    }
+}
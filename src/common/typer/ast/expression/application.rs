@@ -0,0 +1,81 @@
+use super::super::ty::{Type, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Expression Application
+// ==========================================================================
+pub struct Application {
+    /// applied function
+    fun: Box<Expression>,
+    /// argument of application
+    arg: Box<Expression>,
+    /// type of application
+    ty: Type,
+    /// location of application
+    loc: Location,
+}
+
+impl Application {
+    /// make a new application, `ty` is the result type of the function
+    pub fn new(fun: Expression, arg: Expression, ty: Type) -> Self {
+        Self {
+            fun: Box::new(fun),
+            arg: Box::new(arg),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get applied function
+    pub fn fun(&self) -> &Expression {
+        &self.fun
+    }
+
+    /// get argument
+    pub fn arg(&self) -> &Expression {
+        &self.arg
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Application {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Application {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Application {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Application {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Application {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.fun.pretty_precedence(1, theme))
+            .append(
+                Doc::line()
+                    .append(self.arg.pretty_precedence(0, theme))
+                    .nest(2),
+            )
+            .group()
+    }
+}
@@ -0,0 +1,100 @@
+use super::super::ty::{Type, Typed, TypedMut};
+use super::super::Pattern;
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Expression Local Binding
+// ==========================================================================
+pub struct Let {
+    /// pattern bound to the value
+    pattern: Pattern,
+    /// value bound by the pattern
+    value: Box<Expression>,
+    /// body, where variables of the pattern are visible
+    body: Box<Expression>,
+    /// type of binding
+    ty: Type,
+    /// location of binding
+    loc: Location,
+}
+
+impl Let {
+    /// make a new local binding, its type is the type of its body
+    pub fn new(pattern: Pattern, value: Expression, body: Expression) -> Self {
+        let ty = body.ty().clone();
+        Self {
+            pattern,
+            value: Box::new(value),
+            body: Box::new(body),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get bound pattern
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    /// get bound value
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+
+    /// get body
+    pub fn body(&self) -> &Expression {
+        &self.body
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Let {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Let {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Let {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Let {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Let {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(
+                theme
+                    .keyword(&"let")
+                    .append(Doc::space())
+                    .append(self.pattern.pretty(theme))
+                    .append(Doc::space())
+                    .append(theme.operator(&":="))
+                    .append(Doc::line().append(self.value.pretty_precedence(11, theme)))
+                    .append(Doc::line())
+                    .append(theme.keyword(&"in"))
+                    .nest(2)
+                    .group(),
+            )
+            .append(Doc::line())
+            .append(self.body.pretty_precedence(11, theme))
+            .group()
+    }
+}
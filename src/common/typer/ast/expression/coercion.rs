@@ -0,0 +1,77 @@
+use super::super::ty::{Type, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Expression Coercion
+// ==========================================================================
+/// coercion `(e :> ℤ)` of a number to a larger number type, along ℕ ⊂ ℤ ⊂ ℚ,
+/// or of a boolean to a proposition
+pub struct Coercion {
+    /// coerced number or boolean
+    expr: Box<Expression>,
+    /// type of coercion
+    ty: Type,
+    /// location of coercion, the location of the coerced expression
+    loc: Location,
+}
+
+impl Coercion {
+    /// make a new coercion of `expr` to type `ty`
+    pub fn new(expr: Expression, ty: Type) -> Self {
+        let loc = expr.loc();
+        Self {
+            expr: Box::new(expr),
+            ty,
+            loc,
+        }
+    }
+
+    /// get coerced expression
+    pub fn expr(&self) -> &Expression {
+        &self.expr
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Coercion {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Coercion {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Coercion {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Coercion {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Coercion {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.operator(&"("))
+            .append(self.expr.pretty_precedence(9, theme))
+            .append(Doc::space())
+            .append(theme.operator(&":>"))
+            .append(Doc::space())
+            .append(self.ty.pretty(theme))
+            .append(theme.operator(&")"))
+            .group()
+    }
+}
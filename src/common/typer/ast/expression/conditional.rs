@@ -0,0 +1,103 @@
+use super::super::ty::{Type, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Expression Conditional
+// ==========================================================================
+pub struct If {
+    /// boolean condition
+    cond: Box<Expression>,
+    /// expression evaluated when condition is true
+    then_branch: Box<Expression>,
+    /// expression evaluated when condition is false
+    else_branch: Box<Expression>,
+    /// type of both branches
+    ty: Type,
+    /// location of conditional
+    loc: Location,
+}
+
+impl If {
+    /// make a new conditional, its type is the type of its branches
+    pub fn new(cond: Expression, then_branch: Expression, else_branch: Expression) -> Self {
+        let ty = then_branch.ty().clone();
+        Self {
+            cond: Box::new(cond),
+            then_branch: Box::new(then_branch),
+            else_branch: Box::new(else_branch),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get condition
+    pub fn cond(&self) -> &Expression {
+        &self.cond
+    }
+
+    /// get branch of true condition
+    pub fn then_branch(&self) -> &Expression {
+        &self.then_branch
+    }
+
+    /// get branch of false condition
+    pub fn else_branch(&self) -> &Expression {
+        &self.else_branch
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for If {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for If {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for If {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for If {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for If {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.keyword(&"if"))
+            .append(Doc::space())
+            .append(self.cond.pretty_precedence(11, theme))
+            .append(Doc::line())
+            .append(
+                theme
+                    .keyword(&"then")
+                    .append(Doc::line().append(self.then_branch.pretty_precedence(11, theme)))
+                    .nest(2)
+                    .group(),
+            )
+            .append(Doc::line())
+            .append(
+                theme
+                    .keyword(&"else")
+                    .append(Doc::line().append(self.else_branch.pretty_precedence(11, theme)))
+                    .nest(2)
+                    .group(),
+            )
+            .group()
+    }
+}
@@ -1,15 +1,80 @@
-use super::super::ty::{Type, TypeBuiltin, Typed, TypedMut};
+use super::super::ty::{Type, TypeArrow, TypeBuiltin, Typed, TypedMut};
 use crate::utils::location::{Located, LocatedSet, Location};
 use crate::utils::pretty::Pretty;
+use crate::utils::rational::Rational;
 use crate::utils::theme::{Doc, Theme};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 
 pub type NConst = BigUint;
+pub type ZConst = BigInt;
+pub type QConst = Rational;
 
+/// functions implemented by the interpreter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Primitive {
+    /// ℕ -> ℤ
+    IntOfNat,
+    /// ℤ -> ℕ, negative integers are sent to `0`
+    NatOfInt,
+    /// String -> ℕ, number of characters
+    StringLength,
+    /// String -> String -> String
+    StringAppend,
+    /// String -> ℕ -> Char, `'\000'` out of the string
+    StringGet,
+}
+
+impl Primitive {
+    /// type of the primitive
+    pub fn ty(&self) -> Type {
+        let nat = Type::Builtin(TypeBuiltin::nat());
+        let int = Type::Builtin(TypeBuiltin::int());
+        let char = Type::Builtin(TypeBuiltin::char());
+        let string = Type::Builtin(TypeBuiltin::string());
+        let args = match self {
+            Self::IntOfNat => vec![nat, int],
+            Self::NatOfInt => vec![int, nat],
+            Self::StringLength => vec![string, nat],
+            Self::StringAppend => vec![string.clone(), string.clone(), string],
+            Self::StringGet => vec![string, nat, char],
+        };
+        args.into_iter()
+            .rev()
+            .reduce(|ret, arg| Type::Arrow(TypeArrow::new(arg, ret)))
+            .expect("primitive has a type")
+    }
+
+    /// number of arguments of the primitive
+    pub fn arity(&self) -> usize {
+        match self {
+            Self::IntOfNat | Self::NatOfInt | Self::StringLength => 1,
+            Self::StringAppend | Self::StringGet => 2,
+        }
+    }
+}
+
+impl Pretty for Primitive {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Self::IntOfNat => theme.expr_var(&"__Function_int_of_nat__"),
+            Self::NatOfInt => theme.expr_var(&"__Function_nat_of_int__"),
+            Self::StringLength => theme.expr_var(&"__Function_string_length__"),
+            Self::StringAppend => theme.expr_var(&"__Function_string_append__"),
+            Self::StringGet => theme.expr_var(&"__Function_string_get__"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConstantKind {
     Nat(NConst),
+    Int(ZConst),
+    Rat(QConst),
     Bool(bool),
     Char(char),
+    String(String),
+    Unit,
+    Primitive(Primitive),
 }
 
 pub struct Constant {
@@ -34,6 +99,34 @@ impl Constant {
         Self::new(ConstantKind::Nat(v), Type::Builtin(TypeBuiltin::nat()))
     }
 
+    /// create integer constant
+    pub fn int(v: ZConst) -> Self {
+        Self::new(ConstantKind::Int(v), Type::Builtin(TypeBuiltin::int()))
+    }
+
+    /// create rational constant
+    pub fn rat(v: QConst) -> Self {
+        Self::new(ConstantKind::Rat(v), Type::Builtin(TypeBuiltin::rat()))
+    }
+
+    /// create a string constant
+    pub fn string(s: String) -> Self {
+        Self::new(
+            ConstantKind::String(s),
+            Type::Builtin(TypeBuiltin::string()),
+        )
+    }
+
+    /// create the unit constant
+    pub fn unit() -> Self {
+        Self::new(ConstantKind::Unit, Type::Builtin(TypeBuiltin::unit()))
+    }
+
+    /// create primitive function constant
+    pub fn primitive(p: Primitive) -> Self {
+        Self::new(ConstantKind::Primitive(p), p.ty())
+    }
+
     /// create boolean constant
     pub fn boolean(b: bool) -> Self {
         Self::new(ConstantKind::Bool(b), Type::Builtin(TypeBuiltin::bool()))
@@ -50,6 +143,21 @@ impl Constant {
     }
 }
 
+impl From<ConstantKind> for Constant {
+    fn from(kind: ConstantKind) -> Self {
+        match kind {
+            ConstantKind::Nat(n) => Self::nat(n),
+            ConstantKind::Int(i) => Self::int(i),
+            ConstantKind::Rat(q) => Self::rat(q),
+            ConstantKind::Bool(b) => Self::boolean(b),
+            ConstantKind::Char(c) => Self::character(c),
+            ConstantKind::String(s) => Self::string(s),
+            ConstantKind::Unit => Self::unit(),
+            ConstantKind::Primitive(p) => Self::primitive(p),
+        }
+    }
+}
+
 impl Located for Constant {
     fn loc(&self) -> Location {
         self.loc.clone()
@@ -62,16 +170,27 @@ impl LocatedSet for Constant {
     }
 }
 
-impl Pretty for Constant {
+impl Pretty for ConstantKind {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
-        match &self.kind() {
+        match self {
             ConstantKind::Nat(n) => theme.number(n),
+            ConstantKind::Int(i) => theme.integer(i),
+            ConstantKind::Rat(q) => q.pretty(theme),
             ConstantKind::Bool(b) => theme.boolean(*b),
             ConstantKind::Char(c) => theme.character(*c),
+            ConstantKind::String(s) => theme.string(s),
+            ConstantKind::Unit => theme.operator(&"()"),
+            ConstantKind::Primitive(p) => p.pretty(theme),
         }
     }
 }
 
+impl Pretty for Constant {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.kind.pretty(theme)
+    }
+}
+
 pub mod sealed_ty_mut {
     use super::*;
     impl TypedMut for Constant {
@@ -0,0 +1,203 @@
+use super::super::ty::{Type, Typed, TypedMut};
+use super::super::Identifier;
+use super::Expression;
+use crate::parser::cst;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+pub use crate::parser::cst::expression_definition::ContractKeywordT as ContractKind;
+
+// ==========================================================================
+// Contract
+// ==========================================================================
+/// clause of a contract, a boolean or a proposition
+pub struct Clause {
+    /// precondition or postcondition
+    kind: ContractKind,
+    /// typed clause
+    expr: Expression,
+    /// clause as written, printed by help
+    source: cst::Expression,
+}
+
+impl Clause {
+    pub fn new(kind: ContractKind, expr: Expression, source: cst::Expression) -> Self {
+        Self { kind, expr, source }
+    }
+
+    /// get kind of clause
+    pub fn kind(&self) -> ContractKind {
+        self.kind
+    }
+
+    /// get typed clause
+    pub fn expr(&self) -> &Expression {
+        &self.expr
+    }
+
+    /// get clause as written
+    pub fn source(&self) -> &cst::Expression {
+        &self.source
+    }
+}
+
+impl Pretty for Clause {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.kind.pretty(theme))
+            .append(Doc::space())
+            .append(self.source.pretty(theme).group())
+    }
+}
+
+impl Located for Clause {
+    fn loc(&self) -> Location {
+        self.source.loc()
+    }
+}
+
+/// preconditions and postconditions of a function
+pub struct Contract {
+    /// variables bound by the parameters, in order
+    params: Vec<Identifier>,
+    /// variable bound to the result in postconditions
+    result: Option<Rc<Identifier>>,
+    /// clauses, in order
+    clauses: Vec<Clause>,
+}
+
+impl Contract {
+    pub fn new(
+        params: Vec<Identifier>,
+        result: Option<Rc<Identifier>>,
+        clauses: Vec<Clause>,
+    ) -> Self {
+        Self {
+            params,
+            result,
+            clauses,
+        }
+    }
+
+    /// get variables bound by the parameters
+    pub fn params(&self) -> &[Identifier] {
+        &self.params
+    }
+
+    /// get variable bound to the result, if there are postconditions
+    pub fn result(&self) -> Option<&Rc<Identifier>> {
+        self.result.as_ref()
+    }
+
+    /// get clauses, in order
+    pub fn clauses(&self) -> &[Clause] {
+        &self.clauses
+    }
+
+    /// get preconditions
+    pub fn requires(&self) -> impl Iterator<Item = &Clause> {
+        self.clauses
+            .iter()
+            .filter(|clause| clause.kind == ContractKind::Requires)
+    }
+
+    /// get postconditions
+    pub fn ensures(&self) -> impl Iterator<Item = &Clause> {
+        self.clauses
+            .iter()
+            .filter(|clause| clause.kind == ContractKind::Ensures)
+    }
+}
+
+impl std::fmt::Debug for Contract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Contract")
+            .field("params", &self.params)
+            .field("result", &self.result)
+            .field("clauses", &self.clauses.len())
+            .finish()
+    }
+}
+
+impl Pretty for Contract {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::intersperse(
+            self.clauses.iter().map(|clause| clause.pretty(theme)),
+            Doc::line(),
+        )
+    }
+}
+
+// ==========================================================================
+// Expression Checked by a Contract
+// ==========================================================================
+/// body of a function, checked against its contract when evaluated
+pub struct Checked {
+    /// contract of the function
+    contract: Rc<Contract>,
+    /// body of the function
+    body: Box<Expression>,
+    /// type of body
+    ty: Type,
+    /// location of body
+    loc: Location,
+}
+
+impl Checked {
+    pub fn new(contract: Rc<Contract>, body: Expression) -> Self {
+        let ty = body.ty().clone();
+        let loc = body.loc();
+        Self {
+            contract,
+            body: Box::new(body),
+            ty,
+            loc,
+        }
+    }
+
+    /// get contract
+    pub fn contract(&self) -> &Rc<Contract> {
+        &self.contract
+    }
+
+    /// get body
+    pub fn body(&self) -> &Expression {
+        &self.body
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Checked {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Checked {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Checked {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Checked {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+/// the contract is printed with the definition, only the body is printed here
+impl Pretty for Checked {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.body.pretty(theme)
+    }
+}
@@ -1,9 +1,10 @@
 use super::super::ty::{Type, Typed};
-use super::super::Pattern;
-use super::Expression;
+use super::super::{Identifier, Pattern};
+use super::{Contract, Expression};
 use crate::utils::location::{Located, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
 
 // ==========================================================================
 // Expression Definition
@@ -12,14 +13,43 @@ pub struct Definition {
     /// name of definition
     pattern: Pattern,
 
+    /// type variables bound by definition
+    binders: Vec<Rc<Identifier>>,
+
     /// body of definition
     body: Expression,
+
+    /// contract of definition, if it has clauses
+    contract: Option<Rc<Contract>>,
 }
 
 impl Definition {
     /// Create a new expression definition
-    pub fn new(pattern: Pattern, body: Expression) -> Self {
-        Self { pattern, body }
+    pub fn new(pattern: Pattern, binders: Vec<Rc<Identifier>>, body: Expression) -> Self {
+        Self {
+            pattern,
+            binders,
+            body,
+            contract: None,
+        }
+    }
+
+    /// set contract of definition
+    pub fn with_contract(self, contract: Option<Rc<Contract>>) -> Self {
+        Self { contract, ..self }
+    }
+
+    /// replace type variables bound by definition
+    pub fn with_binders(self, binders: Vec<Rc<Identifier>>) -> Self {
+        Self { binders, ..self }
+    }
+
+    /// replace body of definition by `f` applied to it
+    pub fn map_body(self, f: impl FnOnce(Expression) -> Expression) -> Self {
+        Self {
+            body: f(self.body),
+            ..self
+        }
     }
 
     /// get pattern of definition
@@ -27,10 +57,20 @@ impl Definition {
         &self.pattern
     }
 
+    /// get type variables bound by definition
+    pub fn binders(&self) -> &[Rc<Identifier>] {
+        &self.binders
+    }
+
     /// Get the body of the expression definition
     pub fn body(&self) -> &Expression {
         &self.body
     }
+
+    /// get contract of definition
+    pub fn contract(&self) -> Option<&Rc<Contract>> {
+        self.contract.as_ref()
+    }
 }
 
 /*pub mod sealed_mut_ty {*/
@@ -47,12 +87,26 @@ impl Typed for Definition {
     }
 }
 
-impl Pretty for Definition {
-    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+/// pretty print type variables `<α β>` bound by a definition
+fn pretty_binders<'a>(binders: &[Rc<Identifier>], theme: &Theme) -> Doc<'a> {
+    if binders.is_empty() {
+        return Doc::nil();
+    }
+    Doc::space()
+        .append(theme.operator(&"<"))
+        .append(Doc::intersperse(
+            binders.iter().map(|id| theme.ty_var(id.as_ref())),
+            Doc::space(),
+        ))
+        .append(theme.operator(&">"))
+}
+
+impl Definition {
+    /// pretty print definition without its keyword
+    fn pretty_content(&self, theme: &Theme) -> Doc<'_> {
         Doc::nil()
-            .append(theme.keyword(&"Definition"))
-            .append(Doc::space())
             .append(self.pattern.pretty(theme))
+            .append(pretty_binders(&self.binders, theme))
             .append(Doc::group(
                 Doc::nil().append(
                     Doc::line()
@@ -63,14 +117,71 @@ impl Pretty for Definition {
                         .nest(4),
                 ),
             ))
+            .append(match &self.contract {
+                Some(contract) => Doc::line().append(contract.pretty(theme)).nest(2).group(),
+                None => Doc::nil(),
+            })
             .append(Doc::space())
             .append(theme.operator(&":="))
             .append(Doc::line().append(self.body.pretty(theme).group()).nest(2))
     }
 }
 
+impl Pretty for Definition {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.keyword(&"Definition"))
+            .append(Doc::space())
+            .append(self.pretty_content(theme))
+    }
+}
+
 impl Located for Definition {
     fn loc(&self) -> Location {
         self.pattern.loc().union(self.body.loc())
     }
 }
+
+// ==========================================================================
+// Fixpoint
+// ==========================================================================
+/// mutually recursive definitions
+pub struct Fixpoint {
+    definitions: Vec<Definition>,
+}
+
+impl Fixpoint {
+    /// Create mutually recursive definitions
+    pub fn new(definitions: Vec<Definition>) -> Self {
+        Self { definitions }
+    }
+
+    /// get definitions, in order
+    pub fn definitions(&self) -> &[Definition] {
+        &self.definitions
+    }
+}
+
+impl Pretty for Fixpoint {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::intersperse(
+            self.definitions.iter().enumerate().map(|(i, def)| {
+                let keyword = if i == 0 { "Fixpoint" } else { "with" };
+                Doc::nil()
+                    .append(theme.keyword(&keyword))
+                    .append(Doc::space())
+                    .append(def.pretty_content(theme))
+            }),
+            Doc::hardline(),
+        )
+    }
+}
+
+impl Located for Fixpoint {
+    fn loc(&self) -> Location {
+        match (self.definitions.first(), self.definitions.last()) {
+            (Some(first), Some(last)) => first.loc().union(last.loc()),
+            _ => Location::unknown(),
+        }
+    }
+}
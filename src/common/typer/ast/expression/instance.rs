@@ -0,0 +1,90 @@
+use super::super::identifier::Identifier;
+use super::super::ty::{Type, TypeRecord, Typed, TypedMut};
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// ==========================================================================
+// Expression Instance
+// ==========================================================================
+/// dictionary of an instance of class `Eq τ`, resolved once `τ` is known:
+/// the variable of an instance, or a dictionary parameter of the definition
+#[derive(Debug, Clone)]
+pub struct Instance {
+    /// class applied to the type of the instance
+    ty: Type,
+    /// variable of the dictionary, shared by the copies of the instance
+    dict: Rc<RefCell<Option<Identifier>>>,
+    /// location of instance, the location of the use of a method
+    loc: Location,
+}
+
+impl Instance {
+    /// make a new instance of class `class`, not resolved yet
+    pub fn new(class: TypeRecord, loc: Location) -> Self {
+        Self {
+            ty: Type::Record(class),
+            dict: Rc::new(RefCell::new(None)),
+            loc,
+        }
+    }
+
+    /// get class applied to the type of the instance
+    pub fn class(&self) -> &TypeRecord {
+        self.ty.as_record().expect("type of an instance is a class")
+    }
+
+    /// get variable of the dictionary, once resolved
+    pub fn dict(&self) -> Option<Identifier> {
+        self.dict.borrow().clone()
+    }
+
+    /// resolve instance with the variable of a dictionary
+    pub fn resolve(&self, dict: Identifier) {
+        *self.dict.borrow_mut() = Some(dict);
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Instance {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Instance {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Instance {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Instance {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Instance {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self.dict() {
+            Some(dict) => theme.expr_var(&dict),
+            None => Doc::nil()
+                .append(theme.operator(&"("))
+                .append(theme.keyword(&"Instance"))
+                .append(Doc::space())
+                .append(self.ty.pretty(theme))
+                .append(theme.operator(&")"))
+                .group(),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::super::ty::{Type, TypeArrow, Typed, TypedMut};
+use super::super::Pattern;
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Match Branch
+// ==========================================================================
+pub struct Branch {
+    /// pattern of branch
+    pattern: Rc<Pattern>,
+    /// body of branch
+    body: Rc<Expression>,
+}
+
+impl Branch {
+    /// make a new branch
+    pub fn new(pattern: Pattern, body: Expression) -> Self {
+        Self {
+            pattern: Rc::new(pattern),
+            body: Rc::new(body),
+        }
+    }
+
+    /// get pattern of branch
+    pub fn pattern(&self) -> &Rc<Pattern> {
+        &self.pattern
+    }
+
+    /// get body of branch
+    pub fn body(&self) -> &Rc<Expression> {
+        &self.body
+    }
+}
+
+// ==========================================================================
+// Expression Match
+// ==========================================================================
+pub struct Match {
+    /// branches of pattern matching
+    branches: Vec<Branch>,
+    /// type of pattern matching
+    ty: Type,
+    /// location of pattern matching
+    loc: Location,
+}
+
+impl Match {
+    /// make a new pattern matching, `ty_arg` is the type of the patterns
+    /// and `ty_ret` the type of the bodies
+    pub fn new(branches: Vec<Branch>, ty_arg: Type, ty_ret: Type) -> Self {
+        Self {
+            branches,
+            ty: Type::Arrow(TypeArrow::new(ty_arg, ty_ret)),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get branches of pattern matching
+    pub fn branches(&self) -> &[Branch] {
+        &self.branches
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Match {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Match {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Match {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Match {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Match {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let last = self.branches.len().saturating_sub(1);
+        Doc::intersperse(
+            self.branches.iter().enumerate().map(|(i, branch)| {
+                let prec = if i < last { 10 } else { 11 };
+                Doc::nil()
+                    .append(theme.operator(&"|"))
+                    .append(Doc::space())
+                    .append(branch.pattern.pretty(theme))
+                    .append(Doc::space())
+                    .append(theme.operator(&"=>"))
+                    .append(
+                        Doc::line()
+                            .append(branch.body.pretty_precedence(prec, theme))
+                            .nest(2),
+                    )
+                    .group()
+            }),
+            Doc::line(),
+        )
+        .group()
+    }
+}
@@ -3,12 +3,45 @@ use crate::utils::location::{Located, LocatedSet, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
 
+mod abstraction;
+mod application;
+mod binding;
+mod coercion;
+mod conditional;
 mod constant;
+mod contract;
 mod definition;
+mod instance;
+mod matching;
+mod operation;
+mod quantifier;
+mod record;
+mod reference;
+mod tuple;
 
 pub use super::super::env::Variable as ExpressionVariable;
-pub use constant::{Constant, ConstantKind};
-pub use definition::Definition as ExpressionDefinition;
+pub use abstraction::Abstraction as ExpressionAbstraction;
+pub use application::Application as ExpressionApplication;
+pub use binding::Let as ExpressionLet;
+pub use coercion::Coercion as ExpressionCoercion;
+pub use conditional::If as ExpressionIf;
+pub use constant::{Constant, ConstantKind, Primitive};
+pub use contract::{Checked as ExpressionChecked, Clause, Contract, ContractKind};
+pub use definition::{Definition as ExpressionDefinition, Fixpoint};
+pub use instance::Instance as ExpressionInstance;
+pub use matching::{Branch as MatchBranch, Match as ExpressionMatch};
+pub use operation::{
+    Binary as ExpressionBinary, BinaryOperator, Unary as ExpressionUnary, UnaryOperator,
+};
+pub use quantifier::{Quantifier as ExpressionQuantifier, QuantifierKind};
+pub use record::{
+    Projection as ExpressionProjection, Record as ExpressionRecord, RecordField,
+    Update as ExpressionUpdate,
+};
+pub use reference::{
+    Alloc as ExpressionAlloc, Assign as ExpressionAssign, Deref as ExpressionDeref,
+};
+pub use tuple::Tuple as ExpressionTuple;
 
 // ==========================================================================
 // Expression
@@ -17,6 +50,90 @@ pub use definition::Definition as ExpressionDefinition;
 pub enum Expression {
     Constant(Constant),
     Variable(ExpressionVariable),
+    Abstraction(ExpressionAbstraction),
+    Application(ExpressionApplication),
+    Tuple(ExpressionTuple),
+    Match(ExpressionMatch),
+    Binary(ExpressionBinary),
+    Unary(ExpressionUnary),
+    If(ExpressionIf),
+    Let(ExpressionLet),
+    Quantifier(ExpressionQuantifier),
+    Checked(ExpressionChecked),
+    Alloc(ExpressionAlloc),
+    Deref(ExpressionDeref),
+    Assign(ExpressionAssign),
+    Record(ExpressionRecord),
+    Update(ExpressionUpdate),
+    Projection(ExpressionProjection),
+    Coercion(ExpressionCoercion),
+    Instance(ExpressionInstance),
+}
+
+impl Expression {
+    /// precedence level of expression
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression::Constant(_)
+            | Expression::Variable(_)
+            | Expression::Record(_)
+            | Expression::Update(_)
+            | Expression::Projection(_)
+            | Expression::Coercion(_)
+            | Expression::Instance(_) => 0,
+            Expression::Application(_) | Expression::Alloc(_) | Expression::Deref(_) => 1,
+            Expression::Binary(b) => b.op().precedence(),
+            Expression::Unary(u) => u.op().precedence(),
+            Expression::Assign(_) => 9,
+            Expression::Tuple(_) => 10,
+            Expression::Abstraction(_)
+            | Expression::Match(_)
+            | Expression::If(_)
+            | Expression::Let(_)
+            | Expression::Quantifier(_) => 11,
+            Expression::Checked(c) => c.body().precedence(),
+        }
+    }
+
+    /// expression is a syntactic value, its evaluation neither applies a function
+    /// nor allocates a reference, so its unknown types can be generalised
+    pub fn is_value(&self) -> bool {
+        match self {
+            Expression::Constant(_)
+            | Expression::Variable(_)
+            | Expression::Abstraction(_)
+            | Expression::Match(_)
+            | Expression::Quantifier(_)
+            | Expression::Instance(_) => true,
+            Expression::Tuple(t) => t.elems().iter().all(Expression::is_value),
+            Expression::Record(r) => r.fields().iter().all(|field| field.value().is_value()),
+            Expression::Checked(c) => c.body().is_value(),
+            Expression::Coercion(c) => c.expr().is_value(),
+            // a method of a class is the projection of a dictionary
+            Expression::Projection(p) => p.record().is_value(),
+            Expression::Application(_)
+            | Expression::Binary(_)
+            | Expression::Unary(_)
+            | Expression::If(_)
+            | Expression::Let(_)
+            | Expression::Alloc(_)
+            | Expression::Deref(_)
+            | Expression::Assign(_)
+            | Expression::Update(_) => false,
+        }
+    }
+
+    /// pretty print expression, with parenthesis if precedence is greater than `prec`
+    pub fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        if self.precedence() > prec {
+            Doc::nil()
+                .append(theme.operator(&"("))
+                .append(self.pretty(theme))
+                .append(theme.operator(&")"))
+        } else {
+            self.pretty(theme)
+        }
+    }
 }
 
 mod sealed_mut_ty {
@@ -26,6 +143,24 @@ mod sealed_mut_ty {
             match self {
                 Expression::Constant(c) => c.ty_mut(),
                 Expression::Variable(v) => v.ty_mut(),
+                Expression::Abstraction(a) => a.ty_mut(),
+                Expression::Application(a) => a.ty_mut(),
+                Expression::Tuple(t) => t.ty_mut(),
+                Expression::Match(m) => m.ty_mut(),
+                Expression::Binary(b) => b.ty_mut(),
+                Expression::Unary(u) => u.ty_mut(),
+                Expression::If(i) => i.ty_mut(),
+                Expression::Let(l) => l.ty_mut(),
+                Expression::Quantifier(q) => q.ty_mut(),
+                Expression::Checked(c) => c.ty_mut(),
+                Expression::Alloc(a) => a.ty_mut(),
+                Expression::Deref(d) => d.ty_mut(),
+                Expression::Assign(a) => a.ty_mut(),
+                Expression::Record(r) => r.ty_mut(),
+                Expression::Update(u) => u.ty_mut(),
+                Expression::Projection(p) => p.ty_mut(),
+                Expression::Coercion(c) => c.ty_mut(),
+                Expression::Instance(i) => i.ty_mut(),
             }
         }
     }
@@ -36,6 +171,24 @@ impl Typed for Expression {
         match self {
             Expression::Constant(c) => c.ty(),
             Expression::Variable(v) => v.ty(),
+            Expression::Abstraction(a) => a.ty(),
+            Expression::Application(a) => a.ty(),
+            Expression::Tuple(t) => t.ty(),
+            Expression::Match(m) => m.ty(),
+            Expression::Binary(b) => b.ty(),
+            Expression::Unary(u) => u.ty(),
+            Expression::If(i) => i.ty(),
+            Expression::Let(l) => l.ty(),
+            Expression::Quantifier(q) => q.ty(),
+            Expression::Checked(c) => c.ty(),
+            Expression::Alloc(a) => a.ty(),
+            Expression::Deref(d) => d.ty(),
+            Expression::Assign(a) => a.ty(),
+            Expression::Record(r) => r.ty(),
+            Expression::Update(u) => u.ty(),
+            Expression::Projection(p) => p.ty(),
+            Expression::Coercion(c) => c.ty(),
+            Expression::Instance(i) => i.ty(),
         }
     }
 }
@@ -45,6 +198,24 @@ impl Located for Expression {
         match self {
             Expression::Constant(c) => c.loc(),
             Expression::Variable(v) => v.loc(),
+            Expression::Abstraction(a) => a.loc(),
+            Expression::Application(a) => a.loc(),
+            Expression::Tuple(t) => t.loc(),
+            Expression::Match(m) => m.loc(),
+            Expression::Binary(b) => b.loc(),
+            Expression::Unary(u) => u.loc(),
+            Expression::If(i) => i.loc(),
+            Expression::Let(l) => l.loc(),
+            Expression::Quantifier(q) => q.loc(),
+            Expression::Checked(c) => c.loc(),
+            Expression::Alloc(a) => a.loc(),
+            Expression::Deref(d) => d.loc(),
+            Expression::Assign(a) => a.loc(),
+            Expression::Record(r) => r.loc(),
+            Expression::Update(u) => u.loc(),
+            Expression::Projection(p) => p.loc(),
+            Expression::Coercion(c) => c.loc(),
+            Expression::Instance(i) => i.loc(),
         }
     }
 }
@@ -54,6 +225,24 @@ impl LocatedSet for Expression {
         match self {
             Expression::Constant(c) => c.set_loc(loc),
             Expression::Variable(v) => v.set_loc(loc),
+            Expression::Abstraction(a) => a.set_loc(loc),
+            Expression::Application(a) => a.set_loc(loc),
+            Expression::Tuple(t) => t.set_loc(loc),
+            Expression::Match(m) => m.set_loc(loc),
+            Expression::Binary(b) => b.set_loc(loc),
+            Expression::Unary(u) => u.set_loc(loc),
+            Expression::If(i) => i.set_loc(loc),
+            Expression::Let(l) => l.set_loc(loc),
+            Expression::Quantifier(q) => q.set_loc(loc),
+            Expression::Checked(c) => c.set_loc(loc),
+            Expression::Alloc(a) => a.set_loc(loc),
+            Expression::Deref(d) => d.set_loc(loc),
+            Expression::Assign(a) => a.set_loc(loc),
+            Expression::Record(r) => r.set_loc(loc),
+            Expression::Update(u) => u.set_loc(loc),
+            Expression::Projection(p) => p.set_loc(loc),
+            Expression::Coercion(c) => c.set_loc(loc),
+            Expression::Instance(i) => i.set_loc(loc),
         }
     }
 }
@@ -63,6 +252,24 @@ impl Pretty for Expression {
         match self {
             Expression::Constant(c) => c.pretty(theme),
             Expression::Variable(v) => v.pretty(theme),
+            Expression::Abstraction(a) => a.pretty(theme),
+            Expression::Application(a) => a.pretty(theme),
+            Expression::Tuple(t) => t.pretty(theme),
+            Expression::Match(m) => m.pretty(theme),
+            Expression::Binary(b) => b.pretty(theme),
+            Expression::Unary(u) => u.pretty(theme),
+            Expression::If(i) => i.pretty(theme),
+            Expression::Let(l) => l.pretty(theme),
+            Expression::Quantifier(q) => q.pretty(theme),
+            Expression::Checked(c) => c.pretty(theme),
+            Expression::Alloc(a) => a.pretty(theme),
+            Expression::Deref(d) => d.pretty(theme),
+            Expression::Assign(a) => a.pretty(theme),
+            Expression::Record(r) => r.pretty(theme),
+            Expression::Update(u) => u.pretty(theme),
+            Expression::Projection(p) => p.pretty(theme),
+            Expression::Coercion(c) => c.pretty(theme),
+            Expression::Instance(i) => i.pretty(theme),
         }
     }
 }
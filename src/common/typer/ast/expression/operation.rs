@@ -0,0 +1,245 @@
+use super::super::ty::{Type, TypeBuiltin, TypeBuiltinKind, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+pub use crate::parser::cst::operator::{
+    BinaryOperatorT as BinaryOperator, UnaryOperatorT as UnaryOperator,
+};
+
+// ==========================================================================
+// Operator Types
+// ==========================================================================
+impl BinaryOperator {
+    /// type of operands and type of result of operator,
+    /// arithmetic and comparison operators are on the type `numeric` (see [`Self::operand`])
+    pub fn signature(&self, numeric: Type) -> (Type, Type) {
+        let bool = Type::Builtin(TypeBuiltin::bool());
+        let prop = Type::Builtin(TypeBuiltin::prop());
+        match self {
+            Self::Mul | Self::Div | Self::Mod | Self::Add | Self::Sub => (numeric.clone(), numeric),
+            Self::Eq
+            | Self::NotEq
+            | Self::Less
+            | Self::LessEq
+            | Self::Greater
+            | Self::GreaterEq => (numeric, bool),
+            Self::And | Self::Or => (bool.clone(), bool),
+            Self::Conj | Self::Disj | Self::Implies => (prop.clone(), prop),
+        }
+    }
+
+    /// type of the operands of an operation on operands of types `left` and `right`:
+    /// equality is also on 𝔹, Char, String and unit, other operators are on numbers
+    pub fn operand(&self, left: &Type, right: &Type) -> Type {
+        let is_comparable = |ty: &&Type| {
+            matches!(ty.unfold(), Type::Builtin(b) if matches!(
+                b.kind(),
+                TypeBuiltinKind::Bool
+                    | TypeBuiltinKind::Char
+                    | TypeBuiltinKind::String
+                    | TypeBuiltinKind::Unit
+            ))
+        };
+        match self {
+            Self::Eq | Self::NotEq => [left, right]
+                .into_iter()
+                .find(is_comparable)
+                .map_or_else(|| Self::numeric(left, right), |ty| ty.unfold().clone()),
+            _ => Self::numeric(left, right),
+        }
+    }
+
+    /// number type of an operation on operands of types `left` and `right`:
+    /// ℚ if one of them is ℚ, else ℤ if one of them is ℤ, ℕ otherwise
+    pub fn numeric(left: &Type, right: &Type) -> Type {
+        let is = |builtin: &TypeBuiltin| {
+            [left, right]
+                .iter()
+                .any(|ty| matches!(ty.unfold(), Type::Builtin(b) if b == builtin))
+        };
+        [TypeBuiltin::rat(), TypeBuiltin::int()]
+            .into_iter()
+            .find(is)
+            .map_or(Type::Builtin(TypeBuiltin::nat()), Type::Builtin)
+    }
+}
+
+impl UnaryOperator {
+    /// type of operand and type of result of operator on an operand of type `operand`,
+    /// negation is on ℤ, or on ℚ if `operand` is ℚ
+    pub fn signature(&self, operand: &Type) -> (Type, Type) {
+        let bool = Type::Builtin(TypeBuiltin::bool());
+        match self {
+            Self::Not => (bool.clone(), bool),
+            Self::Neg => {
+                let numeric = BinaryOperator::numeric(operand, &Type::Builtin(TypeBuiltin::int()));
+                (numeric.clone(), numeric)
+            }
+        }
+    }
+}
+
+// ==========================================================================
+// Expression Binary Operation
+// ==========================================================================
+pub struct Binary {
+    /// operator
+    op: BinaryOperator,
+    /// left operand
+    left: Box<Expression>,
+    /// right operand
+    right: Box<Expression>,
+    /// type of operation
+    ty: Type,
+    /// location of operation
+    loc: Location,
+}
+
+impl Binary {
+    /// make a new binary operation of type `ty`
+    pub fn new(op: BinaryOperator, left: Expression, right: Expression, ty: Type) -> Self {
+        Self {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get operator
+    pub fn op(&self) -> BinaryOperator {
+        self.op
+    }
+
+    /// get left operand
+    pub fn left(&self) -> &Expression {
+        &self.left
+    }
+
+    /// get right operand
+    pub fn right(&self) -> &Expression {
+        &self.right
+    }
+}
+
+mod sealed_mut_ty_binary {
+    use super::*;
+    impl TypedMut for Binary {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Binary {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Binary {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Binary {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Binary {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let (prec_left, prec_right) = self.op.operands_precedence();
+        Doc::nil()
+            .append(self.left.pretty_precedence(prec_left, theme))
+            .append(Doc::space())
+            .append(self.op.pretty(theme))
+            .append(
+                Doc::line()
+                    .append(self.right.pretty_precedence(prec_right, theme))
+                    .nest(2),
+            )
+            .group()
+    }
+}
+
+// ==========================================================================
+// Expression Unary Operation
+// ==========================================================================
+pub struct Unary {
+    /// operator
+    op: UnaryOperator,
+    /// operand
+    expr: Box<Expression>,
+    /// type of operation
+    ty: Type,
+    /// location of operation
+    loc: Location,
+}
+
+impl Unary {
+    /// make a new prefix operation, its type is the result type of the operator
+    pub fn new(op: UnaryOperator, expr: Expression) -> Self {
+        let (_, ty) = op.signature(expr.ty());
+        Self {
+            op,
+            expr: Box::new(expr),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get operator
+    pub fn op(&self) -> UnaryOperator {
+        self.op
+    }
+
+    /// get operand
+    pub fn expr(&self) -> &Expression {
+        &self.expr
+    }
+}
+
+mod sealed_mut_ty_unary {
+    use super::*;
+    impl TypedMut for Unary {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Unary {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Unary {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Unary {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Unary {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.op.pretty(theme))
+            .append(Doc::space())
+            .append(
+                self.expr
+                    .pretty_precedence(self.op.operand_precedence(), theme),
+            )
+    }
+}
@@ -0,0 +1,111 @@
+use super::super::pattern::PatternVar;
+use super::super::ty::{Type, TypeBuiltin, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+pub use crate::parser::cst::expression::QuantifierKeywordT as QuantifierKind;
+
+// ==========================================================================
+// Expression Quantifier
+// ==========================================================================
+pub struct Quantifier {
+    /// universal or existential quantifier
+    kind: QuantifierKind,
+    /// variables bound by the quantifier
+    vars: Vec<PatternVar>,
+    /// type of the bound variables
+    ty_var: Type,
+    /// quantified proposition
+    body: Box<Expression>,
+    /// type of quantifier, always `Prop`
+    ty: Type,
+    /// location of quantifier
+    loc: Location,
+}
+
+impl Quantifier {
+    /// make a new quantified proposition over variables `vars` of type `ty_var`
+    pub fn new(
+        kind: QuantifierKind,
+        vars: Vec<PatternVar>,
+        ty_var: Type,
+        body: Expression,
+    ) -> Self {
+        Self {
+            kind,
+            vars,
+            ty_var,
+            body: Box::new(body),
+            ty: Type::Builtin(TypeBuiltin::prop()),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get kind of quantifier
+    pub fn kind(&self) -> QuantifierKind {
+        self.kind
+    }
+
+    /// get bound variables
+    pub fn vars(&self) -> &[PatternVar] {
+        &self.vars
+    }
+
+    /// get type of bound variables
+    pub fn ty_var(&self) -> &Type {
+        &self.ty_var
+    }
+
+    /// get quantified proposition
+    pub fn body(&self) -> &Expression {
+        &self.body
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Quantifier {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Quantifier {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Quantifier {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Quantifier {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Quantifier {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.kind.pretty(theme))
+            .append(Doc::space())
+            .append(Doc::intersperse(
+                self.vars.iter().map(|var| var.pretty(theme)),
+                Doc::space(),
+            ))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::space())
+            .append(self.ty_var.pretty(theme))
+            .append(theme.operator(&","))
+            .append(Doc::line().append(self.body.pretty(theme)).nest(2))
+            .group()
+    }
+}
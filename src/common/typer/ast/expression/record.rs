@@ -0,0 +1,290 @@
+use super::super::ty::{Type, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Record Field
+// ==========================================================================
+/// value of a field `x := e`
+pub struct RecordField {
+    /// name of field
+    name: String,
+    /// position of field in the definition of the record type
+    index: usize,
+    /// value of field
+    value: Expression,
+    /// location of field
+    loc: Location,
+}
+
+impl RecordField {
+    /// make a new field value, `index` is the position of the field in its record type
+    pub fn new(name: String, index: usize, value: Expression, loc: Location) -> Self {
+        Self {
+            name,
+            index,
+            value,
+            loc,
+        }
+    }
+
+    /// get name of field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// get position of field in the definition of the record type
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// get value of field
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+}
+
+impl Located for RecordField {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl Pretty for RecordField {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.expr_var(&self.name))
+            .append(Doc::space())
+            .append(theme.operator(&":="))
+            .append(Doc::line().append(self.value.pretty(theme)).nest(2))
+            .group()
+    }
+}
+
+/// pretty fields between braces, after `head`
+fn pretty_fields<'a>(head: Doc<'a>, fields: &'a [RecordField], theme: &Theme) -> Doc<'a> {
+    Doc::nil()
+        .append(theme.operator(&"{"))
+        .append(Doc::space())
+        .append(head)
+        .append(Doc::intersperse(
+            fields.iter().map(|field| field.pretty(theme)),
+            theme.operator(&";").append(Doc::line()),
+        ))
+        .append(Doc::space())
+        .append(theme.operator(&"}"))
+        .nest(2)
+        .group()
+}
+
+// ==========================================================================
+// Expression Record
+// ==========================================================================
+/// record `{ x := e1; y := e2 }`
+pub struct Record {
+    /// values of fields, in order of the source
+    fields: Vec<RecordField>,
+    /// type of record
+    ty: Type,
+    /// location of record
+    loc: Location,
+}
+
+impl Record {
+    /// make a new record of type `ty`, all fields of the type are given
+    pub fn new(fields: Vec<RecordField>, ty: Type) -> Self {
+        Self {
+            fields,
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get values of fields, in order of the source
+    pub fn fields(&self) -> &[RecordField] {
+        &self.fields
+    }
+}
+
+mod sealed_mut_ty_record {
+    use super::*;
+    impl TypedMut for Record {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Record {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Record {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Record {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Record {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        pretty_fields(Doc::nil(), &self.fields, theme)
+    }
+}
+
+// ==========================================================================
+// Expression Update
+// ==========================================================================
+/// copy of a record with new values for some fields `{ e with x := e1 }`
+pub struct Update {
+    /// updated record
+    record: Box<Expression>,
+    /// new values of fields, in order of the source
+    fields: Vec<RecordField>,
+    /// location of update
+    loc: Location,
+}
+
+impl Update {
+    /// make a new update, its type is the type of the updated record
+    pub fn new(record: Expression, fields: Vec<RecordField>) -> Self {
+        Self {
+            record: Box::new(record),
+            fields,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get updated record
+    pub fn record(&self) -> &Expression {
+        &self.record
+    }
+
+    /// get new values of fields, in order of the source
+    pub fn fields(&self) -> &[RecordField] {
+        &self.fields
+    }
+}
+
+mod sealed_mut_ty_update {
+    use super::*;
+    impl TypedMut for Update {
+        fn ty_mut(&mut self) -> &mut Type {
+            self.record.ty_mut()
+        }
+    }
+}
+
+impl Typed for Update {
+    fn ty(&self) -> &Type {
+        self.record.ty()
+    }
+}
+
+impl Located for Update {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Update {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Update {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let head = Doc::nil()
+            .append(self.record.pretty(theme))
+            .append(Doc::space())
+            .append(theme.keyword(&"with"))
+            .append(Doc::line());
+        pretty_fields(head, &self.fields, theme)
+    }
+}
+
+// ==========================================================================
+// Expression Projection
+// ==========================================================================
+/// projection on a field of a record `e.x`
+pub struct Projection {
+    /// projected record
+    record: Box<Expression>,
+    /// name of field
+    field: String,
+    /// position of field in the definition of the record type
+    index: usize,
+    /// type of projection, the type of the field
+    ty: Type,
+    /// location of projection
+    loc: Location,
+}
+
+impl Projection {
+    /// make a new projection on field at `index`, its type is `ty`, the type of the field
+    pub fn new(record: Expression, field: String, index: usize, ty: Type) -> Self {
+        Self {
+            record: Box::new(record),
+            field,
+            index,
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get projected record
+    pub fn record(&self) -> &Expression {
+        &self.record
+    }
+
+    /// get position of field in the definition of the record type
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+mod sealed_mut_ty_projection {
+    use super::*;
+    impl TypedMut for Projection {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Projection {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Projection {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Projection {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Projection {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.record.pretty_precedence(0, theme))
+            .append(theme.operator(&"."))
+            .append(theme.expr_var(&self.field))
+    }
+}
@@ -0,0 +1,214 @@
+use super::super::ty::{Type, TypeBuiltin, TypeReference, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Expression Allocation
+// ==========================================================================
+/// allocation of a new reference `ref e`
+pub struct Alloc {
+    /// initial value of the reference
+    value: Box<Expression>,
+    /// type of allocation
+    ty: Type,
+    /// location of allocation
+    loc: Location,
+}
+
+impl Alloc {
+    /// make a new allocation, its type is a reference to the type of its value
+    pub fn new(value: Expression) -> Self {
+        let ty = Type::Reference(TypeReference::new(value.ty().clone()));
+        Self {
+            value: Box::new(value),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get initial value
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+}
+
+mod sealed_mut_ty_alloc {
+    use super::*;
+    impl TypedMut for Alloc {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Alloc {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Alloc {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Alloc {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Alloc {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.keyword(&"ref"))
+            .append(Doc::space())
+            .append(self.value.pretty_precedence(1, theme))
+    }
+}
+
+// ==========================================================================
+// Expression Dereference
+// ==========================================================================
+/// read of a reference `!r`
+pub struct Deref {
+    /// read reference
+    reference: Box<Expression>,
+    /// type of dereference, the type of the content of the reference
+    ty: Type,
+    /// location of dereference
+    loc: Location,
+}
+
+impl Deref {
+    /// make a new dereference, its type is `ty`, the type of the content of the reference
+    pub fn new(reference: Expression, ty: Type) -> Self {
+        Self {
+            reference: Box::new(reference),
+            ty,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get read reference
+    pub fn reference(&self) -> &Expression {
+        &self.reference
+    }
+}
+
+mod sealed_mut_ty_deref {
+    use super::*;
+    impl TypedMut for Deref {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Deref {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Deref {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Deref {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Deref {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.operator(&"!"))
+            .append(self.reference.pretty_precedence(1, theme))
+    }
+}
+
+// ==========================================================================
+// Expression Assignment
+// ==========================================================================
+/// write of a reference `r <- e`, of unit type
+pub struct Assign {
+    /// written reference
+    reference: Box<Expression>,
+    /// written value
+    value: Box<Expression>,
+    /// type of assignment
+    ty: Type,
+    /// location of assignment
+    loc: Location,
+}
+
+impl Assign {
+    /// make a new assignment
+    pub fn new(reference: Expression, value: Expression) -> Self {
+        Self {
+            reference: Box::new(reference),
+            value: Box::new(value),
+            ty: Type::Builtin(TypeBuiltin::unit()),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get written reference
+    pub fn reference(&self) -> &Expression {
+        &self.reference
+    }
+
+    /// get written value
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+}
+
+mod sealed_mut_ty_assign {
+    use super::*;
+    impl TypedMut for Assign {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Assign {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Assign {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Assign {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Assign {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.reference.pretty_precedence(8, theme))
+            .append(Doc::space())
+            .append(theme.operator(&"<-"))
+            .append(
+                Doc::line()
+                    .append(self.value.pretty_precedence(8, theme))
+                    .nest(2),
+            )
+            .group()
+    }
+}
@@ -0,0 +1,73 @@
+use super::super::ty::{Type, TypeProduct, Typed, TypedMut};
+use super::Expression;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Expression Tuple
+// ==========================================================================
+pub struct Tuple {
+    /// components of tuple
+    elems: Vec<Expression>,
+    /// type of tuple
+    ty: Type,
+    /// location of tuple
+    loc: Location,
+}
+
+impl Tuple {
+    /// make a new tuple
+    pub fn new(elems: Vec<Expression>) -> Self {
+        let tys = elems.iter().map(|elem| elem.ty().clone()).collect();
+        Self {
+            elems,
+            ty: Type::Product(TypeProduct::new(tys)),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get components of tuple
+    pub fn elems(&self) -> &[Expression] {
+        &self.elems
+    }
+}
+
+mod sealed_mut_ty {
+    use super::*;
+    impl TypedMut for Tuple {
+        fn ty_mut(&mut self) -> &mut Type {
+            &mut self.ty
+        }
+    }
+}
+
+impl Typed for Tuple {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Located for Tuple {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Tuple {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+impl Pretty for Tuple {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::intersperse(
+            self.elems
+                .iter()
+                .map(|elem| elem.pretty_precedence(9, theme)),
+            theme.operator(&",").append(Doc::line()),
+        )
+        .group()
+    }
+}
@@ -13,6 +13,8 @@ pub enum Name {
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct Identifier {
     name: Name,
+    /// modules being defined when the identifier was built, outermost first
+    path: Vec<String>,
     id: usize,
 }
 
@@ -25,6 +27,16 @@ impl Identifier {
         }
     }
 
+    /// get name of identifier qualified by the modules containing it `M.x`
+    pub fn qualified_name(&self) -> String {
+        self.path
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(self.name()))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
     /// get id
     pub fn id(&self) -> usize {
         self.id
@@ -37,52 +49,280 @@ impl std::fmt::Display for Identifier {
     }
 }
 
+// ==========================================================================
+// Module
+// ==========================================================================
+
+/// how a name became visible inside a module
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Visibility {
+    /// defined in module and exported by it
+    Public,
+    /// defined in module and hidden outside of it
+    Private,
+    /// defined in an opened module
+    Opened,
+}
+
+/// module being defined
+#[derive(Debug)]
+struct Module {
+    /// name of module
+    name: String,
+    /// names made visible in module and still visible, in order
+    names: Vec<(String, Rc<Identifier>, Visibility)>,
+}
+
+// ==========================================================================
+// Exported Names
+// ==========================================================================
+
+/// names with the identifiers they make visible
+type Names = Vec<(String, Rc<Identifier>)>;
+
+/// names exported by a checked file, with the number of identifiers built by name
+/// to check it
+#[derive(Debug, Clone, Default)]
+pub struct ExportedNames {
+    /// visible names
+    names: Names,
+    /// names exported by modules, by qualified name of module
+    modules: Vec<(String, Names)>,
+    /// number of identifiers built by name
+    counter: HashMap<String, usize>,
+}
+
 // ==========================================================================
 // Indentifier Builder
 // ==========================================================================
 
 #[derive(Debug, Default)]
 pub struct IdentifierBuilder {
+    /// visible identifiers by name
     table: HashMap<String, Vec<Rc<Identifier>>>,
+    /// number of identifiers built by name
+    counter: HashMap<String, usize>,
+    /// modules being defined, innermost last
+    modules: Vec<Module>,
+    /// names exported by closed modules, by qualified name of module
+    exports: HashMap<String, Vec<(String, Rc<Identifier>)>>,
+    /// names made visible are private to the current module
+    private: bool,
 }
 
 impl IdentifierBuilder {
+    /// create a new identifier that is not visible by name
+    pub fn fresh(&mut self, name: &str) -> Rc<Identifier> {
+        let path = self.path();
+        let count = self.counter.entry(name.to_string()).or_default();
+        let ident = Identifier {
+            name: Name::Named(name.to_string()),
+            path,
+            id: *count,
+        };
+        *count += 1;
+        Rc::new(ident)
+    }
+
     /// create a new identifier
     pub fn build(&mut self, name: &str) -> Rc<Identifier> {
-        match self.table.get_mut(name) {
-            Some(idents) => {
-                let id = idents.len();
-                let ident = Identifier {
-                    name: Name::Named(name.to_string()),
-                    id,
-                };
-                let ident_rc = Rc::new(ident);
-                idents.push(ident_rc.clone());
-                ident_rc
-            }
-            None => {
-                let ident = Identifier {
-                    name: Name::Named(name.to_string()),
-                    id: 0,
-                };
-                let ident_rc = Rc::new(ident);
-                self.table.insert(name.to_string(), vec![ident_rc.clone()]);
-                ident_rc
-            }
+        let ident_rc = self.fresh(name);
+        self.push(ident_rc.clone());
+        ident_rc
+    }
+
+    /// make identifier visible by name again, after it was removed from scope
+    pub fn push(&mut self, id: Rc<Identifier>) {
+        let visibility = if self.private {
+            Visibility::Private
+        } else {
+            Visibility::Public
+        };
+        self.push_name(id.name().to_string(), id, visibility);
+    }
+
+    /// make identifier visible by `name` in the current module
+    fn push_name(&mut self, name: String, id: Rc<Identifier>, visibility: Visibility) {
+        if let Some(module) = self.modules.last_mut() {
+            module.names.push((name.clone(), id.clone(), visibility));
         }
+        self.table.entry(name).or_default().push(id);
     }
 
     /// get identifier by name
     pub fn get(&self, name: &str) -> Rc<Identifier> {
+        self.lookup(name).unwrap_or_else(|| {
+            let ident = Identifier {
+                name: Name::Named(name.to_string()),
+                path: Vec::new(),
+                id: self.counter.get(name).copied().unwrap_or_default(),
+            };
+            Rc::new(ident)
+        })
+    }
+
+    /// get identifier by name, if it is visible
+    pub fn lookup(&self, name: &str) -> Option<Rc<Identifier>> {
         self.table
             .get(name)
             .and_then(|idents| idents.last().cloned())
-            .unwrap_or_else(|| {
-                let ident = Identifier {
-                    name: Name::Named(name.to_string()),
-                    id: 0,
-                };
-                Rc::new(ident)
-            })
+    }
+
+    /// get identifiers visible by name, including shadowed ones
+    pub fn visible(&self) -> impl Iterator<Item = &Rc<Identifier>> {
+        self.table.values().flatten()
+    }
+
+    /// remove identifier from scope
+    pub fn pop(&mut self, id: &Identifier) {
+        if let Some(module) = self.modules.last_mut() {
+            let index = module
+                .names
+                .iter()
+                .rposition(|(name, other, _)| name == id.name() && other.as_ref() == id);
+            if let Some(index) = index {
+                module.names.remove(index);
+            }
+        }
+        self.hide(id.name(), id);
+    }
+
+    /// remove identifier visible by `name` from scope
+    fn hide(&mut self, name: &str, id: &Identifier) {
+        if let Some(idents) = self.table.get_mut(name) {
+            if idents.last().is_some_and(|last| last.as_ref() == id) {
+                idents.pop();
+            }
+        }
+    }
+
+    /// make next identifiers private to the current module, or public again
+    pub fn set_private(&mut self, private: bool) {
+        self.private = private;
+    }
+
+    /// get `name` qualified by the first `depth` modules being defined
+    fn qualified(&self, depth: usize, name: &str) -> String {
+        self.modules[..depth]
+            .iter()
+            .map(|module| module.name.as_str())
+            .chain(std::iter::once(name))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// get names of modules being defined, outermost first
+    pub fn path(&self) -> Vec<String> {
+        self.modules
+            .iter()
+            .map(|module| module.name.clone())
+            .collect()
+    }
+
+    /// start the definition of module `name` inside the current module
+    pub fn enter(&mut self, name: &str) {
+        self.modules.push(Module {
+            name: name.to_string(),
+            names: Vec::new(),
+        });
+    }
+
+    /// end the definition of the current module if it is named `name`,
+    /// its names are hidden and its public names are visible qualified by `name`,
+    /// returns identifiers defined by the module and exported
+    pub fn leave(&mut self, name: &str) -> Option<Vec<Rc<Identifier>>> {
+        if self.modules.last().is_none_or(|module| module.name != name) {
+            return None;
+        }
+        let qualified = self.qualified(self.modules.len() - 1, name);
+        let module = self.modules.pop().expect("module is being defined");
+        for (name, id, _) in module.names.iter().rev() {
+            self.hide(name, id);
+        }
+        let exported = module
+            .names
+            .into_iter()
+            .filter(|(_, _, visibility)| *visibility == Visibility::Public)
+            .map(|(name, id, _)| (name, id))
+            .collect::<Vec<_>>();
+        for (name, id) in &exported {
+            self.push_name(
+                format!("{}.{name}", module.name),
+                id.clone(),
+                Visibility::Public,
+            );
+        }
+        let defined = exported
+            .iter()
+            .filter(|(name, id)| name == id.name())
+            .map(|(_, id)| id.clone())
+            .collect();
+        self.exports.insert(qualified, exported);
+        Some(defined)
+    }
+
+    /// count identifiers from the numbers of identifiers built by name `counter`,
+    /// identifiers built afterward are distinct from those already counted
+    pub fn count_from(&mut self, counter: &HashMap<String, usize>) {
+        for (name, count) in counter {
+            let own = self.counter.entry(name.clone()).or_default();
+            *own = (*own).max(*count);
+        }
+    }
+
+    /// get names visible outside of modules whose identifier is `exported`
+    pub fn exported(&self, exported: impl Fn(&Identifier) -> bool) -> ExportedNames {
+        let names = self
+            .table
+            .iter()
+            .filter_map(|(name, ids)| Some((name.clone(), ids.last()?.clone())))
+            .filter(|(_, id)| exported(id))
+            .collect();
+        let modules = self
+            .exports
+            .iter()
+            .filter(|(_, names)| names.iter().any(|(_, id)| exported(id)))
+            .map(|(module, names)| (module.clone(), names.clone()))
+            .collect();
+        ExportedNames {
+            names,
+            modules,
+            counter: self.counter.clone(),
+        }
+    }
+
+    /// make names exported by a checked file visible
+    pub fn import(&mut self, exported: &ExportedNames) {
+        self.count_from(&exported.counter);
+        for (name, id) in &exported.names {
+            if self.lookup(name).as_ref() != Some(id) {
+                self.push_name(name.clone(), id.clone(), Visibility::Public);
+            }
+        }
+        for (module, names) in &exported.modules {
+            self.exports.insert(module.clone(), names.clone());
+        }
+    }
+
+    /// get number of identifiers built by name
+    pub fn counter(&self) -> &HashMap<String, usize> {
+        &self.counter
+    }
+
+    /// make names exported by module `name` visible unqualified,
+    /// until the end of the current module,
+    /// `name` is relative to the current module or to its parents
+    pub fn open(&mut self, name: &str) -> bool {
+        let Some(exported) = (0..=self.modules.len())
+            .rev()
+            .find_map(|depth| self.exports.get(&self.qualified(depth, name)))
+            .cloned()
+        else {
+            return false;
+        };
+        for (name, id) in exported {
+            self.push_name(name, id, Visibility::Opened);
+        }
+        true
     }
 }
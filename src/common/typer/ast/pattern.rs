@@ -48,18 +48,145 @@ impl LocatedSet for PatternVar {
     }
 }
 
+// ==========================================================================
+// Pattern Tuple
+// ==========================================================================
+pub struct PatternTuple {
+    /// patterns of components
+    patterns: Vec<Pattern>,
+    /// location of the pattern
+    loc: Location,
+}
+
+impl PatternTuple {
+    /// get patterns of components
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+}
+
+impl From<Vec<Pattern>> for PatternTuple {
+    fn from(patterns: Vec<Pattern>) -> Self {
+        Self {
+            patterns,
+            loc: Location::unknown(),
+        }
+    }
+}
+
+impl Pretty for PatternTuple {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::intersperse(
+            self.patterns.iter().map(|pattern| match pattern {
+                Pattern::Tuple(_) => Doc::nil()
+                    .append(theme.operator(&"("))
+                    .append(pattern.pretty(theme))
+                    .append(theme.operator(&")")),
+                _ => pattern.pretty(theme),
+            }),
+            theme.operator(&",").append(Doc::line()),
+        )
+        .group()
+    }
+}
+
+impl Located for PatternTuple {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for PatternTuple {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
+// ==========================================================================
+// Pattern Constructor
+// ==========================================================================
+pub struct PatternConstructor {
+    /// identifier of the constructor
+    id: Rc<Identifier>,
+    /// patterns of constructor arguments
+    args: Vec<Pattern>,
+    /// location of the pattern
+    loc: Location,
+}
+
+impl PatternConstructor {
+    /// make a new constructor pattern
+    pub fn new(id: Rc<Identifier>, args: Vec<Pattern>) -> Self {
+        Self {
+            id,
+            args,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get identifier of the constructor
+    pub fn identifier(&self) -> &Rc<Identifier> {
+        &self.id
+    }
+
+    /// get patterns of constructor arguments
+    pub fn args(&self) -> &[Pattern] {
+        &self.args
+    }
+}
+
+impl Pretty for PatternConstructor {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.args
+            .iter()
+            .fold(theme.expr_var(&self.id), |doc, arg| {
+                let paren = match arg {
+                    Pattern::Tuple(_) => true,
+                    Pattern::Constructor(ctor) => !ctor.args.is_empty(),
+                    Pattern::Variable(_) => false,
+                };
+                let doc_arg = if paren {
+                    Doc::nil()
+                        .append(theme.operator(&"("))
+                        .append(arg.pretty(theme))
+                        .append(theme.operator(&")"))
+                } else {
+                    arg.pretty(theme)
+                };
+                doc.append(Doc::line()).append(doc_arg)
+            })
+            .group()
+    }
+}
+
+impl Located for PatternConstructor {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for PatternConstructor {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
+
 // ==========================================================================
 // Pattern
 // ==========================================================================
 pub enum Pattern {
     Variable(PatternVar),
+    Tuple(PatternTuple),
+    Constructor(PatternConstructor),
 }
 
 impl Pattern {
     /// get names on patterns
-    pub fn names(&self) -> impl Iterator<Item = &Identifier> {
+    pub fn names(&self) -> Box<dyn Iterator<Item = &Identifier> + '_> {
         match self {
-            Pattern::Variable(var) => std::iter::once(var.id.as_ref()),
+            Pattern::Variable(var) => Box::new(std::iter::once(var.id.as_ref())),
+            Pattern::Tuple(tuple) => Box::new(tuple.patterns.iter().flat_map(Pattern::names)),
+            Pattern::Constructor(ctor) => Box::new(ctor.args.iter().flat_map(Pattern::names)),
         }
     }
 }
@@ -68,6 +195,8 @@ impl Pretty for Pattern {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         match self {
             Pattern::Variable(var) => var.pretty(theme),
+            Pattern::Tuple(tuple) => tuple.pretty(theme),
+            Pattern::Constructor(ctor) => ctor.pretty(theme),
         }
     }
 }
@@ -76,6 +205,8 @@ impl Located for Pattern {
     fn loc(&self) -> Location {
         match self {
             Pattern::Variable(var) => var.loc(),
+            Pattern::Tuple(tuple) => tuple.loc(),
+            Pattern::Constructor(ctor) => ctor.loc(),
         }
     }
 }
@@ -0,0 +1,70 @@
+use super::Type;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Arrow Ty
+// ==========================================================================
+#[derive(Debug, Clone)]
+pub struct Arrow {
+    /// type of argument
+    arg: Box<Type>,
+    /// type of result
+    ret: Box<Type>,
+    /// location of arrow
+    loc: Location,
+}
+
+impl Arrow {
+    /// make a new arrow type
+    pub fn new(arg: Type, ret: Type) -> Self {
+        Self {
+            arg: Box::new(arg),
+            ret: Box::new(ret),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get type of argument
+    pub fn arg(&self) -> &Type {
+        &self.arg
+    }
+
+    /// get type of result
+    pub fn ret(&self) -> &Type {
+        &self.ret
+    }
+}
+
+impl PartialEq for Arrow {
+    fn eq(&self, other: &Self) -> bool {
+        self.arg == other.arg && self.ret == other.ret
+    }
+}
+
+impl Eq for Arrow {}
+
+impl Pretty for Arrow {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(self.arg.pretty_precedence(2, theme))
+            .append(Doc::space())
+            .append(theme.operator(&"->"))
+            .append(Doc::softline())
+            .append(self.ret.pretty(theme))
+            .group()
+    }
+}
+
+impl Located for Arrow {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Arrow {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
@@ -9,18 +9,33 @@ use crate::utils::theme::{Doc, Theme};
 pub enum Kind {
     /// kind of natural numbers
     Nat,
+    /// kind of integers
+    Int,
+    /// kind of rational numbers
+    Rat,
     /// kind of booleans
     Bool,
     /// kind of characters
     Char,
+    /// kind of strings
+    String,
+    /// kind of the unit type, with the single value `()`
+    Unit,
+    /// kind of propositions, they are type-checked but never evaluated
+    Prop,
 }
 
 impl Pretty for Kind {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         match self {
             Kind::Nat => theme.ty_var(&"ℕ"),
+            Kind::Int => theme.ty_var(&"ℤ"),
+            Kind::Rat => theme.ty_var(&"ℚ"),
             Kind::Bool => theme.ty_var(&"𝔹"),
             Kind::Char => theme.ty_var(&"Char"),
+            Kind::String => theme.ty_var(&"String"),
+            Kind::Unit => theme.ty_var(&"()"),
+            Kind::Prop => theme.ty_var(&"Prop"),
         }
     }
 }
@@ -36,23 +51,53 @@ pub struct Builtin {
 
 impl Builtin {
     /// Create a new builtin type
-    fn new(kind: Kind) -> Self {
+    pub fn new(kind: Kind) -> Self {
         Builtin {
             kind,
             loc: Location::unknown(), // Builtin types do not have a specific location
         }
     }
 
+    /// get kind of builtin type
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+
     /// make a nat builtin type
     pub fn nat() -> Self {
         Builtin::new(Kind::Nat)
     }
 
+    /// make an int builtin type
+    pub fn int() -> Self {
+        Builtin::new(Kind::Int)
+    }
+
+    /// make a rational builtin type
+    pub fn rat() -> Self {
+        Builtin::new(Kind::Rat)
+    }
+
     /// make a char builtin type
     pub fn char() -> Self {
         Builtin::new(Kind::Char)
     }
 
+    /// make a unit builtin type
+    pub fn unit() -> Self {
+        Builtin::new(Kind::Unit)
+    }
+
+    /// make a string builtin type
+    pub fn string() -> Self {
+        Builtin::new(Kind::String)
+    }
+
+    /// make a proposition builtin type
+    pub fn prop() -> Self {
+        Builtin::new(Kind::Prop)
+    }
+
     /// make a bool builtin type
     pub fn bool() -> Self {
         Builtin::new(Kind::Bool)
@@ -0,0 +1,43 @@
+use super::{Type, TypeRecord};
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Constrained Ty
+// ==========================================================================
+/// type `ty` whose type variables have the classes `classes`, `C α => τ`
+#[derive(Debug, Clone)]
+pub struct Constrained {
+    /// classes of the type variables
+    classes: Vec<TypeRecord>,
+    /// constrained type
+    ty: Type,
+}
+
+impl Constrained {
+    /// make a new constrained type
+    pub fn new(classes: Vec<TypeRecord>, ty: Type) -> Self {
+        Self { classes, ty }
+    }
+}
+
+impl Pretty for Constrained {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.classes
+            .iter()
+            .fold(Doc::nil(), |doc, class| {
+                doc.append(class.pretty(theme))
+                    .append(Doc::space())
+                    .append(theme.operator(&"=>"))
+                    .append(Doc::space())
+            })
+            .append(self.ty.pretty(theme).group())
+    }
+}
+
+impl Located for Constrained {
+    fn loc(&self) -> Location {
+        self.ty.loc()
+    }
+}
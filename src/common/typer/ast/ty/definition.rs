@@ -0,0 +1,206 @@
+use super::super::Identifier;
+use super::Type;
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Constructor of Type Definition
+// ==========================================================================
+#[derive(Debug, Clone)]
+pub struct DefinitionConstructor {
+    /// identifier of constructor
+    id: Rc<Identifier>,
+    /// types of constructor arguments
+    args: Vec<Type>,
+    /// type of constructor
+    ty: Type,
+}
+
+impl DefinitionConstructor {
+    /// make a new constructor
+    pub fn new(id: Rc<Identifier>, args: Vec<Type>, ty: Type) -> Self {
+        Self { id, args, ty }
+    }
+
+    /// get identifier of constructor
+    pub fn id(&self) -> &Rc<Identifier> {
+        &self.id
+    }
+
+    /// get types of constructor arguments
+    pub fn args(&self) -> &[Type] {
+        &self.args
+    }
+
+    /// get type of constructor
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Pretty for DefinitionConstructor {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.operator(&"|"))
+            .append(Doc::space())
+            .append(theme.def_var(&self.id))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::space())
+            .append(self.ty.pretty(theme))
+            .group()
+    }
+}
+
+// ==========================================================================
+// Field of Type Definition
+// ==========================================================================
+#[derive(Debug, Clone)]
+pub struct DefinitionField {
+    /// name of field
+    name: String,
+    /// type of field
+    ty: Type,
+}
+
+impl DefinitionField {
+    /// make a new field
+    pub fn new(name: String, ty: Type) -> Self {
+        Self { name, ty }
+    }
+
+    /// get name of field
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// get type of field
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl Pretty for DefinitionField {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.expr_var(&self.name))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::space())
+            .append(self.ty.pretty(theme))
+            .group()
+    }
+}
+
+/// pretty fields of a record type `{ x : τ1; y : τ2 }`
+pub fn pretty_fields<'a>(fields: &'a [DefinitionField], theme: &Theme) -> Doc<'a> {
+    Doc::nil()
+        .append(theme.operator(&"{"))
+        .append(Doc::space())
+        .append(Doc::intersperse(
+            fields.iter().map(|field| field.pretty(theme)),
+            theme.operator(&";").append(Doc::line()),
+        ))
+        .append(Doc::space())
+        .append(theme.operator(&"}"))
+        .nest(2)
+        .group()
+}
+
+// ==========================================================================
+// Type Definition
+// ==========================================================================
+pub struct Definition {
+    /// name of defined type
+    name: Rc<Identifier>,
+    /// type parameters of defined type
+    params: Vec<Rc<Identifier>>,
+    /// defined type
+    ty: Type,
+    /// constructors of defined type, empty for an alias
+    constructors: Vec<DefinitionConstructor>,
+    /// fields of defined type, empty if it is not a record
+    fields: Vec<DefinitionField>,
+    /// location of definition
+    loc: Location,
+}
+
+impl Definition {
+    /// make a new type definition
+    pub fn new(
+        name: Rc<Identifier>,
+        params: Vec<Rc<Identifier>>,
+        ty: Type,
+        constructors: Vec<DefinitionConstructor>,
+        fields: Vec<DefinitionField>,
+        loc: Location,
+    ) -> Self {
+        Self {
+            name,
+            params,
+            ty,
+            constructors,
+            fields,
+            loc,
+        }
+    }
+
+    /// get name of defined type
+    pub fn name(&self) -> &Rc<Identifier> {
+        &self.name
+    }
+
+    /// get type parameters of defined type
+    pub fn params(&self) -> &[Rc<Identifier>] {
+        &self.params
+    }
+
+    /// get defined type
+    pub fn ty(&self) -> &Type {
+        &self.ty
+    }
+
+    /// get constructors of defined type
+    pub fn constructors(&self) -> &[DefinitionConstructor] {
+        &self.constructors
+    }
+
+    /// get fields of defined type
+    pub fn fields(&self) -> &[DefinitionField] {
+        &self.fields
+    }
+}
+
+impl Pretty for Definition {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let doc_body = match &self.ty {
+            Type::Union(_) => Doc::line()
+                .append(Doc::intersperse(
+                    self.constructors.iter().map(|ctor| ctor.pretty(theme)),
+                    Doc::line(),
+                ))
+                .group(),
+            Type::Record(_) => Doc::softline().append(pretty_fields(&self.fields, theme)),
+            ty => Doc::softline().append(ty.pretty(theme).group()),
+        };
+        Doc::nil()
+            .append(theme.keyword(&"Type"))
+            .append(Doc::space())
+            .append(theme.ty_var(&self.name))
+            .append(self.params.iter().fold(Doc::nil(), |doc, param| {
+                doc.append(Doc::space())
+                    .append(theme.ty_var(param.as_ref()))
+            }))
+            .append(Doc::space())
+            .append(theme.operator(&":="))
+            .append(doc_body.nest(2))
+    }
+}
+
+impl Located for Definition {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
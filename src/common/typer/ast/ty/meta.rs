@@ -0,0 +1,107 @@
+use super::Type;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::{self, Pretty};
+use crate::utils::theme::{Doc, Theme};
+use std::cell::{OnceCell, RefCell};
+use std::rc::Rc;
+
+thread_local! {
+    /// number of the message being printed and its unknown types, in order of naming
+    static NAMED: RefCell<(usize, Vec<Meta>)> = const { RefCell::new((0, Vec::new())) };
+}
+
+/// name `?a`, `?b`, …, `?z`, `?a1`, … of the unknown type printed at position `index`
+/// of a message
+fn name(index: usize) -> String {
+    let letter = char::from(b'a' + (index % 26) as u8);
+    match index / 26 {
+        0 => format!("?{letter}"),
+        round => format!("?{letter}{round}"),
+    }
+}
+
+// ==========================================================================
+// Meta Ty
+// ==========================================================================
+/// unknown type, solved at most once by unification
+/// (all clones share the same solution)
+#[derive(Debug, Clone)]
+pub struct Meta {
+    /// solution of unknown type
+    solution: Rc<OnceCell<Type>>,
+    /// location of unknown type
+    loc: Location,
+}
+
+impl Meta {
+    /// make a new unknown type
+    pub fn new() -> Self {
+        Self {
+            solution: Rc::new(OnceCell::new()),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get solution of unknown type if it is solved
+    pub fn get(&self) -> Option<&Type> {
+        self.solution.get()
+    }
+
+    /// get name of unknown type in the message being printed,
+    /// distinct from the names of the other unknown types of the message
+    pub fn name(&self) -> String {
+        pretty::message(|| {
+            NAMED.with_borrow_mut(|(number, named)| {
+                if *number != pretty::message_number() {
+                    *number = pretty::message_number();
+                    named.clear();
+                }
+                let index = named.iter().position(|meta| meta == self);
+                name(index.unwrap_or_else(|| {
+                    named.push(self.clone());
+                    named.len() - 1
+                }))
+            })
+        })
+    }
+
+    /// solve unknown type with `ty`, fails if `ty` contains it
+    pub fn solve(&self, ty: &Type) -> bool {
+        !ty.occurs(self) && self.solution.set(ty.clone()).is_ok()
+    }
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for Meta {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.solution, &other.solution)
+    }
+}
+
+impl Eq for Meta {}
+
+impl Pretty for Meta {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self.get() {
+            Some(ty) => ty.pretty(theme),
+            None => theme.ty_var(&self.name()),
+        }
+    }
+}
+
+impl Located for Meta {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Meta {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
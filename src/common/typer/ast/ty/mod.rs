@@ -2,22 +2,298 @@ use super::super::error::ErrorUnexpectedType;
 use crate::utils::location::{Located, LocatedSet, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
+use std::collections::HashMap;
+use std::rc::Rc;
 
+mod arrow;
 mod builtin;
+mod constrained;
+mod definition;
+mod meta;
+mod product;
+mod record;
+mod reference;
+mod union;
+mod variable;
 
 pub use super::super::env::Alias as TypeAlias;
-pub use builtin::Builtin as TypeBuiltin;
+pub use arrow::Arrow as TypeArrow;
+pub use builtin::{Builtin as TypeBuiltin, Kind as TypeBuiltinKind};
+pub use constrained::Constrained as TypeConstrained;
+pub use definition::{
+    pretty_fields, Definition as TypeDefinition, DefinitionConstructor, DefinitionField,
+};
+pub use meta::Meta as TypeMeta;
+pub use product::Product as TypeProduct;
+pub use record::Record as TypeRecord;
+pub use reference::Reference as TypeReference;
+pub use union::Union as TypeUnion;
+pub use variable::Variable as TypeVariable;
+
+/// substitution of type variables by types
+pub type Substitution = HashMap<Rc<super::Identifier>, Type>;
 
 #[derive(Debug, Clone)]
 pub enum Type {
     Builtin(TypeBuiltin),
     Alias(TypeAlias),
+    Arrow(TypeArrow),
+    Product(TypeProduct),
+    Record(TypeRecord),
+    Reference(TypeReference),
+    Union(TypeUnion),
+    Variable(TypeVariable),
+    Meta(TypeMeta),
 }
 
 impl Type {
-    /// type is compatible with another type
+    /// type is compatible with another type,
+    /// unknown types of both are solved to make them equal
     pub fn is_compatible(&self, other: &Self) -> bool {
-        *self == *other
+        self.unify(other).is_ok()
+    }
+
+    /// position of number type in ℕ ⊂ ℤ ⊂ ℚ
+    fn number_rank(&self) -> Option<u8> {
+        match self.unfold() {
+            Type::Builtin(builtin) => match builtin.kind() {
+                TypeBuiltinKind::Nat => Some(0),
+                TypeBuiltinKind::Int => Some(1),
+                TypeBuiltinKind::Rat => Some(2),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// type is a number type
+    pub fn is_number(&self) -> bool {
+        self.number_rank().is_some()
+    }
+
+    /// type is the sort of propositions
+    pub fn is_prop(&self) -> bool {
+        matches!(self.unfold(), Type::Builtin(builtin) if *builtin.kind() == TypeBuiltinKind::Prop)
+    }
+
+    /// type is a number type strictly included in number type `other` along ℕ ⊂ ℤ ⊂ ℚ,
+    /// or 𝔹 included in Prop, a value of this type is coerced where a value of the other
+    /// type is expected
+    pub fn is_subtype(&self, other: &Self) -> bool {
+        let numbers = matches!(
+            (self.number_rank(), other.number_rank()),
+            (Some(rank), Some(other_rank)) if rank < other_rank
+        );
+        let propositions = other.is_prop()
+            && matches!(self.unfold(), Type::Builtin(builtin) if *builtin.kind() == TypeBuiltinKind::Bool);
+        numbers || propositions
+    }
+
+    /// least upper bound of two types, the largest one of two number types,
+    /// otherwise the first one, that the other one must be equal to
+    pub fn join(&self, other: &Self) -> Self {
+        if self.is_subtype(other) {
+            other.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// solve unknown types of both types to make them equal,
+    /// on failure return the innermost conflicting types
+    pub fn unify(&self, other: &Self) -> Result<(), Box<(Type, Type)>> {
+        let conflict = || Err(Box::new((self.clone(), other.clone())));
+        match (self.unfold(), other.unfold()) {
+            (Type::Meta(m1), Type::Meta(m2)) if m1 == m2 => Ok(()),
+            (Type::Meta(meta), _) if meta.solve(other) => Ok(()),
+            (_, Type::Meta(meta)) if meta.solve(self) => Ok(()),
+            (Type::Builtin(b1), Type::Builtin(b2)) if b1 == b2 => Ok(()),
+            (Type::Variable(v1), Type::Variable(v2)) if v1 == v2 => Ok(()),
+            (Type::Arrow(a1), Type::Arrow(a2)) => {
+                a1.arg().unify(a2.arg())?;
+                a1.ret().unify(a2.ret())
+            }
+            (Type::Product(p1), Type::Product(p2)) if p1.tys().len() == p2.tys().len() => p1
+                .tys()
+                .iter()
+                .zip(p2.tys())
+                .try_for_each(|(ty1, ty2)| ty1.unify(ty2)),
+            (Type::Reference(r1), Type::Reference(r2)) => r1.content().unify(r2.content()),
+            (Type::Record(r1), Type::Record(r2)) if r1.name() == r2.name() => r1
+                .args()
+                .iter()
+                .zip(r2.args())
+                .try_for_each(|(ty1, ty2)| ty1.unify(ty2)),
+            (Type::Union(u1), Type::Union(u2)) if u1.name() == u2.name() => u1
+                .args()
+                .iter()
+                .zip(u2.args())
+                .try_for_each(|(ty1, ty2)| ty1.unify(ty2)),
+            _ => conflict(),
+        }
+    }
+
+    /// unknown types left in type, in order of occurrence
+    pub fn metas(&self) -> Vec<TypeMeta> {
+        fn collect(ty: &Type, metas: &mut Vec<TypeMeta>) {
+            match ty.unfold() {
+                Type::Meta(meta) if !metas.contains(meta) => metas.push(meta.clone()),
+                Type::Arrow(arrow) => {
+                    collect(arrow.arg(), metas);
+                    collect(arrow.ret(), metas);
+                }
+                Type::Product(product) => product.tys().iter().for_each(|ty| collect(ty, metas)),
+                Type::Reference(reference) => collect(reference.content(), metas),
+                Type::Record(record) => record.args().iter().for_each(|ty| collect(ty, metas)),
+                Type::Union(union) => union.args().iter().for_each(|ty| collect(ty, metas)),
+                _ => (),
+            }
+        }
+        let mut metas = Vec::new();
+        collect(self, &mut metas);
+        metas
+    }
+
+    /// unknown type `meta` occurs in type
+    fn occurs(&self, meta: &TypeMeta) -> bool {
+        match self.unfold() {
+            Type::Meta(other) => other == meta,
+            Type::Arrow(arrow) => arrow.arg().occurs(meta) || arrow.ret().occurs(meta),
+            Type::Product(product) => product.tys().iter().any(|ty| ty.occurs(meta)),
+            Type::Reference(reference) => reference.content().occurs(meta),
+            Type::Record(record) => record.args().iter().any(|ty| ty.occurs(meta)),
+            Type::Union(union) => union.args().iter().any(|ty| ty.occurs(meta)),
+            _ => false,
+        }
+    }
+
+    /// replace type variables of `subst` in type
+    pub fn substitute(&self, subst: &Substitution) -> Type {
+        match self {
+            Type::Variable(var) => match subst.get(var.id()) {
+                Some(ty) => ty.clone().with_loc(var),
+                None => self.clone(),
+            },
+            Type::Alias(alias) => Type::Alias(alias.substitute(subst)),
+            Type::Arrow(arrow) => Type::Arrow(
+                TypeArrow::new(arrow.arg().substitute(subst), arrow.ret().substitute(subst))
+                    .with_loc(arrow),
+            ),
+            Type::Product(product) => Type::Product(
+                TypeProduct::new(
+                    product
+                        .tys()
+                        .iter()
+                        .map(|ty| ty.substitute(subst))
+                        .collect(),
+                )
+                .with_loc(product),
+            ),
+            Type::Reference(reference) => Type::Reference(
+                TypeReference::new(reference.content().substitute(subst)).with_loc(reference),
+            ),
+            Type::Record(record) => Type::Record(
+                TypeRecord::new(
+                    record.name().clone(),
+                    record
+                        .args()
+                        .iter()
+                        .map(|ty| ty.substitute(subst))
+                        .collect(),
+                )
+                .with_loc(record),
+            ),
+            Type::Union(union) => Type::Union(
+                TypeUnion::new(
+                    union.name().clone(),
+                    union.args().iter().map(|ty| ty.substitute(subst)).collect(),
+                )
+                .with_loc(union),
+            ),
+            Type::Meta(meta) => match meta.get() {
+                Some(ty) => ty.substitute(subst),
+                None => self.clone(),
+            },
+            Type::Builtin(_) => self.clone(),
+        }
+    }
+
+    /// unfold aliases and solved unknown types until a concrete type
+    pub fn unfold(&self) -> &Type {
+        match self {
+            Type::Alias(alias) => alias.ty().unfold(),
+            Type::Meta(meta) => match meta.get() {
+                Some(ty) => ty.unfold(),
+                None => self,
+            },
+            ty => ty,
+        }
+    }
+
+    /// get arrow type if type is a function type
+    pub fn as_arrow(&self) -> Option<&TypeArrow> {
+        match self.unfold() {
+            Type::Arrow(arrow) => Some(arrow),
+            _ => None,
+        }
+    }
+
+    /// get product type if type is a product type
+    pub fn as_product(&self) -> Option<&TypeProduct> {
+        match self.unfold() {
+            Type::Product(product) => Some(product),
+            _ => None,
+        }
+    }
+
+    /// get reference type if type is a reference type
+    pub fn as_reference(&self) -> Option<&TypeReference> {
+        match self.unfold() {
+            Type::Reference(reference) => Some(reference),
+            _ => None,
+        }
+    }
+
+    /// get record type if type is a record type
+    pub fn as_record(&self) -> Option<&TypeRecord> {
+        match self.unfold() {
+            Type::Record(record) => Some(record),
+            _ => None,
+        }
+    }
+
+    /// get union type if type is a union type
+    pub fn as_union(&self) -> Option<&TypeUnion> {
+        match self.unfold() {
+            Type::Union(union) => Some(union),
+            _ => None,
+        }
+    }
+
+    /// precedence level of type
+    fn precedence(&self) -> u8 {
+        match self {
+            Type::Builtin(_) | Type::Variable(_) => 0,
+            Type::Alias(alias) if alias.args().is_empty() => 0,
+            Type::Union(union) if union.args().is_empty() => 0,
+            Type::Record(record) if record.args().is_empty() => 0,
+            Type::Alias(_) | Type::Union(_) | Type::Record(_) | Type::Reference(_) => 1,
+            Type::Meta(meta) => meta.get().map_or(0, Type::precedence),
+            Type::Product(_) => 2,
+            Type::Arrow(_) => 3,
+        }
+    }
+
+    /// pretty print type, with parenthesis if precedence is greater than `prec`
+    pub fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        if self.precedence() > prec {
+            Doc::nil()
+                .append(theme.operator(&"("))
+                .append(self.pretty(theme))
+                .append(theme.operator(&")"))
+        } else {
+            self.pretty(theme)
+        }
     }
 }
 
@@ -26,6 +302,13 @@ impl Pretty for Type {
         match self {
             Type::Builtin(builtin) => builtin.pretty(theme),
             Type::Alias(alias) => alias.pretty(theme),
+            Type::Arrow(arrow) => arrow.pretty(theme),
+            Type::Product(product) => product.pretty(theme),
+            Type::Reference(reference) => reference.pretty(theme),
+            Type::Record(record) => record.pretty(theme),
+            Type::Union(union) => union.pretty(theme),
+            Type::Variable(var) => var.pretty(theme),
+            Type::Meta(meta) => meta.pretty(theme),
         }
     }
 }
@@ -35,6 +318,13 @@ impl Located for Type {
         match self {
             Type::Builtin(builtin) => builtin.loc(),
             Type::Alias(alias) => alias.loc(),
+            Type::Arrow(arrow) => arrow.loc(),
+            Type::Product(product) => product.loc(),
+            Type::Reference(reference) => reference.loc(),
+            Type::Record(record) => record.loc(),
+            Type::Union(union) => union.loc(),
+            Type::Variable(var) => var.loc(),
+            Type::Meta(meta) => meta.loc(),
         }
     }
 }
@@ -44,15 +334,29 @@ impl LocatedSet for Type {
         match self {
             Type::Builtin(builtin) => builtin.set_loc(loc),
             Type::Alias(alias) => alias.set_loc(loc),
+            Type::Arrow(arrow) => arrow.set_loc(loc),
+            Type::Product(product) => product.set_loc(loc),
+            Type::Reference(reference) => reference.set_loc(loc),
+            Type::Record(record) => record.set_loc(loc),
+            Type::Union(union) => union.set_loc(loc),
+            Type::Variable(var) => var.set_loc(loc),
+            Type::Meta(meta) => meta.set_loc(loc),
         }
     }
 }
 
 impl PartialEq for Type {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
+        match (self.unfold(), other.unfold()) {
+            (Type::Meta(m1), Type::Meta(m2)) => m1 == m2,
             (Type::Builtin(b1), Type::Builtin(b2)) => b1 == b2,
-            (Type::Alias(a), ty) | (ty, Type::Alias(a)) => a.ty() == ty,
+            (Type::Variable(v1), Type::Variable(v2)) => v1 == v2,
+            (Type::Arrow(a1), Type::Arrow(a2)) => a1 == a2,
+            (Type::Product(p1), Type::Product(p2)) => p1 == p2,
+            (Type::Reference(r1), Type::Reference(r2)) => r1 == r2,
+            (Type::Record(r1), Type::Record(r2)) => r1 == r2,
+            (Type::Union(u1), Type::Union(u2)) => u1 == u2,
+            _ => false,
         }
     }
 }
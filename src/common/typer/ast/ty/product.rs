@@ -0,0 +1,62 @@
+use super::Type;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Product Ty
+// ==========================================================================
+#[derive(Debug, Clone)]
+pub struct Product {
+    /// types of components
+    tys: Vec<Type>,
+    /// location of product
+    loc: Location,
+}
+
+impl Product {
+    /// make a new product type
+    pub fn new(tys: Vec<Type>) -> Self {
+        Self {
+            tys,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get types of components
+    pub fn tys(&self) -> &[Type] {
+        &self.tys
+    }
+}
+
+impl PartialEq for Product {
+    fn eq(&self, other: &Self) -> bool {
+        self.tys == other.tys
+    }
+}
+
+impl Eq for Product {}
+
+impl Pretty for Product {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::intersperse(
+            self.tys.iter().map(|ty| ty.pretty_precedence(1, theme)),
+            Doc::space()
+                .append(theme.operator(&"*"))
+                .append(Doc::softline()),
+        )
+        .group()
+    }
+}
+
+impl Located for Product {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Product {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
@@ -0,0 +1,74 @@
+use super::super::Identifier;
+use super::Type;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Record Ty
+// ==========================================================================
+/// record type, its fields are registered in the environment
+#[derive(Debug, Clone)]
+pub struct Record {
+    /// name of record type
+    name: Rc<Identifier>,
+    /// type arguments of record type
+    args: Vec<Type>,
+    /// location of record type
+    loc: Location,
+}
+
+impl Record {
+    /// make a new record type
+    pub fn new(name: Rc<Identifier>, args: Vec<Type>) -> Self {
+        Self {
+            name,
+            args,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get name of record type
+    pub fn name(&self) -> &Rc<Identifier> {
+        &self.name
+    }
+
+    /// get type arguments of record type
+    pub fn args(&self) -> &[Type] {
+        &self.args
+    }
+}
+
+impl PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.args == other.args
+    }
+}
+
+impl Eq for Record {}
+
+impl Pretty for Record {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.args
+            .iter()
+            .fold(theme.ty_var(&self.name.qualified_name()), |doc, arg| {
+                doc.append(Doc::softline())
+                    .append(arg.pretty_precedence(0, theme))
+            })
+            .group()
+            .nest(2)
+    }
+}
+
+impl Located for Record {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Record {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
@@ -0,0 +1,62 @@
+use super::Type;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Reference Ty
+// ==========================================================================
+/// type of mutable cells holding a value of type `ty`
+#[derive(Debug, Clone)]
+pub struct Reference {
+    /// type of content
+    ty: Box<Type>,
+    /// location of reference type
+    loc: Location,
+}
+
+impl Reference {
+    /// make a new reference type
+    pub fn new(ty: Type) -> Self {
+        Self {
+            ty: Box::new(ty),
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get type of content
+    pub fn content(&self) -> &Type {
+        &self.ty
+    }
+}
+
+impl PartialEq for Reference {
+    fn eq(&self, other: &Self) -> bool {
+        self.ty == other.ty
+    }
+}
+
+impl Eq for Reference {}
+
+impl Pretty for Reference {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.keyword(&"ref"))
+            .append(Doc::softline())
+            .append(self.ty.pretty_precedence(0, theme))
+            .group()
+            .nest(2)
+    }
+}
+
+impl Located for Reference {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Reference {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
@@ -0,0 +1,74 @@
+use super::super::Identifier;
+use super::Type;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Union Ty
+// ==========================================================================
+/// union type, its constructors are registered in the environment
+#[derive(Debug, Clone)]
+pub struct Union {
+    /// name of union type
+    name: Rc<Identifier>,
+    /// type arguments of union type
+    args: Vec<Type>,
+    /// location of union type
+    loc: Location,
+}
+
+impl Union {
+    /// make a new union type
+    pub fn new(name: Rc<Identifier>, args: Vec<Type>) -> Self {
+        Self {
+            name,
+            args,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get name of union type
+    pub fn name(&self) -> &Rc<Identifier> {
+        &self.name
+    }
+
+    /// get type arguments of union type
+    pub fn args(&self) -> &[Type] {
+        &self.args
+    }
+}
+
+impl PartialEq for Union {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.args == other.args
+    }
+}
+
+impl Eq for Union {}
+
+impl Pretty for Union {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        self.args
+            .iter()
+            .fold(theme.ty_var(&self.name.qualified_name()), |doc, arg| {
+                doc.append(Doc::softline())
+                    .append(arg.pretty_precedence(0, theme))
+            })
+            .group()
+            .nest(2)
+    }
+}
+
+impl Located for Union {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Union {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
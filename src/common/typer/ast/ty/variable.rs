@@ -0,0 +1,58 @@
+use super::super::Identifier;
+use crate::utils::location::{Located, LocatedSet, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Variable Ty
+// ==========================================================================
+/// type variable bound by a definition, only equal to itself
+#[derive(Debug, Clone)]
+pub struct Variable {
+    /// identifier of type variable
+    id: Rc<Identifier>,
+    /// location of type variable
+    loc: Location,
+}
+
+impl Variable {
+    /// make a new type variable
+    pub fn new(id: Rc<Identifier>) -> Self {
+        Self {
+            id,
+            loc: Location::unknown(),
+        }
+    }
+
+    /// get identifier of type variable
+    pub fn id(&self) -> &Rc<Identifier> {
+        &self.id
+    }
+}
+
+impl PartialEq for Variable {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Variable {}
+
+impl Pretty for Variable {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.ty_var(&self.id.name())
+    }
+}
+
+impl Located for Variable {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl LocatedSet for Variable {
+    fn set_loc(&mut self, loc: &impl Located) {
+        self.loc = loc.loc().clone();
+    }
+}
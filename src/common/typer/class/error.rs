@@ -0,0 +1,164 @@
+use crate::typer::ast::Type;
+use crate::utils::error::{ErrorCode, ErrorReport, Message};
+use crate::utils::location::{Located, Location};
+
+// =======================================================================
+// Error Not Class
+// =======================================================================
+pub struct ErrorNotClass {
+    ty: Type,
+    loc: Location,
+}
+
+impl ErrorNotClass {
+    pub fn new(ty: &Type, location: &Location) -> Self {
+        Self {
+            ty: ty.clone(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorNotClass {
+    fn code(&self) -> i32 {
+        320
+    }
+}
+
+impl Located for ErrorNotClass {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorNotClass {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Not a class.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Type ")
+            .append(Message::of_pretty(&self.ty).important())
+            .with_text(" is not a class applied to a type.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Missing Instance
+// =======================================================================
+pub struct ErrorMissingInstance {
+    /// class applied to the type of the missing instance
+    ty: Type,
+    /// type of the missing instance is unknown
+    unknown: bool,
+    /// types of the instances of the class
+    instances: Vec<Type>,
+    loc: Location,
+}
+
+impl ErrorMissingInstance {
+    pub fn new(ty: &Type, unknown: bool, instances: Vec<Type>, location: &Location) -> Self {
+        Self {
+            ty: ty.clone(),
+            unknown,
+            instances,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorMissingInstance {
+    fn code(&self) -> i32 {
+        321
+    }
+}
+
+impl Located for ErrorMissingInstance {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorMissingInstance {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Missing instance.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = if self.unknown {
+            Message::text("Instance ")
+                .append(Message::of_pretty(&self.ty).important())
+                .with_text(" cannot be chosen, its type is unknown.")
+        } else {
+            Message::text("No instance ")
+                .append(Message::of_pretty(&self.ty).important())
+                .with_text(" is defined.")
+        };
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let msg = if self.instances.is_empty() {
+            Message::text("The class has no instance.")
+        } else {
+            Message::text("Instances are ").append(
+                Message::intersperse(
+                    self.instances
+                        .iter()
+                        .map(|ty| Message::of_pretty(ty).important()),
+                    Message::text(", "),
+                )
+                .with_text("."),
+            )
+        };
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Overlapping Instance
+// =======================================================================
+pub struct ErrorOverlappingInstance {
+    /// class applied to the type of the instance
+    ty: Type,
+    loc: Location,
+}
+
+impl ErrorOverlappingInstance {
+    pub fn new(ty: &Type, location: &Location) -> Self {
+        Self {
+            ty: ty.clone(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorOverlappingInstance {
+    fn code(&self) -> i32 {
+        324
+    }
+}
+
+impl Located for ErrorOverlappingInstance {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorOverlappingInstance {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Overlapping instance.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Instance ")
+            .append(Message::of_pretty(&self.ty).important())
+            .with_text(" is already defined.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        Some(Message::text("A class has one instance for each type."))
+    }
+}
@@ -0,0 +1,276 @@
+mod error;
+
+pub use error::{ErrorMissingInstance, ErrorNotClass, ErrorOverlappingInstance};
+
+use super::ast::{self, Typed as _};
+use super::type_ast::{to_errs, Result, Typer};
+use crate::parser::cst::{self, AsIdentifier as _};
+use crate::utils::error::ResultExt as _;
+use crate::utils::location::{Located as _, LocatedSet as _};
+use std::rc::Rc;
+
+impl Typer {
+    /// resolve instances used since the last resolution, once `res` is typed:
+    /// an instance of a class of `dicts` is its dictionary parameter,
+    /// other instances are the instance defined for their type
+    pub(super) fn resolve<T>(
+        &mut self,
+        res: Result<T>,
+        dicts: &[(ast::TypeRecord, Rc<ast::Identifier>)],
+    ) -> Result<T> {
+        let instances = std::mem::take(&mut self.instances);
+        let value = res?;
+        let errs = instances
+            .into_iter()
+            .filter_map(|instance| {
+                let class = instance.class();
+                let ty = &class.args()[0];
+                let defined = self.env.instances_of(class.name());
+                let dict = dicts
+                    .iter()
+                    .find(|(other, _)| other == class)
+                    .map(|(_, dict)| dict)
+                    .or_else(|| {
+                        let mut defined = defined.iter();
+                        defined.find(|(other, _)| other == ty).map(|(_, dict)| dict)
+                    });
+                match dict {
+                    Some(dict) => {
+                        instance.resolve(dict.as_ref().clone());
+                        None
+                    }
+                    None => {
+                        let tys = defined
+                            .iter()
+                            .map(|(ty, _)| {
+                                ast::Type::Record(ast::TypeRecord::new(
+                                    class.name().clone(),
+                                    vec![ty.clone()],
+                                ))
+                            })
+                            .collect();
+                        let unknown = !ty.metas().is_empty();
+                        let err =
+                            ErrorMissingInstance::new(instance.ty(), unknown, tys, &instance.loc());
+                        Some(err.into())
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if errs.is_empty() {
+            Ok(value)
+        } else {
+            Err(errs)
+        }
+    }
+
+    /// classes of type variables `binders` used by the instances to resolve, each one once
+    pub(super) fn classes(&self, binders: &[Rc<ast::Identifier>]) -> Vec<ast::TypeRecord> {
+        let mut classes: Vec<ast::TypeRecord> = Vec::new();
+        for instance in &self.instances {
+            let class = instance.class();
+            let bound = matches!(
+                class.args()[0].unfold(),
+                ast::Type::Variable(var) if binders.contains(var.id())
+            );
+            if bound && !classes.contains(class) {
+                classes.push(class.clone());
+            }
+        }
+        classes
+    }
+
+    /// dictionary parameters of a definition, one for each class of its type variables
+    /// `binders` used by the definition
+    pub(super) fn dictionaries(
+        &mut self,
+        binders: &[Rc<ast::Identifier>],
+    ) -> Vec<(ast::TypeRecord, Rc<ast::Identifier>)> {
+        self.classes(binders)
+            .into_iter()
+            .map(|class| {
+                let dict = self
+                    .id_builder
+                    .fresh(&format!("dict_{}", class.name().name()));
+                (class, dict)
+            })
+            .collect()
+    }
+
+    /// abstract mutually recursive definitions `defs` over dictionary parameters `dicts`,
+    /// each name is constrained by their classes: in each body, the names used by
+    /// recursive calls are bound to the definitions applied to the parameters
+    pub(super) fn fixpoint_dictionaries(
+        &mut self,
+        defs: Vec<ast::ExpressionDefinition>,
+        definitions: &[(&cst::ExpressionDefinition, Option<ast::Documentation>)],
+        dicts: Vec<(ast::TypeRecord, Rc<ast::Identifier>)>,
+    ) -> Vec<ast::ExpressionDefinition> {
+        if dicts.is_empty() {
+            return defs;
+        }
+        let classes = dicts
+            .iter()
+            .map(|(class, _)| class.clone())
+            .collect::<Vec<_>>();
+        let recursive = defs
+            .iter()
+            .flat_map(|def| {
+                def.pattern()
+                    .names()
+                    .map(|id| (id.clone(), def.ty().clone()))
+            })
+            .collect::<Vec<_>>();
+        for (id, _) in &recursive {
+            self.env.set_constraints(id, classes.clone());
+        }
+        let mut abstracted = Vec::new();
+        for (def, (definition, _)) in defs.into_iter().zip(definitions) {
+            let values = recursive
+                .iter()
+                .map(|(id, ty)| self.recursive_reference(id, ty, &dicts, definition))
+                .collect::<Vec<_>>();
+            let def = def.map_body(|body| {
+                let body =
+                    recursive
+                        .iter()
+                        .zip(values)
+                        .rev()
+                        .fold(body, |body, ((id, _), value)| {
+                            let pattern =
+                                ast::Pattern::Variable(ast::PatternVar::from(Rc::new(id.clone())));
+                            let binding =
+                                ast::ExpressionLet::new(pattern, value, body).with_loc(*definition);
+                            ast::Expression::Let(binding)
+                        });
+                dicts.iter().rev().fold(body, |body, (class, dict)| {
+                    let param = ast::Pattern::Variable(ast::PatternVar::from(dict.clone()));
+                    let ty = ast::Type::Record(class.clone());
+                    let abs =
+                        ast::ExpressionAbstraction::new(param, ty, body).with_loc(*definition);
+                    ast::Expression::Abstraction(abs)
+                })
+            });
+            abstracted.push(def);
+        }
+        abstracted
+    }
+
+    /// recursive reference to definition `id` of type `ty` applied to dictionary
+    /// parameters `dicts`, a function is eta-expanded so that it is not applied
+    /// before it is called
+    fn recursive_reference(
+        &mut self,
+        id: &ast::Identifier,
+        ty: &ast::Type,
+        dicts: &[(ast::TypeRecord, Rc<ast::Identifier>)],
+        definition: &cst::ExpressionDefinition,
+    ) -> ast::Expression {
+        let loc = definition.loc();
+        let ty_dicts = dicts.iter().rev().fold(ty.clone(), |ty, (class, _)| {
+            ast::Type::Arrow(ast::TypeArrow::new(ast::Type::Record(class.clone()), ty))
+        });
+        let var = ast::ExpressionVariable::new(id.clone(), ty_dicts, loc.clone());
+        let applied = dicts
+            .iter()
+            .fold(ast::Expression::Variable(var), |fun, (class, dict)| {
+                let ty = fun.ty().as_arrow().expect("dictionary is an argument");
+                let ty = ty.ret().clone();
+                let instance = ast::ExpressionInstance::new(class.clone(), loc.clone());
+                instance.resolve(dict.as_ref().clone());
+                let arg = ast::Expression::Instance(instance);
+                let app = ast::ExpressionApplication::new(fun, arg, ty).with_loc(definition);
+                ast::Expression::Application(app)
+            });
+        let Some(arrow) = ty.as_arrow() else {
+            return applied;
+        };
+        let param = self.id_builder.fresh("x");
+        let arg =
+            ast::ExpressionVariable::new(param.as_ref().clone(), arrow.arg().clone(), loc.clone());
+        let app = ast::ExpressionApplication::new(
+            applied,
+            ast::Expression::Variable(arg),
+            arrow.ret().clone(),
+        )
+        .with_loc(definition);
+        let pattern = ast::Pattern::Variable(ast::PatternVar::from(param));
+        let abs = ast::ExpressionAbstraction::new(
+            pattern,
+            arrow.arg().clone(),
+            ast::Expression::Application(app),
+        )
+        .with_loc(definition);
+        ast::Expression::Abstraction(abs)
+    }
+
+    /// add class, a record type of methods with one type parameter,
+    /// each method is a variable constrained by the class
+    pub fn class(
+        &mut self,
+        definition: &cst::TypeDefinition,
+        doc: Option<ast::Documentation>,
+    ) -> Result<ast::TypeDefinition> {
+        let def = self.type_definition(definition, doc)?;
+        let id = def.name().clone();
+        self.env.add_class(id.clone());
+        let args = def
+            .params()
+            .iter()
+            .map(|param| ast::Type::Variable(ast::TypeVariable::new(param.clone())))
+            .collect();
+        let class = ast::TypeRecord::new(id.clone(), args);
+        if let cst::ty_definition::TypeDefinitionBody::Record(record) = &definition.body {
+            for (field, method_field) in record.fields.iter().zip(def.fields()) {
+                let method = self.id_builder.build(field.name.name());
+                self.env
+                    .add_expr_def(method.clone(), method_field.ty().clone(), field.name.loc());
+                self.env.set_binders(&method, def.params().to_vec());
+                self.env.set_constraints(&method, vec![class.clone()]);
+                self.env.add_method(method, id.clone());
+            }
+        }
+        Ok(def)
+    }
+
+    /// add instance of a class for a type, its dictionary is the value of a variable
+    /// that is not visible by name
+    pub fn instance(&mut self, instance: &cst::Instance) -> Result<ast::ExpressionDefinition> {
+        let ty = self.ty(&instance.ty)?;
+        let class = match ty.as_record() {
+            Some(class) if self.env.is_class(class.name()) => class.clone(),
+            _ => {
+                let err = ErrorNotClass::new(&ty, &instance.ty.loc());
+                return Err(to_errs(err.into()));
+            }
+        };
+        let overlapping = self
+            .env
+            .instances_of(class.name())
+            .iter()
+            .any(|(other, _)| other == &class.args()[0]);
+        let overlapping_res = if overlapping {
+            let err = ErrorOverlappingInstance::new(&ty, &instance.ty.loc());
+            Err(to_errs(err.into()))
+        } else {
+            Ok(())
+        };
+        // the body is a record of the class, whatever other record type has its fields
+        let body_res = self
+            .expression_expecting(&instance.body, &ty)
+            .and_then(|body| Self::operand(body, &ty));
+        let body = self
+            .resolve(body_res, &[])
+            .combine(overlapping_res)
+            .map(|(body, _)| body)?;
+        let dict = self
+            .id_builder
+            .fresh(&format!("instance_{}", class.name().name()));
+        self.env
+            .add_expr_def(dict.clone(), ty.clone(), instance.ty.loc());
+        self.env
+            .add_instance(class.name(), class.args()[0].clone(), dict.clone());
+        let pattern = ast::Pattern::Variable(ast::PatternVar::from(dict).with_loc(&instance.ty));
+        Ok(ast::ExpressionDefinition::new(pattern, Vec::new(), body))
+    }
+}
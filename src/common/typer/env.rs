@@ -1,5 +1,9 @@
-use super::ast::{Documentation, Identifier, Type, Typed};
-use crate::typer::error::ErrorVariableNotFound;
+use super::ast::{
+    pretty_fields, Contract, DefinitionField, Documentation, Identifier, Substitution, Type,
+    TypeBuiltin, TypeMeta, TypeRecord, TypeUnion, TypeVariable, Typed,
+};
+use super::kernel::{Formula, Theorem};
+use crate::typer::error::{Error, ErrorTypeArity, ErrorVariableNotFound};
 use crate::utils::location::{Located, LocatedSet, Location};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
@@ -20,6 +24,16 @@ pub struct Variable {
 }
 
 impl Variable {
+    /// make a reference to variable `identifier` of type `ty`, made by the typer
+    /// rather than read from the environment
+    pub fn new(identifier: Identifier, ty: Type, loc: Location) -> Self {
+        Self {
+            identifier,
+            ty,
+            loc,
+        }
+    }
+
     /// get the identifier of the variable
     pub fn identifier(&self) -> &Identifier {
         &self.identifier
@@ -68,12 +82,36 @@ impl Pretty for Variable {
 pub struct Alias {
     /// name of alias
     name: Identifier,
-    /// type of alias
+    /// type arguments of alias
+    args: Vec<Type>,
+    /// type of alias, with its arguments substituted
     ty: Box<Type>,
     /// location of alias
     loc: Location,
 }
 
+impl Alias {
+    /// get name of alias
+    pub fn name(&self) -> &Identifier {
+        &self.name
+    }
+
+    /// get type arguments of alias
+    pub fn args(&self) -> &[Type] {
+        &self.args
+    }
+
+    /// replace type variables of `subst` in alias
+    pub fn substitute(&self, subst: &Substitution) -> Self {
+        Self {
+            name: self.name.clone(),
+            args: self.args.iter().map(|ty| ty.substitute(subst)).collect(),
+            ty: Box::new(self.ty.substitute(subst)),
+            loc: self.loc.clone(),
+        }
+    }
+}
+
 impl Typed for Alias {
     fn ty(&self) -> &Type {
         &self.ty
@@ -82,7 +120,14 @@ impl Typed for Alias {
 
 impl Pretty for Alias {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
-        theme.ty_var(&self.name.name())
+        self.args
+            .iter()
+            .fold(theme.ty_var(&self.name.qualified_name()), |doc, arg| {
+                doc.append(Doc::softline())
+                    .append(arg.pretty_precedence(0, theme))
+            })
+            .group()
+            .nest(2)
     }
 }
 
@@ -98,6 +143,128 @@ impl LocatedSet for Alias {
     }
 }
 
+// ==========================================================================
+// Constructor
+// ==========================================================================
+/// constructor of an union type
+#[derive(Debug, Clone)]
+pub struct Constructor {
+    /// identifier of constructor
+    id: Rc<Identifier>,
+    /// union type of constructor
+    union: Rc<Identifier>,
+    /// type parameters of union type
+    params: Vec<Rc<Identifier>>,
+    /// types of constructor arguments
+    args: Vec<Type>,
+    /// type of constructor
+    ty: Type,
+}
+
+impl Constructor {
+    /// get identifier of constructor
+    pub fn id(&self) -> &Rc<Identifier> {
+        &self.id
+    }
+
+    /// get union type of constructor
+    pub fn union(&self) -> &Rc<Identifier> {
+        &self.union
+    }
+
+    /// get types of constructor arguments
+    pub fn args(&self) -> &[Type] {
+        &self.args
+    }
+
+    /// get types of constructor arguments for an instance `union` of its union type
+    pub fn args_of(&self, union: &TypeUnion) -> Vec<Type> {
+        let subst = self
+            .params
+            .iter()
+            .cloned()
+            .zip(union.args().iter().cloned())
+            .collect::<Substitution>();
+        self.args.iter().map(|ty| ty.substitute(&subst)).collect()
+    }
+
+    /// get union type of constructor with fresh unknown type arguments
+    pub fn instantiate_union(&self) -> Type {
+        let args = self
+            .params
+            .iter()
+            .map(|_| Type::Meta(TypeMeta::new()))
+            .collect();
+        Type::Union(TypeUnion::new(self.union.clone(), args))
+    }
+}
+
+impl Typed for Constructor {
+    fn ty(&self) -> &Type {
+        &self.ty
+    }
+}
+
+// ==========================================================================
+// Record
+// ==========================================================================
+/// record type with its fields
+#[derive(Debug, Clone)]
+pub struct Record {
+    /// identifier of record type
+    id: Rc<Identifier>,
+    /// type parameters of record type
+    params: Vec<Rc<Identifier>>,
+    /// fields of record type, in order of definition
+    fields: Vec<DefinitionField>,
+}
+
+impl Record {
+    /// get identifier of record type
+    pub fn id(&self) -> &Rc<Identifier> {
+        &self.id
+    }
+
+    /// get fields of record type, in order of definition
+    pub fn fields(&self) -> &[DefinitionField] {
+        &self.fields
+    }
+
+    /// get names of fields of record type
+    pub fn names(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .map(|field| field.name().to_string())
+            .collect()
+    }
+
+    /// get position of field `name` in the record type
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name() == name)
+    }
+
+    /// get type of field at `index` for an instance `record` of the record type
+    pub fn field_ty(&self, index: usize, record: &TypeRecord) -> Type {
+        let subst = self
+            .params
+            .iter()
+            .cloned()
+            .zip(record.args().iter().cloned())
+            .collect::<Substitution>();
+        self.fields[index].ty().substitute(&subst)
+    }
+
+    /// get record type with fresh unknown type arguments
+    pub fn instantiate(&self) -> TypeRecord {
+        let args = self
+            .params
+            .iter()
+            .map(|_| Type::Meta(TypeMeta::new()))
+            .collect();
+        TypeRecord::new(self.id.clone(), args)
+    }
+}
+
 // ==========================================================================
 // Identifier Kind
 // ==========================================================================
@@ -107,6 +274,8 @@ pub enum IdentifierKind {
     Type,
     /// identifier is a expression variable
     Expr,
+    /// identifier is a theorem
+    Theorem,
 }
 
 // ==========================================================================
@@ -114,7 +283,7 @@ pub enum IdentifierKind {
 // ==========================================================================
 /// information about identifier
 /// with store documentation, location of definition and location of references
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IdentifierInfo {
     /// identifier
     pub id: Rc<Identifier>,
@@ -124,10 +293,32 @@ pub struct IdentifierInfo {
     pub kind: IdentifierKind,
     /// type of indentifier
     pub ty: Type,
+    /// type variables bound by definition
+    pub binders: Vec<Rc<Identifier>>,
+    /// classes of the bound type variables, a dictionary of each one is given at every use
+    pub constraints: Vec<TypeRecord>,
     /// definition location
     pub loc_def: Location,
     /// location of references
     pub loc_refs: Vec<Location>,
+    /// modules exporting the definition, outermost first
+    pub path: Vec<String>,
+}
+
+impl IdentifierInfo {
+    /// get name of identifier qualified by its modules `M.x`
+    pub fn qualified_name(&self) -> String {
+        qualified_name(&self.path, self.id.name())
+    }
+}
+
+/// get `name` qualified by modules of `path`
+fn qualified_name(path: &[String], name: &str) -> String {
+    path.iter()
+        .map(String::as_str)
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join(".")
 }
 
 // ==========================================================================
@@ -137,10 +328,22 @@ pub struct IdentifierInfo {
 #[derive(Debug)]
 pub struct Help {
     id: Rc<Identifier>,
+    /// modules exporting the definition
+    path: Vec<String>,
     ty: Type,
+    binders: Vec<Rc<Identifier>>,
+    constraints: Vec<TypeRecord>,
     loc: Location,
     kind: IdentifierKind,
     doc: Option<Documentation>,
+    constructors: Vec<Constructor>,
+    fields: Vec<DefinitionField>,
+    theorem: Option<Rc<Theorem>>,
+    contract: Option<Rc<Contract>>,
+    /// references of the environment used by the definition, with their types
+    effects: Vec<(Identifier, Type)>,
+    /// instances of a class, the class applied to the type of each instance
+    instances: Vec<Type>,
 }
 
 impl Pretty for Help {
@@ -149,24 +352,114 @@ impl Pretty for Help {
             IdentifierKind::Expr => Doc::nil()
                 .append(theme.operator(&":"))
                 .append(Doc::softline())
+                .append(self.constraints.iter().fold(Doc::nil(), |doc, class| {
+                    doc.append(class.pretty(theme))
+                        .append(Doc::space())
+                        .append(theme.operator(&"=>"))
+                        .append(Doc::space())
+                }))
                 .append(self.ty.pretty(theme).group()),
             IdentifierKind::Type => match &self.ty {
                 Type::Builtin(_) => theme.comment(&"(builtin)"),
+                Type::Union(_) => Doc::nil().append(theme.operator(&":=")).append(
+                    Doc::line()
+                        .append(Doc::intersperse(
+                            self.constructors.iter().map(|ctor| {
+                                Doc::nil()
+                                    .append(theme.operator(&"|"))
+                                    .append(Doc::space())
+                                    .append(theme.expr_var(&ctor.id.name()))
+                                    .append(Doc::space())
+                                    .append(theme.operator(&":"))
+                                    .append(Doc::space())
+                                    .append(ctor.ty.pretty(theme))
+                                    .group()
+                            }),
+                            Doc::line(),
+                        ))
+                        .nest(2)
+                        .group(),
+                ),
+                Type::Record(_) => Doc::nil()
+                    .append(theme.operator(&":="))
+                    .append(Doc::softline())
+                    .append(pretty_fields(&self.fields, theme)),
                 ty => Doc::nil()
                     .append(theme.operator(&":="))
                     .append(Doc::softline())
                     .append(ty.pretty(theme).group()),
             },
+            IdentifierKind::Theorem => Doc::nil()
+                .append(theme.operator(&":"))
+                .append(Doc::softline())
+                .append(match &self.theorem {
+                    Some(theorem) => theorem.formula().pretty(theme).group(),
+                    None => self.ty.pretty(theme),
+                }),
+        };
+
+        let doc_binders = match &self.kind {
+            _ if self.binders.is_empty() => Doc::nil(),
+            IdentifierKind::Expr => Doc::nil()
+                .append(theme.operator(&"<"))
+                .append(Doc::intersperse(
+                    self.binders.iter().map(|id| theme.ty_var(&id.name())),
+                    Doc::space(),
+                ))
+                .append(theme.operator(&">"))
+                .append(Doc::space()),
+            IdentifierKind::Type | IdentifierKind::Theorem => {
+                self.binders.iter().fold(Doc::nil(), |doc, id| {
+                    doc.append(theme.ty_var(&id.name())).append(Doc::space())
+                })
+            }
+        };
+
+        let doc_effects = match &self.kind {
+            IdentifierKind::Expr if !self.effects.is_empty() => Doc::nil()
+                .append(theme.operator(&"["))
+                .append(Doc::intersperse(
+                    self.effects.iter().map(|(id, ty)| {
+                        Doc::nil()
+                            .append(theme.expr_var(&id.name()))
+                            .append(Doc::space())
+                            .append(theme.operator(&":"))
+                            .append(Doc::space())
+                            .append(ty.pretty(theme))
+                    }),
+                    theme.operator(&",").append(Doc::space()),
+                ))
+                .append(theme.operator(&"]"))
+                .append(Doc::space()),
+            _ => Doc::nil(),
+        };
+
+        let doc_instances = self.instances.iter().fold(Doc::nil(), |doc, ty| {
+            doc.append(
+                Doc::hardline()
+                    .append(theme.keyword(&"Instance"))
+                    .append(Doc::space())
+                    .append(ty.pretty(theme))
+                    .nest(2),
+            )
+        });
+
+        let doc_contract = match &self.contract {
+            Some(contract) => Doc::hardline().append(contract.pretty(theme)).nest(2),
+            None => Doc::nil(),
         };
 
         let documentation = match &self.doc {
             Some(doc) => Doc::hardline().append(doc.pretty(theme)).nest(2),
             None => Doc::nil(),
         };
-        theme
-            .expr_var(&self.id.name())
+        doc_effects
+            .append(theme.expr_var(&qualified_name(&self.path, self.id.name())))
             .append(Doc::space())
+            .append(doc_binders)
             .append(doc_ty)
+            .append(doc_instances)
+            .append(doc_contract)
             .append(documentation)
     }
 }
@@ -183,6 +476,29 @@ impl Located for Help {
 pub struct Env {
     /// map of identifiers
     table: HashMap<Rc<Identifier>, IdentifierInfo>,
+    /// map of constructors
+    constructors: HashMap<Rc<Identifier>, Constructor>,
+    /// constructors of union types, in order of definition
+    unions: HashMap<Rc<Identifier>, Vec<Rc<Identifier>>>,
+    /// map of record types
+    records: HashMap<Rc<Identifier>, Record>,
+    /// record types by name of their fields, the last defined record type hides the others
+    fields: HashMap<String, Rc<Identifier>>,
+    /// instances of classes, the type of each instance with the variable of its dictionary,
+    /// in order of definition
+    classes: HashMap<Rc<Identifier>, Vec<(Type, Rc<Identifier>)>>,
+    /// classes of methods
+    methods: HashMap<Identifier, Rc<Identifier>>,
+    /// map of theorems
+    theorems: HashMap<Rc<Identifier>, Rc<Theorem>>,
+    /// map of contracts of definitions
+    contracts: HashMap<Identifier, Rc<Contract>>,
+    /// map of references of the environment used by definitions,
+    /// a reference defined globally uses itself
+    effects: HashMap<Identifier, Vec<Identifier>>,
+    /// types of definitions that are not generalised,
+    /// their unknown types are solved by later uses
+    weak: Vec<Type>,
 }
 
 impl Env {
@@ -192,8 +508,11 @@ impl Env {
             doc: None,
             kind,
             ty,
+            binders: Vec::new(),
+            constraints: Vec::new(),
             loc_def,
             loc_refs: Vec::new(),
+            path: Vec::new(),
         };
         self.table.insert(id, info);
     }
@@ -208,6 +527,239 @@ impl Env {
         self.add(id, loc_def, ty, IdentifierKind::Type);
     }
 
+    /// add type variable bound by a definition
+    pub fn add_type_var(&mut self, id: Rc<Identifier>, loc_def: Location) {
+        let ty = Type::Variable(TypeVariable::new(id.clone()));
+        self.add(id, loc_def, ty, IdentifierKind::Type);
+    }
+
+    /// add union type definition with type parameters `params`,
+    /// constructors are added afterward
+    pub fn add_union_def(
+        &mut self,
+        id: Rc<Identifier>,
+        params: Vec<Rc<Identifier>>,
+        loc_def: Location,
+    ) {
+        let args = params
+            .iter()
+            .map(|param| Type::Variable(TypeVariable::new(param.clone())))
+            .collect();
+        let ty = Type::Union(TypeUnion::new(id.clone(), args));
+        self.unions.insert(id.clone(), Vec::new());
+        self.add(id.clone(), loc_def, ty, IdentifierKind::Type);
+        self.set_binders(&id, params);
+    }
+
+    /// add constructor of union type `union`
+    pub fn add_constructor(
+        &mut self,
+        id: Rc<Identifier>,
+        union: Rc<Identifier>,
+        args: Vec<Type>,
+        ty: Type,
+        loc_def: Location,
+    ) {
+        self.unions
+            .entry(union.clone())
+            .or_default()
+            .push(id.clone());
+        let params = self
+            .table
+            .get(&union)
+            .map(|info| info.binders.clone())
+            .unwrap_or_default();
+        let constructor = Constructor {
+            id: id.clone(),
+            union,
+            params: params.clone(),
+            args,
+            ty: ty.clone(),
+        };
+        self.constructors.insert(id.clone(), constructor);
+        self.add_expr_def(id.clone(), ty, loc_def);
+        self.set_binders(&id, params);
+    }
+
+    /// add record type definition with type parameters `params`,
+    /// fields are set afterward
+    pub fn add_record_def(
+        &mut self,
+        id: Rc<Identifier>,
+        params: Vec<Rc<Identifier>>,
+        loc_def: Location,
+    ) {
+        let args = params
+            .iter()
+            .map(|param| Type::Variable(TypeVariable::new(param.clone())))
+            .collect();
+        let ty = Type::Record(TypeRecord::new(id.clone(), args));
+        let record = Record {
+            id: id.clone(),
+            params: params.clone(),
+            fields: Vec::new(),
+        };
+        self.records.insert(id.clone(), record);
+        self.add(id.clone(), loc_def, ty, IdentifierKind::Type);
+        self.set_binders(&id, params);
+    }
+
+    /// set fields of record type `record`
+    pub fn set_fields(&mut self, record: &Rc<Identifier>, fields: Vec<DefinitionField>) {
+        for field in &fields {
+            self.fields.insert(field.name().to_string(), record.clone());
+        }
+        if let Some(info) = self.records.get_mut(record) {
+            info.fields = fields;
+        }
+    }
+
+    /// get record type by identifier
+    pub fn get_record(&self, id: &Identifier) -> Option<&Record> {
+        self.records.get(id)
+    }
+
+    /// get last defined record type with a field `name`
+    pub fn record_of_field(&self, name: &str) -> Option<&Record> {
+        self.fields.get(name).and_then(|id| self.records.get(id))
+    }
+
+    /// get record types with fields `names`: the record types with exactly these fields
+    /// if any, the record types declaring all of them otherwise,
+    /// a record type hidden by a later one of the same name is left out
+    pub fn records_of_fields(&self, names: &[&str]) -> Vec<&Record> {
+        let hidden = |record: &Record| {
+            self.records.keys().any(|other| {
+                other.qualified_name() == record.id.qualified_name() && other.id() > record.id.id()
+            })
+        };
+        let declaring = self
+            .records
+            .values()
+            .filter(|record| names.iter().all(|name| record.position(name).is_some()))
+            .filter(|record| !hidden(record))
+            .collect::<Vec<_>>();
+        let exact = declaring
+            .iter()
+            .copied()
+            .filter(|record| {
+                record
+                    .fields
+                    .iter()
+                    .all(|field| names.contains(&field.name()))
+            })
+            .collect::<Vec<_>>();
+        if exact.is_empty() {
+            declaring
+        } else {
+            exact
+        }
+    }
+
+    /// add class, a record type of methods with one type parameter already added
+    pub fn add_class(&mut self, id: Rc<Identifier>) {
+        self.classes.insert(id, Vec::new());
+    }
+
+    /// record type is a class
+    pub fn is_class(&self, id: &Identifier) -> bool {
+        self.classes.contains_key(id)
+    }
+
+    /// add method of class `class`, a field of the class
+    pub fn add_method(&mut self, id: Rc<Identifier>, class: Rc<Identifier>) {
+        self.methods.insert(id.as_ref().clone(), class);
+    }
+
+    /// get class of a method by identifier
+    pub fn get_method(&self, id: &Identifier) -> Option<&Rc<Identifier>> {
+        self.methods.get(id)
+    }
+
+    /// add instance of class `class` for type `ty`, its dictionary is the variable `dict`
+    pub fn add_instance(&mut self, class: &Identifier, ty: Type, dict: Rc<Identifier>) {
+        if let Some(instances) = self.classes.get_mut(class) {
+            instances.push((ty, dict));
+        }
+    }
+
+    /// get instances of class, at most one for each type
+    pub fn instances_of(&self, class: &Identifier) -> &[(Type, Rc<Identifier>)] {
+        self.classes.get(class).map_or(&[], Vec::as_slice)
+    }
+
+    /// add theorem checked by the kernel
+    pub fn add_theorem(&mut self, theorem: Theorem, loc_def: Location) -> Rc<Theorem> {
+        let id = theorem.name().clone();
+        let theorem = Rc::new(theorem);
+        self.theorems.insert(id.clone(), theorem.clone());
+        self.add(
+            id,
+            loc_def,
+            Type::Builtin(TypeBuiltin::prop()),
+            IdentifierKind::Theorem,
+        );
+        theorem
+    }
+
+    /// get statement of theorem by identifier
+    pub fn get_theorem(&mut self, id: &Identifier, loc: Location) -> Option<Formula> {
+        let theorem = self.theorems.get(id)?;
+        if let Some(info) = self.table.get_mut(id) {
+            info.loc_refs.push(loc);
+        }
+        Some(theorem.formula().clone())
+    }
+
+    /// get statement of theorem by identifier, without recording a reference
+    pub fn get_statement(&self, id: &Identifier) -> Option<&Formula> {
+        self.theorems.get(id).map(|theorem| theorem.formula())
+    }
+
+    /// set contract of definition of identifier
+    pub fn set_contract(&mut self, id: &Identifier, contract: Rc<Contract>) {
+        self.contracts.insert(id.clone(), contract);
+    }
+
+    /// get contract of definition by identifier
+    pub fn get_contract(&self, id: &Identifier) -> Option<&Rc<Contract>> {
+        self.contracts.get(id)
+    }
+
+    /// set references of the environment used by definition of identifier
+    pub fn set_effects(&mut self, id: &Identifier, effects: Vec<Identifier>) {
+        self.effects.insert(id.clone(), effects);
+    }
+
+    /// get references of the environment used by definition of identifier
+    pub fn get_effects(&self, id: &Identifier) -> &[Identifier] {
+        self.effects.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// add type of a definition that is not generalised
+    pub fn add_weak(&mut self, ty: Type) {
+        self.weak.push(ty);
+    }
+
+    /// unknown types left in types of definitions that are not generalised
+    pub fn weak_metas(&self) -> Vec<TypeMeta> {
+        self.weak.iter().flat_map(Type::metas).collect()
+    }
+
+    /// get constructor by identifier
+    pub fn get_constructor(&self, id: &Identifier) -> Option<&Constructor> {
+        self.constructors.get(id)
+    }
+
+    /// iterate over constructors of union type
+    pub fn constructors_of(&self, union: &Identifier) -> impl Iterator<Item = &Constructor> {
+        self.unions
+            .get(union)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.constructors.get(id))
+    }
+
     /// set documentation for identifier
     pub fn set_doc(&mut self, id: &Identifier, doc: Documentation) {
         if let Some(info) = self.table.get_mut(id) {
@@ -215,20 +767,66 @@ impl Env {
         }
     }
 
-    /// get variable by identifier
+    /// set modules exporting the definition of identifier
+    pub fn set_path(&mut self, id: &Identifier, path: Vec<String>) {
+        if let Some(info) = self.table.get_mut(id) {
+            info.path = path;
+        }
+    }
+
+    /// set type variables bound by definition of identifier
+    pub fn set_binders(&mut self, id: &Identifier, binders: Vec<Rc<Identifier>>) {
+        if let Some(info) = self.table.get_mut(id) {
+            info.binders = binders;
+        }
+    }
+
+    /// set classes of type variables bound by definition of identifier
+    pub fn set_constraints(&mut self, id: &Identifier, constraints: Vec<TypeRecord>) {
+        if let Some(info) = self.table.get_mut(id) {
+            info.constraints = constraints;
+        }
+    }
+
+    /// get variable by identifier,
+    /// its bound type variables are instantiated with fresh unknown types
     pub fn get_expr_var(
         &mut self,
         id: &Identifier,
         loc: Location,
     ) -> Result<Variable, ErrorVariableNotFound> {
+        self.get_constrained_var(id, loc).map(|(var, _)| var)
+    }
+
+    /// get variable by identifier with the classes of its bound type variables,
+    /// both are instantiated with the same fresh unknown types
+    pub fn get_constrained_var(
+        &mut self,
+        id: &Identifier,
+        loc: Location,
+    ) -> Result<(Variable, Vec<TypeRecord>), ErrorVariableNotFound> {
         match self.table.get_mut(id) {
             Some(info) if info.kind == IdentifierKind::Expr => {
                 info.loc_refs.push(loc.clone());
-                Ok(Variable {
+                let subst = info
+                    .binders
+                    .iter()
+                    .map(|binder| (binder.clone(), Type::Meta(TypeMeta::new())))
+                    .collect::<Substitution>();
+                let constraints = info
+                    .constraints
+                    .iter()
+                    .map(|class| {
+                        let args = class.args().iter().map(|ty| ty.substitute(&subst));
+                        TypeRecord::new(class.name().clone(), args.collect())
+                    })
+                    .collect();
+                let var = Variable {
                     identifier: id.clone(),
-                    ty: info.ty.clone(),
+                    ty: info.ty.substitute(&subst),
                     loc,
-                })
+                };
+                Ok((var, constraints))
             }
             _ => Err(ErrorVariableNotFound::new(
                 id.clone(),
@@ -238,26 +836,50 @@ impl Env {
         }
     }
 
-    /// get type alias
-    pub fn get_alias_ty(
+    /// get type of expression variable by identifier, without recording a reference
+    pub fn get_expr_ty(&self, id: &Identifier) -> Option<&Type> {
+        match self.table.get(id) {
+            Some(info) if info.kind == IdentifierKind::Expr => Some(&info.ty),
+            _ => None,
+        }
+    }
+
+    /// get type `id` applied to type arguments `args`
+    pub fn get_ty(
         &mut self,
         id: &Identifier,
+        args: Vec<Type>,
         loc: Location,
-    ) -> Result<Alias, ErrorVariableNotFound> {
+    ) -> Result<Type, Box<Error>> {
         match self.table.get_mut(id) {
             Some(info) if info.kind == IdentifierKind::Type => {
                 info.loc_refs.push(loc.clone());
-                Ok(Alias {
-                    name: id.clone(),
-                    ty: Box::new(info.ty.clone()),
-                    loc,
-                })
+                if info.binders.len() != args.len() {
+                    let err = ErrorTypeArity::new(id, info.binders.len(), args.len(), &loc);
+                    return Err(Box::new(err.into()));
+                }
+                match &info.ty {
+                    Type::Variable(var) if var.id().as_ref() == id => Ok(info.ty.clone()),
+                    ty => {
+                        let subst = info
+                            .binders
+                            .iter()
+                            .cloned()
+                            .zip(args.iter().cloned())
+                            .collect::<Substitution>();
+                        Ok(Type::Alias(Alias {
+                            name: id.clone(),
+                            args,
+                            ty: Box::new(ty.substitute(&subst)),
+                            loc,
+                        }))
+                    }
+                }
+            }
+            _ => {
+                let err = ErrorVariableNotFound::new(id.clone(), Some(IdentifierKind::Type), loc);
+                Err(Box::new(err.into()))
             }
-            _ => Err(ErrorVariableNotFound::new(
-                id.clone(),
-                Some(IdentifierKind::Type),
-                loc,
-            )),
         }
     }
 
@@ -269,15 +891,108 @@ impl Env {
         match self.table.get(id) {
             Some(info) => Ok(Help {
                 id: info.id.clone(),
+                path: info.path.clone(),
                 ty: info.ty.clone(),
+                binders: info.binders.clone(),
+                constraints: info.constraints.clone(),
                 loc,
                 kind: info.kind,
                 doc: info.doc.clone(),
+                constructors: self.constructors_of(id).cloned().collect(),
+                fields: self
+                    .records
+                    .get(id)
+                    .map(|record| record.fields.clone())
+                    .unwrap_or_default(),
+                theorem: self.theorems.get(id).cloned(),
+                contract: self.contracts.get(id).cloned(),
+                effects: self
+                    .get_effects(id)
+                    .iter()
+                    .filter(|reference| *reference != id)
+                    .filter_map(|reference| {
+                        let ty = self.get_expr_ty(reference)?;
+                        Some((reference.clone(), ty.clone()))
+                    })
+                    .collect(),
+                instances: self
+                    .instances_of(id)
+                    .iter()
+                    .map(|(ty, _)| Type::Record(TypeRecord::new(info.id.clone(), vec![ty.clone()])))
+                    .collect(),
             }),
             None => Err(ErrorVariableNotFound::new(id.clone(), None, loc)),
         }
     }
 
+    /// get the part of the environment defining identifiers that are `defined`,
+    /// with their instances of classes of the environment
+    pub fn exports(&self, defined: impl Fn(&Identifier) -> bool) -> Env {
+        fn filter<K, V>(map: &HashMap<K, V>, keep: impl Fn(&K, &V) -> bool) -> HashMap<K, V>
+        where
+            K: Clone + Eq + std::hash::Hash,
+            V: Clone,
+        {
+            map.iter()
+                .filter(|(key, value)| keep(key, value))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        }
+        let classes = self
+            .classes
+            .iter()
+            .map(|(class, instances)| {
+                let instances = instances
+                    .iter()
+                    .filter(|(_, dict)| defined(dict))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (class.clone(), instances)
+            })
+            .filter(|(class, instances)| defined(class) || !instances.is_empty())
+            .collect();
+        Env {
+            table: filter(&self.table, |id, _| defined(id)),
+            constructors: filter(&self.constructors, |id, _| defined(id)),
+            unions: filter(&self.unions, |id, _| defined(id)),
+            records: filter(&self.records, |id, _| defined(id)),
+            fields: filter(&self.fields, |_, record| defined(record)),
+            classes,
+            methods: filter(&self.methods, |id, _| defined(id)),
+            theorems: filter(&self.theorems, |id, _| defined(id)),
+            contracts: filter(&self.contracts, |id, _| defined(id)),
+            effects: filter(&self.effects, |id, _| defined(id)),
+            weak: Vec::new(),
+        }
+    }
+
+    /// add definitions of `other`, the instances of a class are added after the
+    /// instances already defined
+    pub fn extend(&mut self, other: &Env) {
+        self.table.extend(other.table.clone());
+        self.constructors.extend(other.constructors.clone());
+        self.unions.extend(other.unions.clone());
+        self.records.extend(other.records.clone());
+        self.fields.extend(other.fields.clone());
+        for (class, instances) in &other.classes {
+            let own = self.classes.entry(class.clone()).or_default();
+            for instance in instances {
+                if !own.iter().any(|(_, dict)| dict == &instance.1) {
+                    own.push(instance.clone());
+                }
+            }
+        }
+        self.methods.extend(other.methods.clone());
+        self.theorems.extend(other.theorems.clone());
+        self.contracts.extend(other.contracts.clone());
+        self.effects.extend(other.effects.clone());
+    }
+
+    /// get information of identifier
+    pub fn get_info(&self, id: &Identifier) -> Option<&IdentifierInfo> {
+        self.table.get(id)
+    }
+
     /// iternate over all identifiers
     pub fn iter(&self) -> impl Iterator<Item = &IdentifierInfo> {
         self.table.values()
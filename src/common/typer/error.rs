@@ -1,4 +1,8 @@
 use super::ast::{Identifier, Type};
+use super::class::{ErrorMissingInstance, ErrorNotClass, ErrorOverlappingInstance};
+use super::kernel::ErrorProof;
+use super::matching::Pat;
+use super::tactic::ErrorTactic;
 use crate::typer::env::IdentifierKind;
 use crate::utils::error::{ErrorCode, ErrorReport, Message};
 use crate::utils::location::{Located, Location};
@@ -45,9 +49,10 @@ impl ErrorReport for ErrorVariableNotFound {
         let mut msg = match self.kind {
             Some(IdentifierKind::Type) => Message::text("Type variable "),
             Some(IdentifierKind::Expr) => Message::text("Expression variable "),
+            Some(IdentifierKind::Theorem) => Message::text("Theorem "),
             None => Message::text("Variable "),
         };
-        msg.extend(Message::text(self.identifier.name()).important());
+        msg.extend(Message::text(self.identifier.qualified_name()).important());
         msg.add_text(" not found.");
         Some(msg)
     }
@@ -56,17 +61,29 @@ impl ErrorReport for ErrorVariableNotFound {
 // =======================================================================
 // Error Unexpected Type
 // =======================================================================
+/// type is still unknown, a conflict with it comes from an infinite type
+fn is_unknown(ty: &Type) -> bool {
+    matches!(ty.unfold(), Type::Meta(_))
+}
+
 pub struct ErrorUnexpectedType {
     expected: Type,
     found: Type,
+    /// innermost conflicting types, if they add information to the whole types
+    conflict: Option<Box<(Type, Type)>>,
     loc: Location,
 }
 
 impl ErrorUnexpectedType {
     pub fn new(expected: &Type, found: &Type, location: &Location) -> Self {
+        let conflict = expected.unify(found).err().filter(|conflict| {
+            let (left, right) = conflict.as_ref();
+            left != expected || right != found || is_unknown(left) || is_unknown(right)
+        });
         Self {
             expected: expected.clone(),
             found: found.clone(),
+            conflict,
             loc: location.clone(),
         }
     }
@@ -97,11 +114,367 @@ impl ErrorReport for ErrorUnexpectedType {
     }
 
     fn note(&self) -> Option<Message> {
-        let msg = Message::text("Expected : ")
+        let mut msg = Message::text("Expected : ")
             .append(Message::of_pretty(&self.expected).important())
             .with_line()
             .with_text("Found    : ")
             .append(Message::of_pretty(&self.found).important());
+        if let Some((left, right)) = self.conflict.as_deref() {
+            msg = msg
+                .with_line()
+                .with_text("Conflict : ")
+                .append(Message::of_pretty(left).important())
+                .with_text(" ≠ ")
+                .append(Message::of_pretty(right).important());
+            if is_unknown(left) || is_unknown(right) {
+                msg = msg.with_text(" (infinite type)");
+            }
+        }
+        let (expected, found) = match self.conflict.as_deref() {
+            Some((left, right)) => (left, right),
+            None => (&self.expected, &self.found),
+        };
+        if expected.is_number() && found.is_number() {
+            msg = msg.with_line().with_text("Coercion : ");
+            msg = if found.is_subtype(expected) {
+                msg.append(Message::of_pretty(found).important())
+                    .with_text(" ⊂ ")
+                    .append(Message::of_pretty(expected).important())
+                    .with_text(", but numbers are only coerced where a number is expected")
+            } else {
+                msg.with_text("none from ")
+                    .append(Message::of_pretty(found).important())
+                    .with_text(" to ")
+                    .append(Message::of_pretty(expected).important())
+                    .with_text(", numbers are only coerced along ℕ ⊂ ℤ ⊂ ℚ")
+            };
+        }
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Not A Function
+// =======================================================================
+pub struct ErrorNotFunction {
+    ty: Type,
+    loc: Location,
+}
+
+impl ErrorNotFunction {
+    pub fn new(ty: &Type, location: &Location) -> Self {
+        Self {
+            ty: ty.clone(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorNotFunction {
+    fn code(&self) -> i32 {
+        303
+    }
+}
+
+impl Located for ErrorNotFunction {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorNotFunction {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Not a function.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Expression of type ")
+            .append(Message::of_pretty(&self.ty).important())
+            .with_text(" cannot be applied.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Pattern Mismatch
+// =======================================================================
+pub struct ErrorPatternMismatch {
+    ty: Type,
+    loc: Location,
+}
+
+impl ErrorPatternMismatch {
+    pub fn new(ty: &Type, location: &Location) -> Self {
+        Self {
+            ty: ty.clone(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorPatternMismatch {
+    fn code(&self) -> i32 {
+        305
+    }
+}
+
+impl Located for ErrorPatternMismatch {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorPatternMismatch {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Pattern mismatch.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Pattern cannot match type ")
+            .append(Message::of_pretty(&self.ty).important())
+            .with_text(".");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Non Exhaustive
+// =======================================================================
+pub struct ErrorNonExhaustive {
+    missing: Pat,
+    loc: Location,
+}
+
+impl ErrorNonExhaustive {
+    pub fn new(missing: Pat, location: &Location) -> Self {
+        Self {
+            missing,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorNonExhaustive {
+    fn code(&self) -> i32 {
+        306
+    }
+}
+
+impl Located for ErrorNonExhaustive {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorNonExhaustive {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Non-exhaustive pattern matching.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Pattern ")
+            .append(Message::of_pretty(&self.missing).important())
+            .with_text(" is not matched.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Redundant Pattern
+// =======================================================================
+pub struct ErrorRedundantPattern {
+    loc: Location,
+}
+
+impl ErrorRedundantPattern {
+    pub fn new(location: &Location) -> Self {
+        Self {
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorRedundantPattern {
+    fn code(&self) -> i32 {
+        307
+    }
+}
+
+impl Located for ErrorRedundantPattern {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorRedundantPattern {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Redundant pattern.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        Some(Message::text(
+            "Pattern is already matched by previous patterns.",
+        ))
+    }
+}
+
+/// number of arguments `n` as text, "1 argument" or "n arguments"
+fn arguments(n: usize) -> String {
+    if n == 1 {
+        "1 argument".to_string()
+    } else {
+        format!("{n} arguments")
+    }
+}
+
+// =======================================================================
+// Error Constructor Arity
+// =======================================================================
+pub struct ErrorConstructorArity {
+    identifier: Identifier,
+    expected: usize,
+    found: usize,
+    loc: Location,
+}
+
+impl ErrorConstructorArity {
+    pub fn new(
+        identifier: &Identifier,
+        expected: usize,
+        found: usize,
+        location: &Location,
+    ) -> Self {
+        Self {
+            identifier: identifier.clone(),
+            expected,
+            found,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorConstructorArity {
+    fn code(&self) -> i32 {
+        308
+    }
+}
+
+impl Located for ErrorConstructorArity {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorConstructorArity {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Wrong number of constructor arguments.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Constructor ")
+            .append(Message::text(self.identifier.qualified_name()).important())
+            .with_text(format!(
+                " expects {}, found {}.",
+                arguments(self.expected),
+                self.found
+            ));
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Not A Constructor
+// =======================================================================
+pub struct ErrorNotConstructor {
+    identifier: Identifier,
+    loc: Location,
+}
+
+impl ErrorNotConstructor {
+    pub fn new(identifier: &Identifier, location: &Location) -> Self {
+        Self {
+            identifier: identifier.clone(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorNotConstructor {
+    fn code(&self) -> i32 {
+        309
+    }
+}
+
+impl Located for ErrorNotConstructor {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorNotConstructor {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Not a constructor.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Variable ")
+            .append(Message::text(self.identifier.qualified_name()).important())
+            .with_text(" is not a constructor.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Type Arity
+// =======================================================================
+pub struct ErrorTypeArity {
+    identifier: Identifier,
+    expected: usize,
+    found: usize,
+    loc: Location,
+}
+
+impl ErrorTypeArity {
+    pub fn new(
+        identifier: &Identifier,
+        expected: usize,
+        found: usize,
+        location: &Location,
+    ) -> Self {
+        Self {
+            identifier: identifier.clone(),
+            expected,
+            found,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorTypeArity {
+    fn code(&self) -> i32 {
+        310
+    }
+}
+
+impl Located for ErrorTypeArity {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorTypeArity {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Wrong number of type arguments.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Type ")
+            .append(Message::text(self.identifier.qualified_name()).important())
+            .with_text(format!(
+                " expects {}, found {}.",
+                arguments(self.expected),
+                self.found
+            ));
         Some(msg)
     }
 }
@@ -109,20 +482,731 @@ impl ErrorReport for ErrorUnexpectedType {
 // =======================================================================
 // ErrorFromParser
 // =======================================================================
-pub enum Error {
-    VariableNotFound(ErrorVariableNotFound),
-    UnexpectedType(ErrorUnexpectedType),
+// Error Not Terminating
+// =======================================================================
+#[derive(Debug)]
+pub struct ErrorNotTerminating {
+    identifier: Identifier,
+    loc: Location,
 }
 
-impl From<ErrorVariableNotFound> for Error {
-    fn from(e: ErrorVariableNotFound) -> Self {
-        Error::VariableNotFound(e)
+impl ErrorNotTerminating {
+    pub fn new(identifier: &Identifier, location: &Location) -> Self {
+        Self {
+            identifier: identifier.clone(),
+            loc: location.clone(),
+        }
     }
 }
 
-impl From<ErrorUnexpectedType> for Error {
-    fn from(e: ErrorUnexpectedType) -> Self {
-        Error::UnexpectedType(e)
+impl ErrorCode for ErrorNotTerminating {
+    fn code(&self) -> i32 {
+        311
+    }
+}
+
+impl Located for ErrorNotTerminating {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorNotTerminating {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Recursive definition may not terminate.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Recursive use of ")
+            .append(Message::text(self.identifier.qualified_name()).important())
+            .with_text(" is not applied to a structurally smaller argument.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let msg = Message::text(
+            "At a fixed position, recursive calls must have as argument a variable bound \
+             inside a constructor pattern matching the parameter at that position.",
+        );
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Undeclared Reference
+// =======================================================================
+#[derive(Debug)]
+pub struct ErrorUndeclaredReference {
+    /// reference of the environment
+    reference: Identifier,
+    /// type of reference
+    ty: Type,
+    /// definition using the reference, if it is not the reference itself
+    through: Option<Identifier>,
+    loc: Location,
+}
+
+impl ErrorUndeclaredReference {
+    pub fn new(
+        reference: &Identifier,
+        ty: &Type,
+        through: Option<&Identifier>,
+        location: &Location,
+    ) -> Self {
+        Self {
+            reference: reference.clone(),
+            ty: ty.clone(),
+            through: through.cloned(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorUndeclaredReference {
+    fn code(&self) -> i32 {
+        314
+    }
+}
+
+impl Located for ErrorUndeclaredReference {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorUndeclaredReference {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Reference not declared.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = match &self.through {
+            Some(through) => Message::text(through.name())
+                .important()
+                .with_text(" uses reference "),
+            None => Message::text("Reference "),
+        };
+        let msg = msg.append(Message::text(self.reference.name()).important());
+        let msg = match &self.through {
+            Some(_) => {
+                msg.with_text(", which is not declared in the environment of the definition.")
+            }
+            None => msg.with_text(" is not declared in the environment of the definition."),
+        };
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let msg = Message::text("Declare it before the name of the definition: ")
+            .append(
+                Message::text(format!("[{} : ", self.reference.name()))
+                    .append(Message::of_pretty(&self.ty))
+                    .with_text("]")
+                    .important(),
+            )
+            .with_text(".");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Unknown Field
+// =======================================================================
+pub struct ErrorUnknownField {
+    field: String,
+    /// record type expected to have the field
+    ty: Option<Type>,
+    /// fields of the record type
+    fields: Vec<String>,
+    loc: Location,
+}
+
+impl ErrorUnknownField {
+    pub fn new(field: &str, ty: Option<&Type>, fields: Vec<String>, location: &Location) -> Self {
+        Self {
+            field: field.to_string(),
+            ty: ty.cloned(),
+            fields,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorUnknownField {
+    fn code(&self) -> i32 {
+        315
+    }
+}
+
+impl Located for ErrorUnknownField {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorUnknownField {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Field not found.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = match &self.ty {
+            Some(ty) => Message::text("Type ")
+                .append(Message::of_pretty(ty).important())
+                .with_text(" has no field ")
+                .append(Message::text(&self.field).important())
+                .with_text("."),
+            None => Message::text("Field ")
+                .append(Message::text(&self.field).important())
+                .with_text(" is not a field of a record type."),
+        };
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        if self.fields.is_empty() {
+            return None;
+        }
+        Some(
+            Message::text("Fields are ").append(
+                Message::intersperse(
+                    self.fields
+                        .iter()
+                        .map(|field| Message::text(field).important()),
+                    Message::text(", "),
+                )
+                .with_text("."),
+            ),
+        )
+    }
+}
+
+// =======================================================================
+// Error Missing Field
+// =======================================================================
+pub struct ErrorMissingField {
+    /// fields not given
+    fields: Vec<String>,
+    /// record type
+    ty: Type,
+    loc: Location,
+}
+
+impl ErrorMissingField {
+    pub fn new(fields: Vec<String>, ty: &Type, location: &Location) -> Self {
+        Self {
+            fields,
+            ty: ty.clone(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorMissingField {
+    fn code(&self) -> i32 {
+        316
+    }
+}
+
+impl Located for ErrorMissingField {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorMissingField {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Missing field.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let (head, verb) = match self.fields.len() {
+            1 => ("Field ", " is not given."),
+            _ => ("Fields ", " are not given."),
+        };
+        let msg = Message::text(head)
+            .append(Message::intersperse(
+                self.fields
+                    .iter()
+                    .map(|field| Message::text(field).important()),
+                Message::text(", "),
+            ))
+            .with_text(" of ")
+            .append(Message::of_pretty(&self.ty).important())
+            .with_text(verb);
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Duplicate Field
+// =======================================================================
+pub struct ErrorDuplicateField {
+    field: String,
+    loc: Location,
+}
+
+impl ErrorDuplicateField {
+    pub fn new(field: &str, location: &Location) -> Self {
+        Self {
+            field: field.to_string(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorDuplicateField {
+    fn code(&self) -> i32 {
+        317
+    }
+}
+
+impl Located for ErrorDuplicateField {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorDuplicateField {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Duplicate field.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Field ")
+            .append(Message::text(&self.field).important())
+            .with_text(" appears more than once.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Module Not Found
+// =======================================================================
+pub struct ErrorModuleNotFound {
+    name: String,
+    loc: Location,
+}
+
+impl ErrorModuleNotFound {
+    pub fn new(name: &str, location: &Location) -> Self {
+        Self {
+            name: name.to_string(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorModuleNotFound {
+    fn code(&self) -> i32 {
+        318
+    }
+}
+
+impl Located for ErrorModuleNotFound {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorModuleNotFound {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Module not found.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Module ")
+            .append(Message::text(&self.name).important())
+            .with_text(" is not defined.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Module End
+// =======================================================================
+pub struct ErrorModuleEnd {
+    name: String,
+    /// module being defined
+    current: Option<String>,
+    loc: Location,
+}
+
+impl ErrorModuleEnd {
+    pub fn new(name: &str, current: Option<String>, location: &Location) -> Self {
+        Self {
+            name: name.to_string(),
+            current,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorModuleEnd {
+    fn code(&self) -> i32 {
+        319
+    }
+}
+
+impl Located for ErrorModuleEnd {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorModuleEnd {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Unexpected end of module.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Module ")
+            .append(Message::text(&self.name).important())
+            .with_text(" is not being defined.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let msg = match &self.current {
+            Some(current) => Message::text("Current module is ")
+                .append(Message::text(current).important())
+                .with_text("."),
+            None => Message::text("No module is being defined."),
+        };
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Module Not Closed
+// =======================================================================
+pub struct ErrorModuleNotClosed {
+    name: String,
+    loc: Location,
+}
+
+impl ErrorModuleNotClosed {
+    pub fn new(name: &str, location: &Location) -> Self {
+        Self {
+            name: name.to_string(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorModuleNotClosed {
+    fn code(&self) -> i32 {
+        323
+    }
+}
+
+impl Located for ErrorModuleNotClosed {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorModuleNotClosed {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Module not closed.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Module ")
+            .append(Message::text(&self.name).important())
+            .with_text(" is not ended at the end of the file.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let msg = Message::text("End it with ")
+            .append(Message::text(format!("End {}", self.name)).important())
+            .with_text(" before the end of the file.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Shadowed Result
+// =======================================================================
+pub struct ErrorShadowedResult {
+    /// name of the result of postconditions
+    name: String,
+    loc: Location,
+}
+
+impl ErrorShadowedResult {
+    pub fn new(name: &str, location: &Location) -> Self {
+        Self {
+            name: name.to_string(),
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorShadowedResult {
+    fn code(&self) -> i32 {
+        325
+    }
+}
+
+impl Located for ErrorShadowedResult {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorShadowedResult {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Shadowed result.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Parameter ")
+            .append(Message::text(&self.name).important())
+            .with_text(" would be hidden by the result of the postconditions.");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        let msg = Message::text("Postconditions name the result ")
+            .append(Message::text(&self.name).important())
+            .with_text(", rename the parameter.");
+        Some(msg)
+    }
+}
+
+// =======================================================================
+// Error Ambiguous Record
+// =======================================================================
+pub struct ErrorAmbiguousRecord {
+    /// fields given
+    fields: Vec<String>,
+    /// record types with these fields
+    records: Vec<String>,
+    loc: Location,
+}
+
+impl ErrorAmbiguousRecord {
+    pub fn new(fields: Vec<String>, records: Vec<String>, location: &Location) -> Self {
+        Self {
+            fields,
+            records,
+            loc: location.clone(),
+        }
+    }
+}
+
+impl ErrorCode for ErrorAmbiguousRecord {
+    fn code(&self) -> i32 {
+        326
+    }
+}
+
+impl Located for ErrorAmbiguousRecord {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorAmbiguousRecord {
+    fn head(&self) -> crate::utils::error::Message {
+        Message::text("Ambiguous record.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = Message::text("Record with fields ")
+            .append(Message::intersperse(
+                self.fields
+                    .iter()
+                    .map(|field| Message::text(field).important()),
+                Message::text(", "),
+            ))
+            .with_text(" may be of type ")
+            .append(Message::intersperse(
+                self.records
+                    .iter()
+                    .map(|record| Message::text(record).important()),
+                Message::text(" or "),
+            ))
+            .with_text(".");
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        Some(Message::text("Annotate the record with its type."))
+    }
+}
+
+// =======================================================================
+pub enum Error {
+    VariableNotFound(ErrorVariableNotFound),
+    UnexpectedType(ErrorUnexpectedType),
+    NotFunction(ErrorNotFunction),
+    PatternMismatch(ErrorPatternMismatch),
+    NonExhaustive(ErrorNonExhaustive),
+    RedundantPattern(ErrorRedundantPattern),
+    ConstructorArity(ErrorConstructorArity),
+    NotConstructor(ErrorNotConstructor),
+    TypeArity(ErrorTypeArity),
+    NotTerminating(ErrorNotTerminating),
+    UndeclaredReference(ErrorUndeclaredReference),
+    UnknownField(ErrorUnknownField),
+    MissingField(ErrorMissingField),
+    DuplicateField(ErrorDuplicateField),
+    ModuleNotFound(ErrorModuleNotFound),
+    ModuleEnd(ErrorModuleEnd),
+    ModuleNotClosed(ErrorModuleNotClosed),
+    NotClass(ErrorNotClass),
+    MissingInstance(ErrorMissingInstance),
+    OverlappingInstance(ErrorOverlappingInstance),
+    Proof(ErrorProof),
+    Tactic(ErrorTactic),
+    ShadowedResult(ErrorShadowedResult),
+    AmbiguousRecord(ErrorAmbiguousRecord),
+}
+
+impl From<ErrorVariableNotFound> for Error {
+    fn from(e: ErrorVariableNotFound) -> Self {
+        Error::VariableNotFound(e)
+    }
+}
+
+impl From<ErrorUnexpectedType> for Error {
+    fn from(e: ErrorUnexpectedType) -> Self {
+        Error::UnexpectedType(e)
+    }
+}
+
+impl From<ErrorNotFunction> for Error {
+    fn from(e: ErrorNotFunction) -> Self {
+        Error::NotFunction(e)
+    }
+}
+
+impl From<ErrorPatternMismatch> for Error {
+    fn from(e: ErrorPatternMismatch) -> Self {
+        Error::PatternMismatch(e)
+    }
+}
+
+impl From<ErrorNonExhaustive> for Error {
+    fn from(e: ErrorNonExhaustive) -> Self {
+        Error::NonExhaustive(e)
+    }
+}
+
+impl From<ErrorRedundantPattern> for Error {
+    fn from(e: ErrorRedundantPattern) -> Self {
+        Error::RedundantPattern(e)
+    }
+}
+
+impl From<ErrorConstructorArity> for Error {
+    fn from(e: ErrorConstructorArity) -> Self {
+        Error::ConstructorArity(e)
+    }
+}
+
+impl From<ErrorNotConstructor> for Error {
+    fn from(e: ErrorNotConstructor) -> Self {
+        Error::NotConstructor(e)
+    }
+}
+
+impl From<ErrorTypeArity> for Error {
+    fn from(e: ErrorTypeArity) -> Self {
+        Error::TypeArity(e)
+    }
+}
+
+impl From<ErrorNotTerminating> for Error {
+    fn from(e: ErrorNotTerminating) -> Self {
+        Error::NotTerminating(e)
+    }
+}
+
+impl From<ErrorUndeclaredReference> for Error {
+    fn from(e: ErrorUndeclaredReference) -> Self {
+        Error::UndeclaredReference(e)
+    }
+}
+
+impl From<ErrorUnknownField> for Error {
+    fn from(e: ErrorUnknownField) -> Self {
+        Error::UnknownField(e)
+    }
+}
+
+impl From<ErrorMissingField> for Error {
+    fn from(e: ErrorMissingField) -> Self {
+        Error::MissingField(e)
+    }
+}
+
+impl From<ErrorDuplicateField> for Error {
+    fn from(e: ErrorDuplicateField) -> Self {
+        Error::DuplicateField(e)
+    }
+}
+
+impl From<ErrorModuleNotFound> for Error {
+    fn from(e: ErrorModuleNotFound) -> Self {
+        Error::ModuleNotFound(e)
+    }
+}
+
+impl From<ErrorModuleEnd> for Error {
+    fn from(e: ErrorModuleEnd) -> Self {
+        Error::ModuleEnd(e)
+    }
+}
+
+impl From<ErrorModuleNotClosed> for Error {
+    fn from(e: ErrorModuleNotClosed) -> Self {
+        Error::ModuleNotClosed(e)
+    }
+}
+
+impl From<ErrorNotClass> for Error {
+    fn from(e: ErrorNotClass) -> Self {
+        Error::NotClass(e)
+    }
+}
+
+impl From<ErrorMissingInstance> for Error {
+    fn from(e: ErrorMissingInstance) -> Self {
+        Error::MissingInstance(e)
+    }
+}
+
+impl From<ErrorOverlappingInstance> for Error {
+    fn from(e: ErrorOverlappingInstance) -> Self {
+        Error::OverlappingInstance(e)
+    }
+}
+
+impl From<ErrorProof> for Error {
+    fn from(e: ErrorProof) -> Self {
+        Error::Proof(e)
+    }
+}
+
+impl From<ErrorTactic> for Error {
+    fn from(e: ErrorTactic) -> Self {
+        Error::Tactic(e)
+    }
+}
+
+impl From<ErrorShadowedResult> for Error {
+    fn from(e: ErrorShadowedResult) -> Self {
+        Error::ShadowedResult(e)
+    }
+}
+
+impl From<ErrorAmbiguousRecord> for Error {
+    fn from(e: ErrorAmbiguousRecord) -> Self {
+        Error::AmbiguousRecord(e)
     }
 }
 
@@ -131,6 +1215,28 @@ impl ErrorCode for Error {
         match self {
             Error::VariableNotFound(e) => e.code(),
             Error::UnexpectedType(e) => e.code(),
+            Error::NotFunction(e) => e.code(),
+            Error::PatternMismatch(e) => e.code(),
+            Error::NonExhaustive(e) => e.code(),
+            Error::RedundantPattern(e) => e.code(),
+            Error::ConstructorArity(e) => e.code(),
+            Error::NotConstructor(e) => e.code(),
+            Error::TypeArity(e) => e.code(),
+            Error::NotTerminating(e) => e.code(),
+            Error::UndeclaredReference(e) => e.code(),
+            Error::UnknownField(e) => e.code(),
+            Error::MissingField(e) => e.code(),
+            Error::DuplicateField(e) => e.code(),
+            Error::ModuleNotFound(e) => e.code(),
+            Error::ModuleEnd(e) => e.code(),
+            Error::ModuleNotClosed(e) => e.code(),
+            Error::NotClass(e) => e.code(),
+            Error::MissingInstance(e) => e.code(),
+            Error::OverlappingInstance(e) => e.code(),
+            Error::Proof(e) => e.code(),
+            Error::Tactic(e) => e.code(),
+            Error::ShadowedResult(e) => e.code(),
+            Error::AmbiguousRecord(e) => e.code(),
         }
     }
 }
@@ -140,6 +1246,28 @@ impl Located for Error {
         match self {
             Error::VariableNotFound(e) => e.loc(),
             Error::UnexpectedType(e) => e.loc(),
+            Error::NotFunction(e) => e.loc(),
+            Error::PatternMismatch(e) => e.loc(),
+            Error::NonExhaustive(e) => e.loc(),
+            Error::RedundantPattern(e) => e.loc(),
+            Error::ConstructorArity(e) => e.loc(),
+            Error::NotConstructor(e) => e.loc(),
+            Error::TypeArity(e) => e.loc(),
+            Error::NotTerminating(e) => e.loc(),
+            Error::UndeclaredReference(e) => e.loc(),
+            Error::UnknownField(e) => e.loc(),
+            Error::MissingField(e) => e.loc(),
+            Error::DuplicateField(e) => e.loc(),
+            Error::ModuleNotFound(e) => e.loc(),
+            Error::ModuleEnd(e) => e.loc(),
+            Error::ModuleNotClosed(e) => e.loc(),
+            Error::NotClass(e) => e.loc(),
+            Error::MissingInstance(e) => e.loc(),
+            Error::OverlappingInstance(e) => e.loc(),
+            Error::Proof(e) => e.loc(),
+            Error::Tactic(e) => e.loc(),
+            Error::ShadowedResult(e) => e.loc(),
+            Error::AmbiguousRecord(e) => e.loc(),
         }
     }
 }
@@ -149,6 +1277,28 @@ impl ErrorReport for Error {
         match self {
             Error::VariableNotFound(e) => e.head(),
             Error::UnexpectedType(e) => e.head(),
+            Error::NotFunction(e) => e.head(),
+            Error::PatternMismatch(e) => e.head(),
+            Error::NonExhaustive(e) => e.head(),
+            Error::RedundantPattern(e) => e.head(),
+            Error::ConstructorArity(e) => e.head(),
+            Error::NotConstructor(e) => e.head(),
+            Error::TypeArity(e) => e.head(),
+            Error::NotTerminating(e) => e.head(),
+            Error::UndeclaredReference(e) => e.head(),
+            Error::UnknownField(e) => e.head(),
+            Error::MissingField(e) => e.head(),
+            Error::DuplicateField(e) => e.head(),
+            Error::ModuleNotFound(e) => e.head(),
+            Error::ModuleEnd(e) => e.head(),
+            Error::ModuleNotClosed(e) => e.head(),
+            Error::NotClass(e) => e.head(),
+            Error::MissingInstance(e) => e.head(),
+            Error::OverlappingInstance(e) => e.head(),
+            Error::Proof(e) => e.head(),
+            Error::Tactic(e) => e.head(),
+            Error::ShadowedResult(e) => e.head(),
+            Error::AmbiguousRecord(e) => e.head(),
         }
     }
 
@@ -156,6 +1306,28 @@ impl ErrorReport for Error {
         match self {
             Error::VariableNotFound(e) => e.text(),
             Error::UnexpectedType(e) => e.text(),
+            Error::NotFunction(e) => e.text(),
+            Error::PatternMismatch(e) => e.text(),
+            Error::NonExhaustive(e) => e.text(),
+            Error::RedundantPattern(e) => e.text(),
+            Error::ConstructorArity(e) => e.text(),
+            Error::NotConstructor(e) => e.text(),
+            Error::TypeArity(e) => e.text(),
+            Error::NotTerminating(e) => e.text(),
+            Error::UndeclaredReference(e) => e.text(),
+            Error::UnknownField(e) => e.text(),
+            Error::MissingField(e) => e.text(),
+            Error::DuplicateField(e) => e.text(),
+            Error::ModuleNotFound(e) => e.text(),
+            Error::ModuleEnd(e) => e.text(),
+            Error::ModuleNotClosed(e) => e.text(),
+            Error::NotClass(e) => e.text(),
+            Error::MissingInstance(e) => e.text(),
+            Error::OverlappingInstance(e) => e.text(),
+            Error::Proof(e) => e.text(),
+            Error::Tactic(e) => e.text(),
+            Error::ShadowedResult(e) => e.text(),
+            Error::AmbiguousRecord(e) => e.text(),
         }
     }
 
@@ -163,6 +1335,28 @@ impl ErrorReport for Error {
         match self {
             Error::VariableNotFound(e) => e.note(),
             Error::UnexpectedType(e) => e.note(),
+            Error::NotFunction(e) => e.note(),
+            Error::PatternMismatch(e) => e.note(),
+            Error::NonExhaustive(e) => e.note(),
+            Error::RedundantPattern(e) => e.note(),
+            Error::ConstructorArity(e) => e.note(),
+            Error::NotConstructor(e) => e.note(),
+            Error::TypeArity(e) => e.note(),
+            Error::NotTerminating(e) => e.note(),
+            Error::UndeclaredReference(e) => e.note(),
+            Error::UnknownField(e) => e.note(),
+            Error::MissingField(e) => e.note(),
+            Error::DuplicateField(e) => e.note(),
+            Error::ModuleNotFound(e) => e.note(),
+            Error::ModuleEnd(e) => e.note(),
+            Error::ModuleNotClosed(e) => e.note(),
+            Error::NotClass(e) => e.note(),
+            Error::MissingInstance(e) => e.note(),
+            Error::OverlappingInstance(e) => e.note(),
+            Error::Proof(e) => e.note(),
+            Error::Tactic(e) => e.note(),
+            Error::ShadowedResult(e) => e.note(),
+            Error::AmbiguousRecord(e) => e.note(),
         }
     }
 }
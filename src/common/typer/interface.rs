@@ -0,0 +1,332 @@
+use super::ast::{ConstantKind, Primitive, TypeBuiltinKind};
+use std::fmt::{self, Display, Formatter};
+use std::iter::Peekable;
+use std::path::PathBuf;
+use std::vec::IntoIter;
+
+/// version of the format of interfaces
+const FORMAT: u32 = 3;
+
+/// first line of interfaces, interfaces written in another format or by another build
+/// are not loaded
+fn header() -> String {
+    format!(
+        "startlang-interface {FORMAT} {} {}",
+        env!("CARGO_PKG_VERSION"),
+        env!("STARTLANG_FINGERPRINT")
+    )
+}
+
+/// names of builtin types in interfaces
+const BUILTINS: [(TypeBuiltinKind, &str); 8] = [
+    (TypeBuiltinKind::Nat, "Nat"),
+    (TypeBuiltinKind::Int, "Int"),
+    (TypeBuiltinKind::Rat, "Rat"),
+    (TypeBuiltinKind::Bool, "Bool"),
+    (TypeBuiltinKind::Char, "Char"),
+    (TypeBuiltinKind::String, "String"),
+    (TypeBuiltinKind::Unit, "Unit"),
+    (TypeBuiltinKind::Prop, "Prop"),
+];
+
+/// names of primitive functions in interfaces
+const PRIMITIVES: [(Primitive, &str); 5] = [
+    (Primitive::IntOfNat, "IntOfNat"),
+    (Primitive::NatOfInt, "NatOfInt"),
+    (Primitive::StringLength, "StringLength"),
+    (Primitive::StringAppend, "StringAppend"),
+    (Primitive::StringGet, "StringGet"),
+];
+
+// ==========================================================================
+// Interface Type
+// ==========================================================================
+/// type of an exported definition
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ty {
+    Builtin(TypeBuiltinKind),
+    /// exported type alias applied to type arguments
+    Alias(String, Vec<Ty>),
+    Arrow(Box<Ty>, Box<Ty>),
+    Product(Vec<Ty>),
+    Reference(Box<Ty>),
+    /// type variable, by its position in the binders of the definition
+    Variable(usize),
+}
+
+impl Display for Ty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut Formatter<'_>, head: &str, tys: &[Ty]| {
+            write!(f, "({head}")?;
+            tys.iter().try_for_each(|ty| write!(f, " {ty}"))?;
+            write!(f, ")")
+        };
+        match self {
+            Ty::Builtin(kind) => {
+                let (_, name) = BUILTINS.iter().find(|(other, _)| other == kind).unwrap();
+                write!(f, "(builtin {name})")
+            }
+            Ty::Alias(name, args) => list(f, &format!("alias {name}"), args),
+            Ty::Arrow(arg, ret) => write!(f, "(arrow {arg} {ret})"),
+            Ty::Product(tys) => list(f, "product", tys),
+            Ty::Reference(ty) => write!(f, "(ref {ty})"),
+            Ty::Variable(index) => write!(f, "(var {index})"),
+        }
+    }
+}
+
+type Tokens = Peekable<IntoIter<String>>;
+
+/// split s-expression into parentheses and words
+fn tokens(s: &str) -> Tokens {
+    s.replace('(', " ( ")
+        .replace(')', " ) ")
+        .split_whitespace()
+        .map(str::to_string)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .peekable()
+}
+
+/// parse next token, it must be `expected`
+fn expect(tokens: &mut Tokens, expected: &str) -> Option<()> {
+    (tokens.next()? == expected).then_some(())
+}
+
+impl Ty {
+    /// parse type `(head ...)`
+    fn parse(tokens: &mut Tokens) -> Option<Self> {
+        expect(tokens, "(")?;
+        let ty = match tokens.next()?.as_str() {
+            "builtin" => {
+                let name = tokens.next()?;
+                let (kind, _) = BUILTINS.iter().find(|(_, other)| *other == name)?;
+                Ty::Builtin(kind.clone())
+            }
+            "alias" => {
+                let name = tokens.next()?;
+                return Some(Ty::Alias(name, Self::parse_list(tokens)?));
+            }
+            "arrow" => Ty::Arrow(
+                Box::new(Self::parse(tokens)?),
+                Box::new(Self::parse(tokens)?),
+            ),
+            "product" => return Some(Ty::Product(Self::parse_list(tokens)?)),
+            "ref" => Ty::Reference(Box::new(Self::parse(tokens)?)),
+            "var" => Ty::Variable(tokens.next()?.parse().ok()?),
+            _ => return None,
+        };
+        expect(tokens, ")")?;
+        Some(ty)
+    }
+
+    /// parse types up to the closing parenthesis
+    fn parse_list(tokens: &mut Tokens) -> Option<Vec<Self>> {
+        let mut tys = Vec::new();
+        while tokens.peek()? != ")" {
+            tys.push(Self::parse(tokens)?);
+        }
+        tokens.next();
+        Some(tys)
+    }
+}
+
+// ==========================================================================
+// Interface Value
+// ==========================================================================
+/// constant can be stored as the value of a definition
+pub fn is_storable(constant: &ConstantKind) -> bool {
+    matches!(
+        constant,
+        ConstantKind::Nat(_)
+            | ConstantKind::Int(_)
+            | ConstantKind::Bool(_)
+            | ConstantKind::Char(_)
+            | ConstantKind::Unit
+            | ConstantKind::Primitive(_)
+    )
+}
+
+/// write constant `(head ...)`, it must be storable
+fn write_constant(f: &mut Formatter<'_>, constant: &ConstantKind) -> fmt::Result {
+    match constant {
+        ConstantKind::Nat(n) => write!(f, "(nat {n})"),
+        ConstantKind::Int(i) => write!(f, "(int {i})"),
+        ConstantKind::Bool(b) => write!(f, "(bool {b})"),
+        ConstantKind::Char(c) => write!(f, "(char {})", u32::from(*c)),
+        ConstantKind::Unit => write!(f, "(unit)"),
+        ConstantKind::Primitive(p) => {
+            let (_, name) = PRIMITIVES.iter().find(|(other, _)| other == p).unwrap();
+            write!(f, "(primitive {name})")
+        }
+        ConstantKind::Rat(_) | ConstantKind::String(_) => Err(fmt::Error),
+    }
+}
+
+/// parse constant `(head ...)`
+fn parse_constant(tokens: &mut Tokens) -> Option<ConstantKind> {
+    expect(tokens, "(")?;
+    let head = tokens.next()?;
+    let constant = if head == "unit" {
+        ConstantKind::Unit
+    } else {
+        let arg = tokens.next()?;
+        match head.as_str() {
+            "nat" => ConstantKind::Nat(arg.parse().ok()?),
+            "int" => ConstantKind::Int(arg.parse().ok()?),
+            "bool" => ConstantKind::Bool(arg.parse().ok()?),
+            "char" => ConstantKind::Char(char::from_u32(arg.parse().ok()?)?),
+            "primitive" => {
+                let (p, _) = PRIMITIVES.iter().find(|(_, other)| *other == arg)?;
+                ConstantKind::Primitive(*p)
+            }
+            _ => return None,
+        }
+    };
+    expect(tokens, ")")?;
+    Some(constant)
+}
+
+// ==========================================================================
+// Export
+// ==========================================================================
+/// kind of exported definition
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportKind {
+    /// type definition, its type is the defined type
+    Type,
+    /// expression definition with its value
+    Definition(ConstantKind),
+}
+
+/// definition exported by a checked file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Export {
+    /// name of definition
+    pub name: String,
+    /// kind of definition
+    pub kind: ExportKind,
+    /// names of type variables bound by definition
+    pub binders: Vec<String>,
+    /// type of definition
+    pub ty: Ty,
+    /// documentation lines
+    pub doc: Vec<String>,
+    /// start and end of the name of definition in the source
+    pub span: (usize, usize),
+}
+
+impl Display for Export {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            ExportKind::Type => "type",
+            ExportKind::Definition(_) => "definition",
+        };
+        writeln!(f, "{kind} {} {} {}", self.name, self.span.0, self.span.1)?;
+        write!(f, "binders")?;
+        self.binders.iter().try_for_each(|b| write!(f, " {b}"))?;
+        writeln!(f)?;
+        writeln!(f, "ty {}", self.ty)?;
+        if let ExportKind::Definition(value) = &self.kind {
+            write!(f, "value ")?;
+            write_constant(f, value)?;
+            writeln!(f)?;
+        }
+        self.doc
+            .iter()
+            .try_for_each(|line| writeln!(f, "doc {line}"))?;
+        writeln!(f, "end")
+    }
+}
+
+impl Export {
+    /// parse export starting with line `first`, up to its `end` line
+    fn parse<'a>(first: &str, lines: &mut impl Iterator<Item = &'a str>) -> Option<Self> {
+        let mut words = first.split(' ');
+        let kind = words.next()?;
+        let name = words.next()?.to_string();
+        let span = (words.next()?.parse().ok()?, words.next()?.parse().ok()?);
+        let binders = lines
+            .next()?
+            .strip_prefix("binders")?
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        let ty = Ty::parse(&mut tokens(lines.next()?.strip_prefix("ty ")?))?;
+        let kind = match kind {
+            "type" => ExportKind::Type,
+            "definition" => {
+                let value = lines.next()?.strip_prefix("value ")?;
+                ExportKind::Definition(parse_constant(&mut tokens(value))?)
+            }
+            _ => return None,
+        };
+        let mut doc = Vec::new();
+        loop {
+            match lines.next()? {
+                "end" => break,
+                line => doc.push(line.strip_prefix("doc ")?.to_string()),
+            }
+        }
+        Some(Self {
+            name,
+            kind,
+            binders,
+            ty,
+            doc,
+            span,
+        })
+    }
+}
+
+// ==========================================================================
+// Interface
+// ==========================================================================
+/// definitions exported by a checked file, in order of definition,
+/// they are loaded without checking the file again
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interface {
+    /// hash of the content of the checked file
+    pub hash: u64,
+    /// hash of the content of the files the checked file depends on, with their canonical path
+    pub depends: Vec<(u64, PathBuf)>,
+    /// exported definitions
+    pub exports: Vec<Export>,
+}
+
+impl Display for Interface {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", header())?;
+        writeln!(f, "hash {:016x}", self.hash)?;
+        self.depends
+            .iter()
+            .try_for_each(|(hash, path)| writeln!(f, "depends {hash:016x} {}", path.display()))?;
+        self.exports
+            .iter()
+            .try_for_each(|export| write!(f, "{export}"))
+    }
+}
+
+impl Interface {
+    /// parse interface, `None` when it is malformed or written by another build
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut lines = content.lines();
+        (lines.next()? == header()).then_some(())?;
+        let hash = u64::from_str_radix(lines.next()?.strip_prefix("hash ")?, 16).ok()?;
+        let mut depends = Vec::new();
+        let mut exports = Vec::new();
+        while let Some(line) = lines.next() {
+            if let Some(depend) = line.strip_prefix("depends ") {
+                let (hash, path) = depend.split_once(' ')?;
+                depends.push((u64::from_str_radix(hash, 16).ok()?, PathBuf::from(path)));
+            } else {
+                exports.push(Export::parse(line, &mut lines)?);
+            }
+        }
+        Some(Self {
+            hash,
+            depends,
+            exports,
+        })
+    }
+}
@@ -0,0 +1,227 @@
+use super::error::{ErrorProof, Reason};
+use super::formula::{Formula, Term};
+use super::proof::{Arg, ProofTerm};
+use super::Terms;
+use crate::parser::cst::expression::Variable;
+use crate::parser::cst::AsIdentifier as _;
+use crate::typer::ast::{Type, Typed as _};
+use crate::typer::error::{Error, ErrorUnexpectedType};
+use crate::utils::error::ResultExt as _;
+use crate::utils::location::{Located, Location};
+
+type Result<T, E = Vec<Error>> = std::result::Result<T, E>;
+
+fn fail<T>(reason: Reason, loc: &Location) -> Result<T> {
+    Err(vec![ErrorProof::new(reason, loc).into()])
+}
+
+/// proof constructors known by the kernel, hidden by hypotheses of same name
+const CONSTRUCTORS: &[&str] = &[
+    "conj",
+    "or_introl",
+    "or_intror",
+    "ex_intro",
+    "or_ind",
+    "ex_ind",
+    "eq_refl",
+    "proj1",
+    "proj2",
+];
+
+/// read argument as a proof
+fn proof_of(arg: &Arg) -> Result<ProofTerm> {
+    match arg {
+        Arg::Expr(expr) => ProofTerm::of(expr),
+        Arg::Proof(proof) => Ok(proof.clone()),
+    }
+}
+
+// ==========================================================================
+// Checker
+// ==========================================================================
+pub(super) struct Checker<'t, T> {
+    terms: &'t mut T,
+    /// hypotheses in scope, innermost last
+    hypotheses: Vec<(String, Formula)>,
+}
+
+impl<'t, T: Terms> Checker<'t, T> {
+    pub(super) fn new(terms: &'t mut T, hypotheses: Vec<(String, Formula)>) -> Self {
+        Self { terms, hypotheses }
+    }
+
+    /// get hypothesis by name
+    fn hypothesis(&self, name: &str) -> Option<&Formula> {
+        self.hypotheses
+            .iter()
+            .rev()
+            .find(|(hyp, _)| hyp == name)
+            .map(|(_, formula)| formula)
+    }
+
+    /// name of proof constructor, if `var` names one and is not hidden by a hypothesis
+    fn constructor(&self, var: &Variable) -> Option<&'static str> {
+        CONSTRUCTORS
+            .iter()
+            .find(|name| **name == var.name())
+            .filter(|_| self.hypothesis(var.name()).is_none())
+            .copied()
+    }
+
+    /// type witness of type `ty` and convert it to a term
+    pub(super) fn witness(&mut self, arg: &Arg, ty: &Type) -> Result<Term> {
+        let Arg::Expr(expr) = arg else {
+            return fail(Reason::NotTerm, &arg.loc());
+        };
+        let witness = self.terms.term(expr)?;
+        if !witness.ty().is_compatible(ty) {
+            let err = ErrorUnexpectedType::new(ty, witness.ty(), &witness.loc());
+            return Err(vec![err.into()]);
+        }
+        Term::of(&witness).or_else(|loc| fail(Reason::Unsupported, &loc))
+    }
+
+    /// check that argument `arg` proves `goal`
+    fn check_arg(&mut self, arg: &Arg, goal: &Formula) -> Result<()> {
+        self.check(&proof_of(arg)?, goal)
+    }
+
+    /// check that `proof` proves `goal`
+    pub(super) fn check(&mut self, proof: &ProofTerm, goal: &Formula) -> Result<()> {
+        match proof {
+            ProofTerm::Intro { var, body, loc } => match goal {
+                Formula::Implies(hyp, concl) => {
+                    let hyp = hyp.as_ref().clone();
+                    self.hypotheses.push((var.name().to_string(), hyp));
+                    let res = self.check(body, concl);
+                    self.hypotheses.pop();
+                    res
+                }
+                Formula::Forall(x, ty, concl) => {
+                    let id = self.terms.bind(var, ty);
+                    let concl = concl.substitute(x, &Term::Var(id.as_ref().clone()));
+                    let res = self.check(body, &concl);
+                    self.terms.unbind(&id);
+                    res
+                }
+                _ => fail(Reason::NotIntroducible(goal.clone()), loc),
+            },
+            ProofTerm::Apply { head, args, loc } => match self.constructor(head) {
+                Some(name) if name != "proj1" && name != "proj2" => {
+                    self.check_constructor(name, args, loc, goal)
+                }
+                _ => self.check_inferred(proof, goal),
+            },
+        }
+    }
+
+    /// check application of proof constructor `name` to `args` against `goal`
+    fn check_constructor(
+        &mut self,
+        name: &'static str,
+        args: &[Arg],
+        loc: &Location,
+        goal: &Formula,
+    ) -> Result<()> {
+        match (name, args, goal) {
+            ("conj", [p, q], Formula::And(a, b)) => {
+                let res_a = self.check_arg(p, a);
+                let res_b = self.check_arg(q, b);
+                res_a.combine(res_b).map(|_| ())
+            }
+            ("or_introl", [p], Formula::Or(a, _)) => self.check_arg(p, a),
+            ("or_intror", [q], Formula::Or(_, b)) => self.check_arg(q, b),
+            ("ex_intro", [w, p], Formula::Exists(x, ty, body)) => {
+                let witness = self.witness(w, ty)?;
+                self.check_arg(p, &body.substitute(x, &witness))
+            }
+            ("or_ind", [f, g, h], _) => match self.infer(&proof_of(h)?)? {
+                Formula::Or(a, b) => {
+                    let goal = Box::new(goal.clone());
+                    let res_f = self.check_arg(f, &Formula::Implies(a, goal.clone()));
+                    let res_g = self.check_arg(g, &Formula::Implies(b, goal));
+                    res_f.combine(res_g).map(|_| ())
+                }
+                found => fail(Reason::NotApplicable(found), &h.loc()),
+            },
+            ("ex_ind", [f, h], _) => match self.infer(&proof_of(h)?)? {
+                Formula::Exists(x, ty, body) => {
+                    let goal = Formula::Forall(
+                        x,
+                        ty,
+                        Box::new(Formula::Implies(body, Box::new(goal.clone()))),
+                    );
+                    self.check_arg(f, &goal)
+                }
+                found => fail(Reason::NotApplicable(found), &h.loc()),
+            },
+            ("eq_refl", [], Formula::Atom(term)) if term.is_reflexive() => Ok(()),
+            _ => fail(
+                Reason::Constructor {
+                    name,
+                    goal: Some(goal.clone()),
+                },
+                loc,
+            ),
+        }
+    }
+
+    /// infer proposition of `proof` and compare it with `goal`
+    fn check_inferred(&mut self, proof: &ProofTerm, goal: &Formula) -> Result<()> {
+        let found = self.infer(proof)?;
+        if found == *goal {
+            Ok(())
+        } else {
+            let reason = Reason::Mismatch {
+                expected: goal.clone(),
+                found,
+            };
+            fail(reason, &proof.loc())
+        }
+    }
+
+    /// infer proposition proved by `proof`
+    pub(super) fn infer(&mut self, proof: &ProofTerm) -> Result<Formula> {
+        let (head, args, loc) = match proof {
+            ProofTerm::Apply { head, args, loc } => (head, args, loc),
+            ProofTerm::Intro { loc, .. } => return fail(Reason::NotInferable, loc),
+        };
+        let (mut found, rest) = match self.constructor(head) {
+            Some(name @ ("proj1" | "proj2")) if !args.is_empty() => {
+                match self.infer(&proof_of(&args[0])?)? {
+                    Formula::And(a, _) if name == "proj1" => (*a, &args[1..]),
+                    Formula::And(_, b) => (*b, &args[1..]),
+                    found => return fail(Reason::NotApplicable(found), &args[0].loc()),
+                }
+            }
+            Some(name) => {
+                let reason = Reason::Constructor { name, goal: None };
+                return fail(reason, loc);
+            }
+            None => match self.hypothesis(head.name()) {
+                Some(hyp) => (hyp.clone(), &args[..]),
+                None => match self.terms.theorem(head) {
+                    Some(theorem) => (theorem, &args[..]),
+                    None => {
+                        let reason = Reason::HypothesisNotFound(head.name().to_string());
+                        return fail(reason, &head.loc());
+                    }
+                },
+            },
+        };
+        for arg in rest {
+            found = match found {
+                Formula::Implies(hyp, concl) => {
+                    self.check_arg(arg, &hyp)?;
+                    *concl
+                }
+                Formula::Forall(x, ty, body) => {
+                    let witness = self.witness(arg, &ty)?;
+                    body.substitute(&x, &witness)
+                }
+                found => return fail(Reason::NotApplicable(found), loc),
+            };
+        }
+        Ok(found)
+    }
+}
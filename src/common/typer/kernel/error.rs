@@ -0,0 +1,140 @@
+use super::formula::Formula;
+use crate::utils::error::{ErrorCode, ErrorReport, Message};
+use crate::utils::location::{Located, Location};
+
+// =======================================================================
+// Error Proof
+// =======================================================================
+/// reason why the kernel rejects a proof
+pub enum Reason {
+    /// proof proves `found` where `expected` is required
+    Mismatch { expected: Formula, found: Formula },
+    /// no hypothesis or theorem has this name
+    HypothesisNotFound(String),
+    /// proof of a proposition that is neither an implication
+    /// nor a universal quantification is applied
+    NotApplicable(Formula),
+    /// abstraction against a goal that is neither an implication
+    /// nor a universal quantification
+    NotIntroducible(Formula),
+    /// builtin proof constructor used against a goal it cannot prove,
+    /// or without a goal
+    Constructor {
+        name: &'static str,
+        goal: Option<Formula>,
+    },
+    /// abstraction where its proposition cannot be known from a goal
+    NotInferable,
+    /// only variables can be introduced by an abstraction
+    IntroPattern,
+    /// construct that cannot occur in a proposition
+    Unsupported,
+    /// expression is not a proof term
+    NotProof,
+    /// proof where a term is expected
+    NotTerm,
+}
+
+pub struct ErrorProof {
+    reason: Reason,
+    loc: Location,
+}
+
+impl ErrorProof {
+    pub fn new(reason: Reason, location: &Location) -> Self {
+        Self {
+            reason,
+            loc: location.clone(),
+        }
+    }
+}
+
+/// usage of builtin proof constructor `name`
+fn usage(name: &str) -> &'static str {
+    match name {
+        "conj" => "conj (p : A) (q : B) : A /\\ B",
+        "or_introl" => "or_introl (p : A) : A \\/ B",
+        "or_intror" => "or_intror (q : B) : A \\/ B",
+        "ex_intro" => "ex_intro w (p : P w) : exists x, P x",
+        "or_ind" => "or_ind (f : A --> C) (g : B --> C) (h : A \\/ B) : C",
+        "ex_ind" => "ex_ind (f : forall x, P x --> C) (h : exists x, P x) : C",
+        "proj1" => "proj1 (p : A /\\ B) : A",
+        "proj2" => "proj2 (p : A /\\ B) : B",
+        _ => "eq_refl : t = t",
+    }
+}
+
+impl ErrorCode for ErrorProof {
+    fn code(&self) -> i32 {
+        312
+    }
+}
+
+impl Located for ErrorProof {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorProof {
+    fn head(&self) -> Message {
+        Message::text("Invalid proof.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = match &self.reason {
+            Reason::Mismatch { found, .. } => Message::text("Proof proves ")
+                .append(Message::of_pretty(found).important())
+                .with_text(", not the goal."),
+            Reason::HypothesisNotFound(name) => Message::text("Hypothesis or theorem ")
+                .append(Message::text(name).important())
+                .with_text(" not found."),
+            Reason::NotApplicable(found) => Message::text("Proof of ")
+                .append(Message::of_pretty(found).important())
+                .with_text(" cannot be applied."),
+            Reason::NotIntroducible(goal) => Message::text("Abstraction cannot prove ")
+                .append(Message::of_pretty(goal).important())
+                .with_text(", which is neither an implication nor a universal quantification."),
+            Reason::Constructor {
+                name,
+                goal: Some(goal),
+            } => Message::text("Proof constructor ")
+                .append(Message::text(name).important())
+                .with_text(" cannot prove ")
+                .append(Message::of_pretty(goal).important())
+                .with_text("."),
+            Reason::Constructor { name, goal: None } => Message::text("Proof constructor ")
+                .append(Message::text(name).important())
+                .with_text(" needs all its arguments and a known goal."),
+            Reason::NotInferable => Message::text(
+                "Proposition proved by an abstraction cannot be inferred, it must be checked against a goal.",
+            ),
+            Reason::IntroPattern => Message::text("Only a variable can be introduced."),
+            Reason::Unsupported => {
+                Message::text("This construct cannot occur in a proposition checked by the kernel.")
+            }
+            Reason::NotProof => Message::text("Expression is not a proof term."),
+            Reason::NotTerm => Message::text("Expected a term, found a proof."),
+        };
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        match &self.reason {
+            Reason::Mismatch { expected, found } => Some(
+                Message::text("Expected : ")
+                    .append(Message::of_pretty(expected).important())
+                    .with_line()
+                    .with_text("Found    : ")
+                    .append(Message::of_pretty(found).important()),
+            ),
+            Reason::Constructor { name, .. } => {
+                Some(Message::text("Usage : ").append(Message::text(usage(name)).important()))
+            }
+            Reason::NotProof => Some(Message::text(
+                "Proofs are hypotheses, theorems, abstractions `x => p` and their applications.",
+            )),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use crate::typer::ast::{
+    BinaryOperator, ConstantKind, Expression, Identifier, QuantifierKind, Type, Typed as _,
+    UnaryOperator,
+};
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// ==========================================================================
+// Term
+// ==========================================================================
+/// term inside an atomic proposition
+#[derive(Debug, Clone)]
+pub enum Term {
+    Var(Identifier),
+    Const(ConstantKind),
+    App(Box<Term>, Box<Term>),
+    Binary(BinaryOperator, Box<Term>, Box<Term>),
+    Unary(UnaryOperator, Box<Term>),
+    Tuple(Vec<Term>),
+    /// coercion of a number to a larger number type
+    Coercion(Box<Term>, Type),
+}
+
+/// pairs of bound variables identified by alpha-equivalence, innermost last
+type Renaming<'a> = Vec<(&'a Identifier, &'a Identifier)>;
+
+impl Term {
+    /// convert typed expression, on failure return the location
+    /// of the construct that cannot occur in a proposition
+    pub fn of(expr: &Expression) -> Result<Self, Location> {
+        match expr {
+            Expression::Variable(var) => Ok(Term::Var(var.identifier().clone())),
+            Expression::Constant(c) => Ok(Term::Const(c.kind().clone())),
+            Expression::Application(app) => Ok(Term::App(
+                Box::new(Term::of(app.fun())?),
+                Box::new(Term::of(app.arg())?),
+            )),
+            Expression::Binary(b) if !b.op().is_connective() => Ok(Term::Binary(
+                b.op(),
+                Box::new(Term::of(b.left())?),
+                Box::new(Term::of(b.right())?),
+            )),
+            Expression::Unary(u) => Ok(Term::Unary(u.op(), Box::new(Term::of(u.expr())?))),
+            Expression::Tuple(t) => t
+                .elems()
+                .iter()
+                .map(Term::of)
+                .collect::<Result<_, _>>()
+                .map(Term::Tuple),
+            Expression::Coercion(c) => Ok(Term::Coercion(
+                Box::new(Term::of(c.expr())?),
+                c.ty().clone(),
+            )),
+            _ => Err(expr.loc()),
+        }
+    }
+
+    /// replace free variable `id` by `term`
+    pub fn substitute(&self, id: &Identifier, term: &Term) -> Term {
+        match self {
+            Term::Var(var) if var == id => term.clone(),
+            Term::Var(_) | Term::Const(_) => self.clone(),
+            Term::App(fun, arg) => Term::App(
+                Box::new(fun.substitute(id, term)),
+                Box::new(arg.substitute(id, term)),
+            ),
+            Term::Binary(op, left, right) => Term::Binary(
+                *op,
+                Box::new(left.substitute(id, term)),
+                Box::new(right.substitute(id, term)),
+            ),
+            Term::Unary(op, operand) => Term::Unary(*op, Box::new(operand.substitute(id, term))),
+            Term::Tuple(elems) => {
+                Term::Tuple(elems.iter().map(|elem| elem.substitute(id, term)).collect())
+            }
+            Term::Coercion(number, ty) => {
+                Term::Coercion(Box::new(number.substitute(id, term)), ty.clone())
+            }
+        }
+    }
+
+    /// term is `t = t`, which holds by reflexivity
+    pub fn is_reflexive(&self) -> bool {
+        match self {
+            Term::Binary(BinaryOperator::Eq, left, right) => left.eq_renamed(right, &Vec::new()),
+            _ => false,
+        }
+    }
+
+    /// terms are equal, up to the renaming of bound variables
+    fn eq_renamed(&self, other: &Term, renaming: &Renaming) -> bool {
+        match (self, other) {
+            (Term::Var(x), Term::Var(y)) => {
+                match renaming.iter().rev().find(|(bx, by)| *bx == x || *by == y) {
+                    Some((bx, by)) => *bx == x && *by == y,
+                    None => x == y,
+                }
+            }
+            (Term::Const(c1), Term::Const(c2)) => c1 == c2,
+            (Term::App(f1, a1), Term::App(f2, a2)) => {
+                f1.eq_renamed(f2, renaming) && a1.eq_renamed(a2, renaming)
+            }
+            (Term::Binary(op1, l1, r1), Term::Binary(op2, l2, r2)) => {
+                op1 == op2 && l1.eq_renamed(l2, renaming) && r1.eq_renamed(r2, renaming)
+            }
+            (Term::Unary(op1, e1), Term::Unary(op2, e2)) => {
+                op1 == op2 && e1.eq_renamed(e2, renaming)
+            }
+            (Term::Tuple(elems1), Term::Tuple(elems2)) => {
+                elems1.len() == elems2.len()
+                    && elems1
+                        .iter()
+                        .zip(elems2)
+                        .all(|(e1, e2)| e1.eq_renamed(e2, renaming))
+            }
+            (Term::Coercion(n1, ty1), Term::Coercion(n2, ty2)) => {
+                ty1.unfold() == ty2.unfold() && n1.eq_renamed(n2, renaming)
+            }
+            _ => false,
+        }
+    }
+
+    /// precedence level of term, as for expressions
+    fn precedence(&self) -> u8 {
+        match self {
+            Term::Var(_) | Term::Const(_) | Term::Coercion(_, _) => 0,
+            Term::App(_, _) => 1,
+            Term::Binary(op, _, _) => op.precedence(),
+            Term::Unary(op, _) => op.precedence(),
+            Term::Tuple(_) => 10,
+        }
+    }
+
+    /// pretty print term, with parenthesis if precedence is greater than `prec`
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        if self.precedence() > prec {
+            Doc::nil()
+                .append(theme.operator(&"("))
+                .append(self.pretty(theme))
+                .append(theme.operator(&")"))
+        } else {
+            self.pretty(theme)
+        }
+    }
+}
+
+impl Pretty for Term {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Term::Var(id) => theme.expr_var(&id.name()),
+            Term::Const(c) => c.pretty(theme),
+            Term::App(fun, arg) => Doc::nil()
+                .append(fun.pretty_precedence(1, theme))
+                .append(Doc::line().append(arg.pretty_precedence(0, theme)).nest(2))
+                .group(),
+            Term::Binary(op, left, right) => {
+                let (prec_left, prec_right) = op.operands_precedence();
+                Doc::nil()
+                    .append(left.pretty_precedence(prec_left, theme))
+                    .append(Doc::space())
+                    .append(op.pretty(theme))
+                    .append(
+                        Doc::line()
+                            .append(right.pretty_precedence(prec_right, theme))
+                            .nest(2),
+                    )
+                    .group()
+            }
+            Term::Unary(op, operand) => Doc::nil()
+                .append(op.pretty(theme))
+                .append(Doc::space())
+                .append(operand.pretty_precedence(op.operand_precedence(), theme)),
+            Term::Tuple(elems) => Doc::intersperse(
+                elems.iter().map(|elem| elem.pretty_precedence(9, theme)),
+                theme.operator(&",").append(Doc::line()),
+            )
+            .group(),
+            Term::Coercion(number, ty) => Doc::nil()
+                .append(theme.operator(&"("))
+                .append(number.pretty_precedence(9, theme))
+                .append(Doc::space())
+                .append(theme.operator(&":>"))
+                .append(Doc::space())
+                .append(ty.pretty(theme))
+                .append(theme.operator(&")"))
+                .group(),
+        }
+    }
+}
+
+// ==========================================================================
+// Formula
+// ==========================================================================
+/// proposition as seen by the kernel, bound variables keep their identifiers
+/// which are unique, so substitution never captures a variable
+#[derive(Debug, Clone)]
+pub enum Formula {
+    /// atomic proposition, a boolean term `b` stands for `b = true`
+    Atom(Term),
+    And(Box<Formula>, Box<Formula>),
+    Or(Box<Formula>, Box<Formula>),
+    Implies(Box<Formula>, Box<Formula>),
+    Forall(Identifier, Type, Box<Formula>),
+    Exists(Identifier, Type, Box<Formula>),
+}
+
+impl Formula {
+    /// convert typed proposition, on failure return the location
+    /// of the construct that cannot occur in a proposition
+    pub fn of(expr: &Expression) -> Result<Self, Location> {
+        match expr {
+            Expression::Binary(b) if b.op().is_connective() => {
+                let left = Box::new(Formula::of(b.left())?);
+                let right = Box::new(Formula::of(b.right())?);
+                Ok(match b.op() {
+                    BinaryOperator::Conj => Formula::And(left, right),
+                    BinaryOperator::Disj => Formula::Or(left, right),
+                    _ => Formula::Implies(left, right),
+                })
+            }
+            Expression::Quantifier(q) => {
+                let body = Formula::of(q.body())?;
+                Ok(q.vars().iter().rev().fold(body, |body, var| {
+                    let id = var.identifier().clone();
+                    let ty = q.ty_var().clone();
+                    match q.kind() {
+                        QuantifierKind::Forall => Formula::Forall(id, ty, Box::new(body)),
+                        QuantifierKind::Exists => Formula::Exists(id, ty, Box::new(body)),
+                    }
+                }))
+            }
+            Expression::Coercion(c) if c.ty().is_prop() => Formula::of(c.expr()),
+            _ => Term::of(expr).map(Formula::Atom),
+        }
+    }
+
+    /// replace free variable `id` by `term`
+    pub fn substitute(&self, id: &Identifier, term: &Term) -> Formula {
+        let sub = |f: &Formula| Box::new(f.substitute(id, term));
+        match self {
+            Formula::Atom(t) => Formula::Atom(t.substitute(id, term)),
+            Formula::And(a, b) => Formula::And(sub(a), sub(b)),
+            Formula::Or(a, b) => Formula::Or(sub(a), sub(b)),
+            Formula::Implies(a, b) => Formula::Implies(sub(a), sub(b)),
+            Formula::Forall(x, _, _) | Formula::Exists(x, _, _) if x == id => self.clone(),
+            Formula::Forall(x, ty, body) => Formula::Forall(x.clone(), ty.clone(), sub(body)),
+            Formula::Exists(x, ty, body) => Formula::Exists(x.clone(), ty.clone(), sub(body)),
+        }
+    }
+
+    /// formulas are equal, up to the renaming of bound variables
+    fn eq_renamed<'a>(&'a self, other: &'a Formula, renaming: &mut Renaming<'a>) -> bool {
+        match (self, other) {
+            (Formula::Atom(t1), Formula::Atom(t2)) => t1.eq_renamed(t2, renaming),
+            (Formula::And(a1, b1), Formula::And(a2, b2))
+            | (Formula::Or(a1, b1), Formula::Or(a2, b2))
+            | (Formula::Implies(a1, b1), Formula::Implies(a2, b2)) => {
+                a1.eq_renamed(a2, renaming) && b1.eq_renamed(b2, renaming)
+            }
+            (Formula::Forall(x1, ty1, body1), Formula::Forall(x2, ty2, body2))
+            | (Formula::Exists(x1, ty1, body1), Formula::Exists(x2, ty2, body2)) => {
+                if !ty1.is_compatible(ty2) {
+                    return false;
+                }
+                renaming.push((x1, x2));
+                let eq = body1.eq_renamed(body2, renaming);
+                renaming.pop();
+                eq
+            }
+            _ => false,
+        }
+    }
+
+    /// precedence level of formula, as for expressions
+    fn precedence(&self) -> u8 {
+        match self {
+            Formula::Atom(t) => t.precedence(),
+            Formula::And(_, _) => BinaryOperator::Conj.precedence(),
+            Formula::Or(_, _) => BinaryOperator::Disj.precedence(),
+            Formula::Implies(_, _) => BinaryOperator::Implies.precedence(),
+            Formula::Forall(_, _, _) | Formula::Exists(_, _, _) => 11,
+        }
+    }
+
+    /// pretty print formula, with parenthesis if precedence is greater than `prec`
+    fn pretty_precedence(&self, prec: u8, theme: &Theme) -> Doc<'_> {
+        if self.precedence() > prec {
+            Doc::nil()
+                .append(theme.operator(&"("))
+                .append(self.pretty(theme))
+                .append(theme.operator(&")"))
+        } else {
+            self.pretty(theme)
+        }
+    }
+
+    /// pretty print quantifier, consecutive binders of same kind and type are merged
+    fn pretty_quantifier<'a>(&'a self, theme: &Theme) -> Doc<'a> {
+        let (keyword, ty) = match self {
+            Formula::Forall(_, ty, _) => ("forall", ty),
+            Formula::Exists(_, ty, _) => ("exists", ty),
+            _ => return self.pretty(theme),
+        };
+        let mut vars = Vec::new();
+        let mut body = self;
+        loop {
+            match body {
+                Formula::Forall(x, ty_x, inner) if keyword == "forall" && ty_x == ty => {
+                    vars.push(x);
+                    body = inner;
+                }
+                Formula::Exists(x, ty_x, inner) if keyword == "exists" && ty_x == ty => {
+                    vars.push(x);
+                    body = inner;
+                }
+                _ => break,
+            }
+        }
+        Doc::nil()
+            .append(theme.keyword(&keyword))
+            .append(Doc::space())
+            .append(Doc::intersperse(
+                vars.iter().map(|x| theme.def_var(&x.name())),
+                Doc::space(),
+            ))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::space())
+            .append(ty.pretty(theme))
+            .append(theme.operator(&","))
+            .append(Doc::line().append(body.pretty(theme)).nest(2))
+            .group()
+    }
+}
+
+/// pretty print connective `op` between `left` and `right`
+fn pretty_binary<'a>(
+    op: &'a BinaryOperator,
+    left: &'a Formula,
+    right: &'a Formula,
+    theme: &Theme,
+) -> Doc<'a> {
+    let (prec_left, prec_right) = op.operands_precedence();
+    Doc::nil()
+        .append(left.pretty_precedence(prec_left, theme))
+        .append(Doc::space())
+        .append(op.pretty(theme))
+        .append(
+            Doc::line()
+                .append(right.pretty_precedence(prec_right, theme))
+                .nest(2),
+        )
+        .group()
+}
+
+impl PartialEq for Formula {
+    /// formulas are equal up to the renaming of bound variables
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_renamed(other, &mut Vec::new())
+    }
+}
+
+impl Pretty for Formula {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Formula::Atom(t) => t.pretty(theme),
+            Formula::And(a, b) => pretty_binary(&BinaryOperator::Conj, a, b, theme),
+            Formula::Or(a, b) => pretty_binary(&BinaryOperator::Disj, a, b, theme),
+            Formula::Implies(a, b) => pretty_binary(&BinaryOperator::Implies, a, b, theme),
+            Formula::Forall(_, _, _) | Formula::Exists(_, _, _) => self.pretty_quantifier(theme),
+        }
+    }
+}
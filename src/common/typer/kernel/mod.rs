@@ -0,0 +1,245 @@
+mod check;
+mod error;
+mod formula;
+mod proof;
+
+pub use error::ErrorProof;
+pub use formula::{Formula, Term};
+pub use proof::{Arg, ProofTerm};
+
+use super::ast::{self, Identifier, Type};
+use super::error::Error;
+use crate::parser::cst;
+use crate::utils::location::{Located, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use check::Checker;
+use std::rc::Rc;
+
+// ==========================================================================
+// Terms
+// ==========================================================================
+/// access of the kernel to terms and theorems of the environment
+pub trait Terms {
+    /// bind term variable `var` of type `ty` while checking a proof
+    fn bind(&mut self, var: &cst::pattern::Variable, ty: &Type) -> Rc<Identifier>;
+
+    /// remove term variable bound by [`Terms::bind`]
+    fn unbind(&mut self, id: &Identifier);
+
+    /// type term occurring in a proof
+    fn term(&mut self, expr: &cst::Expression) -> Result<ast::Expression, Vec<Error>>;
+
+    /// get statement of theorem `var`
+    fn theorem(&mut self, var: &cst::expression::Variable) -> Option<Formula>;
+}
+
+// ==========================================================================
+// Theorem
+// ==========================================================================
+/// proposition proved by the kernel, only built by [`check`]
+pub struct Theorem {
+    /// name of theorem
+    name: Rc<Identifier>,
+    /// statement, as typed
+    statement: ast::Expression,
+    /// statement, as checked by the kernel
+    formula: Formula,
+    /// location of theorem
+    loc: Location,
+}
+
+impl Theorem {
+    /// get name of theorem
+    pub fn name(&self) -> &Rc<Identifier> {
+        &self.name
+    }
+
+    /// get statement of theorem
+    pub fn statement(&self) -> &ast::Expression {
+        &self.statement
+    }
+
+    /// get statement of theorem, as checked by the kernel
+    pub fn formula(&self) -> &Formula {
+        &self.formula
+    }
+}
+
+impl std::fmt::Debug for Theorem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Theorem")
+            .field("name", &self.name)
+            .field("formula", &self.formula)
+            .finish()
+    }
+}
+
+impl Located for Theorem {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl Pretty for Theorem {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.def_var(&self.name.name()))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::line().append(self.statement.pretty(theme)).nest(2))
+            .group()
+    }
+}
+
+// ==========================================================================
+// Obligation
+// ==========================================================================
+/// proposition a definition satisfies when it meets its contract, left to prove
+pub struct Obligation {
+    /// name of definition
+    name: Rc<Identifier>,
+    /// `forall x1 ⋯ xn, requires --> ensures`, with the result replaced by `name x1 ⋯ xn`
+    formula: Formula,
+    /// location of definition
+    loc: Location,
+}
+
+impl Obligation {
+    /// get proposition left to prove
+    pub fn formula(&self) -> &Formula {
+        &self.formula
+    }
+}
+
+impl Located for Obligation {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl Pretty for Obligation {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.keyword(&"Obligation"))
+            .append(Doc::space())
+            .append(theme.def_var(&self.name.name()))
+            .append(Doc::space())
+            .append(theme.operator(&":"))
+            .append(Doc::line().append(self.formula.pretty(theme)).nest(2))
+            .group()
+    }
+}
+
+/// hypotheses of a proof, innermost last
+pub type Hypotheses = [(String, Formula)];
+
+/// read typed proposition as a formula
+pub fn formula(statement: &ast::Expression) -> Result<Formula, Vec<Error>> {
+    Formula::of(statement).map_err(|loc| {
+        let err = ErrorProof::new(error::Reason::Unsupported, &loc);
+        vec![Error::from(err)]
+    })
+}
+
+/// proof obligation of definition `def`, if its contract has postconditions
+pub fn obligation(def: &ast::ExpressionDefinition) -> Result<Option<Obligation>, Vec<Error>> {
+    let unsupported = |loc: &Location| {
+        let err = ErrorProof::new(error::Reason::Unsupported, loc);
+        vec![Error::from(err)]
+    };
+    let Some(contract) = def.contract() else {
+        return Ok(None);
+    };
+    let Some(result) = contract.result() else {
+        return Ok(None);
+    };
+    let name = match def.pattern() {
+        ast::Pattern::Variable(var) => Rc::new(var.identifier().clone()),
+        pattern => return Err(unsupported(&pattern.loc())),
+    };
+    let mut params = Vec::new();
+    let mut body = def.body();
+    while let ast::Expression::Abstraction(abs) = body {
+        match abs.pattern().as_ref() {
+            ast::Pattern::Variable(var) => params.push((var.identifier().clone(), abs.ty_arg())),
+            pattern => return Err(unsupported(&pattern.loc())),
+        }
+        body = abs.body();
+    }
+    let call = params
+        .iter()
+        .fold(Term::Var(name.as_ref().clone()), |fun, (id, _)| {
+            Term::App(Box::new(fun), Box::new(Term::Var(id.clone())))
+        });
+    let ensures = contract
+        .ensures()
+        .map(|clause| formula(clause.expr()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|ensures| ensures.substitute(result, &call))
+        .reduce(|left, right| Formula::And(Box::new(left), Box::new(right)))
+        .expect("contract with a result has postconditions");
+    let requires = contract
+        .requires()
+        .map(|clause| formula(clause.expr()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let formula = requires.into_iter().rev().fold(ensures, |concl, hyp| {
+        Formula::Implies(Box::new(hyp), Box::new(concl))
+    });
+    let formula = params.into_iter().rev().fold(formula, |body, (id, ty)| {
+        Formula::Forall(id, ty.clone(), Box::new(body))
+    });
+    Ok(Some(Obligation {
+        name,
+        formula,
+        loc: def.loc(),
+    }))
+}
+
+/// infer proposition proved by `proof` under `hypotheses`
+pub fn infer(
+    terms: &mut impl Terms,
+    hypotheses: &Hypotheses,
+    proof: &ProofTerm,
+) -> Result<Formula, Vec<Error>> {
+    Checker::new(terms, hypotheses.to_vec()).infer(proof)
+}
+
+/// check that `proof` proves `goal` under `hypotheses`
+pub fn prove(
+    terms: &mut impl Terms,
+    hypotheses: &Hypotheses,
+    proof: &ProofTerm,
+    goal: &Formula,
+) -> Result<(), Vec<Error>> {
+    Checker::new(terms, hypotheses.to_vec()).check(proof, goal)
+}
+
+/// type `expr` as a witness of type `ty` and read it as a term
+pub fn witness(
+    terms: &mut impl Terms,
+    expr: &cst::Expression,
+    ty: &Type,
+) -> Result<Term, Vec<Error>> {
+    Checker::new(terms, Vec::new()).witness(&Arg::Expr(Box::new(expr.clone())), ty)
+}
+
+/// check that `proof` proves `statement`, a typed proposition,
+/// and make it the theorem `name`
+pub fn check(
+    terms: &mut impl Terms,
+    name: Rc<Identifier>,
+    statement: ast::Expression,
+    proof: &ProofTerm,
+    loc: Location,
+) -> Result<Theorem, Vec<Error>> {
+    let formula = formula(&statement)?;
+    prove(terms, &[], proof, &formula)?;
+    Ok(Theorem {
+        name,
+        statement,
+        formula,
+        loc,
+    })
+}
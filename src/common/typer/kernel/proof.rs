@@ -0,0 +1,139 @@
+use super::error::{ErrorProof, Reason};
+use crate::parser::cst;
+use crate::parser::cst::expression::{
+    Expression0, Expression1, Expression2, Expression3, Expression4, Expression5, Variable,
+};
+use crate::parser::cst::pattern::{Pattern0, Pattern1, Pattern2};
+use crate::typer::error::Error;
+use crate::utils::location::{Located, Location};
+
+// ==========================================================================
+// Proof Term
+// ==========================================================================
+/// argument of an applied proof, read as a proof or as a term
+/// depending on the proposition it is applied to
+#[derive(Debug, Clone)]
+pub enum Arg {
+    Expr(Box<cst::Expression>),
+    Proof(ProofTerm),
+}
+
+/// proof term checked by the kernel
+#[derive(Debug, Clone)]
+pub enum ProofTerm {
+    /// `x => p`, introduction of an implication or a universal quantification
+    Intro {
+        var: cst::pattern::Variable,
+        body: Box<ProofTerm>,
+        loc: Location,
+    },
+    /// `h a1 ⋯ an`, application of a hypothesis, a theorem or a proof constructor
+    Apply {
+        head: Variable,
+        args: Vec<Arg>,
+        loc: Location,
+    },
+}
+
+impl ProofTerm {
+    /// read proof term from an expression
+    pub fn of(expr: &Expression5) -> Result<Self, Vec<Error>> {
+        let not_proof = || Err(vec![ErrorProof::new(Reason::NotProof, &expr.loc()).into()]);
+        match expr {
+            Expression5::Abstraction { pattern, body, .. } => {
+                let Some(var) = intro_var(pattern) else {
+                    let err = ErrorProof::new(Reason::IntroPattern, &pattern.loc());
+                    return Err(vec![err.into()]);
+                };
+                Ok(ProofTerm::Intro {
+                    var: var.clone(),
+                    body: Box::new(ProofTerm::of(body)?),
+                    loc: expr.loc(),
+                })
+            }
+            Expression5::Expression4(Expression4::Expression3(Expression3::Expression2(
+                Expression2::Expression1(expr1),
+            ))) => {
+                let mut args = Vec::new();
+                let mut fun = expr1;
+                while let Expression1::Application { fun: inner, arg } = fun {
+                    args.push(Arg::Expr(Box::new(lift(arg.clone()))));
+                    fun = inner;
+                }
+                args.reverse();
+                match fun {
+                    Expression1::Expression0(Expression0::Variable(head)) => Ok(ProofTerm::Apply {
+                        head: head.clone(),
+                        args,
+                        loc: expr.loc(),
+                    }),
+                    Expression1::Expression0(Expression0::Paren(inner)) => {
+                        match ProofTerm::of(inner.inner())? {
+                            ProofTerm::Apply {
+                                head,
+                                args: mut head_args,
+                                ..
+                            } => {
+                                head_args.extend(args);
+                                Ok(ProofTerm::Apply {
+                                    head,
+                                    args: head_args,
+                                    loc: expr.loc(),
+                                })
+                            }
+                            intro if args.is_empty() => Ok(intro),
+                            _ => not_proof(),
+                        }
+                    }
+                    _ => not_proof(),
+                }
+            }
+            _ => not_proof(),
+        }
+    }
+
+    /// proof term `head args`, where `head` names a hypothesis, a theorem or a proof constructor
+    pub fn apply(head: &str, args: Vec<Arg>, loc: Location) -> Self {
+        ProofTerm::Apply {
+            head: Variable::new(
+                cst::expression::VariableT::from(head.to_string()),
+                loc.clone(),
+            ),
+            args,
+            loc,
+        }
+    }
+}
+
+impl Located for ProofTerm {
+    fn loc(&self) -> Location {
+        match self {
+            ProofTerm::Intro { loc, .. } | ProofTerm::Apply { loc, .. } => loc.clone(),
+        }
+    }
+}
+
+impl Located for Arg {
+    fn loc(&self) -> Location {
+        match self {
+            Arg::Expr(expr) => expr.loc(),
+            Arg::Proof(proof) => proof.loc(),
+        }
+    }
+}
+
+/// read expression0 as an expression
+fn lift(expr: Expression0) -> Expression5 {
+    Expression5::Expression4(Expression4::Expression3(Expression3::Expression2(
+        Expression2::Expression1(Expression1::Expression0(expr)),
+    )))
+}
+
+/// variable introduced by pattern, if it is a variable
+fn intro_var(pattern: &cst::Pattern) -> Option<&cst::pattern::Variable> {
+    match pattern {
+        Pattern2::Pattern1(Pattern1::Pattern0(Pattern0::Variable(var))) => Some(var),
+        Pattern2::Pattern1(Pattern1::Pattern0(Pattern0::Paren(inner))) => intro_var(inner.inner()),
+        _ => None,
+    }
+}
@@ -0,0 +1,235 @@
+use super::ast::{self, Identifier, Type};
+use super::env::Env;
+use super::error::{Error, ErrorNonExhaustive, ErrorRedundantPattern};
+use crate::utils::location::{Located as _, Location};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Simplified Pattern
+// ==========================================================================
+/// pattern without binders, used as row of the matrix and as witness
+#[derive(Debug, Clone)]
+pub enum Pat {
+    /// matches everything
+    Wild,
+    /// matches a constructor and its arguments
+    Constructor(Rc<Identifier>, Vec<Pat>),
+    /// matches a tuple component-wise
+    Tuple(Vec<Pat>),
+}
+
+impl From<&ast::Pattern> for Pat {
+    fn from(pattern: &ast::Pattern) -> Self {
+        match pattern {
+            ast::Pattern::Variable(_) => Pat::Wild,
+            ast::Pattern::Tuple(tuple) => {
+                Pat::Tuple(tuple.patterns().iter().map(Pat::from).collect())
+            }
+            ast::Pattern::Constructor(ctor) => Pat::Constructor(
+                ctor.identifier().clone(),
+                ctor.args().iter().map(Pat::from).collect(),
+            ),
+        }
+    }
+}
+
+impl Pat {
+    /// pretty print pattern, with parenthesis if it is not atomic and `atomic` is required
+    fn pretty_atomic(&self, atomic: bool, theme: &Theme) -> Doc<'_> {
+        let is_atomic = match self {
+            Pat::Wild => true,
+            Pat::Constructor(_, args) => args.is_empty(),
+            Pat::Tuple(_) => false,
+        };
+        if atomic && !is_atomic {
+            Doc::nil()
+                .append(theme.operator(&"("))
+                .append(self.pretty(theme))
+                .append(theme.operator(&")"))
+        } else {
+            self.pretty(theme)
+        }
+    }
+}
+
+impl Pretty for Pat {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        match self {
+            Pat::Wild => theme.def_var(&"_"),
+            Pat::Constructor(id, args) => {
+                args.iter().fold(theme.expr_var(&id.name()), |doc, arg| {
+                    doc.append(Doc::space())
+                        .append(arg.pretty_atomic(true, theme))
+                })
+            }
+            Pat::Tuple(pats) => Doc::intersperse(
+                pats.iter().map(|pat| match pat {
+                    Pat::Tuple(_) => pat.pretty_atomic(true, theme),
+                    _ => pat.pretty_atomic(false, theme),
+                }),
+                theme.operator(&",").append(Doc::space()),
+            ),
+        }
+    }
+}
+
+// ==========================================================================
+// Usefulness
+// ==========================================================================
+type Row = Vec<Pat>;
+
+/// rows of `rows` specialized by a constructor of arity `arity`
+fn specialize_constructor(rows: &[Row], id: &Identifier, arity: usize) -> Vec<Row> {
+    rows.iter()
+        .filter_map(|row| match &row[0] {
+            Pat::Constructor(ctor, args) if ctor.as_ref() == id => {
+                Some(args.iter().chain(&row[1..]).cloned().collect())
+            }
+            Pat::Constructor(_, _) | Pat::Tuple(_) => None,
+            Pat::Wild => Some(
+                std::iter::repeat_n(Pat::Wild, arity)
+                    .chain(row[1..].iter().cloned())
+                    .collect(),
+            ),
+        })
+        .collect()
+}
+
+/// rows of `rows` specialized by a tuple of size `size`
+fn specialize_tuple(rows: &[Row], size: usize) -> Vec<Row> {
+    rows.iter()
+        .filter_map(|row| match &row[0] {
+            Pat::Tuple(pats) => Some(pats.iter().chain(&row[1..]).cloned().collect()),
+            Pat::Constructor(_, _) => None,
+            Pat::Wild => Some(
+                std::iter::repeat_n(Pat::Wild, size)
+                    .chain(row[1..].iter().cloned())
+                    .collect(),
+            ),
+        })
+        .collect()
+}
+
+/// rows of `rows` starting with a wildcard, without it
+fn default_rows(rows: &[Row]) -> Vec<Row> {
+    rows.iter()
+        .filter(|row| matches!(row[0], Pat::Wild))
+        .map(|row| row[1..].to_vec())
+        .collect()
+}
+
+/// rebuild the head of a witness from its `arity` first components
+fn rebuild(witness: Vec<Pat>, arity: usize, head: impl FnOnce(Vec<Pat>) -> Pat) -> Vec<Pat> {
+    let mut args = witness;
+    let rest = args.split_off(arity);
+    std::iter::once(head(args)).chain(rest).collect()
+}
+
+/// values matched by `query` of types `tys` that are not matched by any row of `rows`,
+/// returns a witness of such values if it exists
+fn useful(env: &Env, rows: &[Row], query: &[Pat], tys: &[Type]) -> Option<Vec<Pat>> {
+    let Some((head, query_rest)) = query.split_first() else {
+        return rows.is_empty().then(Vec::new);
+    };
+    let (ty, tys_rest) = tys.split_first().expect("a type by column");
+
+    match (head, ty.unfold()) {
+        (Pat::Constructor(id, args), _) => {
+            let ctor_tys = match (env.get_constructor(id), ty.as_union()) {
+                (Some(ctor), Some(union)) => ctor.args_of(union),
+                (Some(ctor), None) => ctor.args().to_vec(),
+                (None, _) => Vec::new(),
+            };
+            let rows = specialize_constructor(rows, id, args.len());
+            let query = args.iter().chain(query_rest).cloned().collect::<Vec<_>>();
+            let tys = ctor_tys
+                .into_iter()
+                .chain(tys_rest.iter().cloned())
+                .collect::<Vec<_>>();
+            let witness = useful(env, &rows, &query, &tys)?;
+            Some(rebuild(witness, args.len(), |args| {
+                Pat::Constructor(id.clone(), args)
+            }))
+        }
+        (Pat::Tuple(pats), Type::Product(product)) => {
+            let rows = specialize_tuple(rows, pats.len());
+            let query = pats.iter().chain(query_rest).cloned().collect::<Vec<_>>();
+            let tys = product
+                .tys()
+                .iter()
+                .chain(tys_rest)
+                .cloned()
+                .collect::<Vec<_>>();
+            let witness = useful(env, &rows, &query, &tys)?;
+            Some(rebuild(witness, pats.len(), Pat::Tuple))
+        }
+        (Pat::Wild, Type::Product(product)) => {
+            let pats = vec![Pat::Wild; product.tys().len()];
+            let query = std::iter::once(Pat::Tuple(pats))
+                .chain(query_rest.iter().cloned())
+                .collect::<Vec<_>>();
+            useful(env, rows, &query, tys)
+        }
+        (Pat::Wild, Type::Union(union)) => {
+            let ctors = env.constructors_of(union.name()).collect::<Vec<_>>();
+            let is_used = |id: &Identifier| {
+                rows.iter()
+                    .any(|row| matches!(&row[0], Pat::Constructor(ctor, _) if ctor.as_ref() == id))
+            };
+            let missing = ctors.iter().find(|ctor| !is_used(ctor.id()));
+            match missing {
+                None => ctors.iter().find_map(|ctor| {
+                    let pats = vec![Pat::Wild; ctor.args().len()];
+                    let query = std::iter::once(Pat::Constructor(ctor.id().clone(), pats))
+                        .chain(query_rest.iter().cloned())
+                        .collect::<Vec<_>>();
+                    useful(env, rows, &query, tys)
+                }),
+                Some(missing) => {
+                    let witness = useful(env, &default_rows(rows), query_rest, tys_rest)?;
+                    let head = if ctors.iter().any(|ctor| is_used(ctor.id())) {
+                        let pats = vec![Pat::Wild; missing.args().len()];
+                        Pat::Constructor(missing.id().clone(), pats)
+                    } else {
+                        Pat::Wild
+                    };
+                    Some(std::iter::once(head).chain(witness).collect())
+                }
+            }
+        }
+        (Pat::Wild, _) | (Pat::Tuple(_), _) => {
+            let witness = useful(env, &default_rows(rows), query_rest, tys_rest)?;
+            Some(std::iter::once(Pat::Wild).chain(witness).collect())
+        }
+    }
+}
+
+/// check that `patterns` of type `ty` are exhaustive and without redundancy
+/// ("Warnings for pattern matching", L. Maranget)
+pub fn check<'a>(
+    env: &Env,
+    ty: &Type,
+    patterns: impl IntoIterator<Item = &'a ast::Pattern>,
+    loc: Location,
+) -> Result<(), Vec<Error>> {
+    let tys = [ty.clone()];
+    let mut rows = Vec::new();
+    let mut errs = Vec::new();
+    for pattern in patterns {
+        let row = vec![Pat::from(pattern)];
+        if useful(env, &rows, &row, &tys).is_none() {
+            errs.push(ErrorRedundantPattern::new(&pattern.loc()).into());
+        }
+        rows.push(row);
+    }
+    if let Some(mut witness) = useful(env, &rows, &[Pat::Wild], &tys) {
+        errs.push(ErrorNonExhaustive::new(witness.remove(0), &loc).into());
+    }
+    if errs.is_empty() {
+        Ok(())
+    } else {
+        Err(errs)
+    }
+}
@@ -1,6 +1,12 @@
 pub mod ast;
+mod class;
 pub mod env;
 mod error;
+pub mod interface;
+pub mod kernel;
+mod matching;
+pub mod tactic;
+mod termination;
 mod type_ast;
 
-pub use type_ast::Typer;
+pub use type_ast::{Exports, Typer};
@@ -0,0 +1,116 @@
+use crate::parser::cst::command::TacticKeywordT;
+use crate::typer::kernel::Formula;
+use crate::utils::error::{ErrorCode, ErrorReport, Message};
+use crate::utils::location::{Located, Location};
+
+// =======================================================================
+// Error Tactic
+// =======================================================================
+/// reason why a tactic or a proof command fails
+pub enum Reason {
+    /// tactic or proof command outside of a proof
+    NoProof,
+    /// theorem stated while proof of theorem `name` is in progress
+    InProgress(String),
+    /// tactic while all goals are proved
+    NoGoal,
+    /// tactic cannot prove the shape of the goal
+    NotFit(TacticKeywordT, Formula),
+    /// applied proof does not conclude the goal
+    Mismatch {
+        expected: Box<Formula>,
+        found: Box<Formula>,
+    },
+    /// `Qed` while goals are left
+    Incomplete(usize),
+}
+
+pub struct ErrorTactic {
+    reason: Reason,
+    loc: Location,
+}
+
+impl ErrorTactic {
+    pub fn new(reason: Reason, location: &Location) -> Self {
+        Self {
+            reason,
+            loc: location.clone(),
+        }
+    }
+}
+
+/// goals proved by tactic
+fn usage(tactic: &TacticKeywordT) -> &'static str {
+    match tactic {
+        TacticKeywordT::Intro => "intro x : A --> B  or  forall x, P x",
+        TacticKeywordT::Exists => "exists w : exists x, P x",
+        TacticKeywordT::Split => "split : A /\\ B",
+        TacticKeywordT::Left | TacticKeywordT::Right => "left, right : A \\/ B",
+        TacticKeywordT::Reflexivity => "reflexivity : t = t",
+        TacticKeywordT::Apply | TacticKeywordT::Exact => "apply p, exact p : any goal",
+    }
+}
+
+impl ErrorCode for ErrorTactic {
+    fn code(&self) -> i32 {
+        313
+    }
+}
+
+impl Located for ErrorTactic {
+    fn loc(&self) -> Location {
+        self.loc.clone()
+    }
+}
+
+impl ErrorReport for ErrorTactic {
+    fn head(&self) -> Message {
+        Message::text("Tactic failed.")
+    }
+
+    fn text(&self) -> Option<Message> {
+        let msg = match &self.reason {
+            Reason::NoProof => Message::text("No proof in progress."),
+            Reason::InProgress(name) => Message::text("Proof of ")
+                .append(Message::text(name).important())
+                .with_text(" is still in progress."),
+            Reason::NoGoal => Message::text("No goal left to prove."),
+            Reason::NotFit(tactic, goal) => Message::text("Tactic ")
+                .append(Message::text(tactic.name()).important())
+                .with_text(" cannot prove ")
+                .append(Message::of_pretty(goal).important())
+                .with_text("."),
+            Reason::Mismatch { found, .. } => Message::text("Proof of ")
+                .append(Message::of_pretty(found.as_ref()).important())
+                .with_text(" does not conclude the goal."),
+            Reason::Incomplete(1) => Message::text("Proof is incomplete, 1 goal is left."),
+            Reason::Incomplete(left) => Message::text("Proof is incomplete, ")
+                .append(Message::text(left).important())
+                .with_text(" goals are left."),
+        };
+        Some(msg)
+    }
+
+    fn note(&self) -> Option<Message> {
+        match &self.reason {
+            Reason::NoProof => Some(Message::text(
+                "Start a proof with a theorem stated without `:=`.",
+            )),
+            Reason::InProgress(_) => Some(Message::text(
+                "Finish it with `Qed` or drop it with `Abort`.",
+            )),
+            Reason::NoGoal => Some(Message::text("Close the proof with `Qed`.")),
+            Reason::NotFit(tactic, _) => {
+                Some(Message::text("Usage : ").append(Message::text(usage(tactic)).important()))
+            }
+            Reason::Mismatch { expected, found } => Some(
+                Message::text("Expected : ")
+                    .append(Message::of_pretty(expected.as_ref()).important())
+                    .with_line()
+                    .with_text("Found    : ")
+                    .append(Message::of_pretty(found.as_ref()).important()),
+            ),
+            Reason::Incomplete(_) => None,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::typer::ast::{Identifier, Type};
+use crate::typer::kernel::Formula;
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// ==========================================================================
+// Goal
+// ==========================================================================
+/// proposition left to prove, in its context
+#[derive(Debug, Clone)]
+pub struct Goal {
+    /// hole filled by the proof of this goal
+    hole: usize,
+    /// term variables in scope
+    vars: Vec<(Rc<Identifier>, Type)>,
+    /// hypotheses in scope, innermost last
+    hypotheses: Vec<(String, Formula)>,
+    /// proposition to prove
+    formula: Formula,
+}
+
+impl Goal {
+    pub fn new(
+        hole: usize,
+        vars: Vec<(Rc<Identifier>, Type)>,
+        hypotheses: Vec<(String, Formula)>,
+        formula: Formula,
+    ) -> Self {
+        Self {
+            hole,
+            vars,
+            hypotheses,
+            formula,
+        }
+    }
+
+    /// get hole filled by the proof of this goal
+    pub fn hole(&self) -> usize {
+        self.hole
+    }
+
+    /// get term variables in scope
+    pub fn vars(&self) -> &[(Rc<Identifier>, Type)] {
+        &self.vars
+    }
+
+    /// get hypotheses in scope
+    pub fn hypotheses(&self) -> &[(String, Formula)] {
+        &self.hypotheses
+    }
+
+    /// get proposition to prove
+    pub fn formula(&self) -> &Formula {
+        &self.formula
+    }
+}
+
+// ==========================================================================
+// Goals
+// ==========================================================================
+/// goals left in a proof, focused one first
+pub struct Goals(Vec<Goal>);
+
+impl Goals {
+    pub fn new(goals: Vec<Goal>) -> Self {
+        Self(goals)
+    }
+}
+
+impl Pretty for Goals {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let Some((focused, others)) = self.0.split_first() else {
+            return Doc::text("No more goals.");
+        };
+        let count = match self.0.len() {
+            1 => "1 goal".to_string(),
+            n => format!("{n} goals"),
+        };
+        let vars = focused.vars.iter().map(|(id, ty)| {
+            Doc::nil()
+                .append(theme.def_var(&id.name()))
+                .append(Doc::space())
+                .append(theme.operator(&":"))
+                .append(Doc::space())
+                .append(ty.pretty(theme))
+        });
+        let hypotheses = focused.hypotheses.iter().map(|(name, formula)| {
+            Doc::nil()
+                .append(theme.def_var(name))
+                .append(Doc::space())
+                .append(theme.operator(&":"))
+                .append(Doc::space())
+                .append(formula.pretty(theme))
+        });
+        let others = others.iter().enumerate().map(|(i, goal)| {
+            Doc::nil()
+                .append(Doc::hardline())
+                .append(Doc::text(format!("goal {}", i + 2)))
+                .append(Doc::space())
+                .append(theme.operator(&"is:"))
+                .append(Doc::space())
+                .append(goal.formula.pretty(theme))
+        });
+        Doc::nil()
+            .append(Doc::text(count))
+            .append(Doc::concat(
+                vars.chain(hypotheses)
+                    .map(|doc| Doc::hardline().append(doc).nest(2)),
+            ))
+            .append(Doc::hardline())
+            .append(theme.operator(&"  ────────────────────"))
+            .append(Doc::hardline())
+            .append(Doc::text("  "))
+            .append(focused.formula.pretty(theme))
+            .append(Doc::concat(others))
+    }
+}
@@ -0,0 +1,344 @@
+mod error;
+mod goal;
+
+pub use error::ErrorTactic;
+pub use goal::{Goal, Goals};
+
+use super::ast::{self, Identifier};
+use super::error::Error;
+use super::kernel::{self, Arg, Formula, ProofTerm, Term, Terms};
+use crate::parser::cst;
+use crate::parser::cst::command::{TacticKeyword, TacticKeywordT};
+use crate::parser::cst::tactic::TacticArgs;
+use crate::parser::cst::AsIdentifier as _;
+use crate::utils::location::{Located, Location};
+use error::Reason;
+use std::rc::Rc;
+
+type Result<T, E = Vec<Error>> = std::result::Result<T, E>;
+
+fn fail<T>(reason: Reason, loc: &Location) -> Result<T> {
+    Err(vec![ErrorTactic::new(reason, loc).into()])
+}
+
+// ==========================================================================
+// Step
+// ==========================================================================
+/// proof term of a refined goal, with holes for the goals it leaves
+#[derive(Debug)]
+enum Step {
+    /// `var => ?hole`
+    Intro {
+        var: cst::pattern::Variable,
+        hole: usize,
+        loc: Location,
+    },
+    /// `proof ?hole1 ⋯ ?holen`
+    Apply { proof: ProofTerm, holes: Vec<usize> },
+}
+
+/// steps filling holes, and goals left by them
+type Refinement = (Vec<(usize, Step)>, Vec<Goal>);
+
+// ==========================================================================
+// Session
+// ==========================================================================
+/// proof of a theorem in progress, its proof term is checked by the kernel at `Qed`
+pub struct Session {
+    /// name of theorem
+    name: Rc<Identifier>,
+    /// statement, as typed
+    statement: ast::Expression,
+    /// documentation of theorem
+    doc: Option<ast::Documentation>,
+    /// location of name of theorem
+    name_loc: Location,
+    /// location of theorem
+    loc: Location,
+    /// goals left, focused one first
+    goals: Vec<Goal>,
+    /// step filling each hole, `None` while its goal is open
+    steps: Vec<Option<Step>>,
+}
+
+impl Session {
+    /// start proof of `statement`, read by the kernel as `formula`
+    pub fn new(
+        name: Rc<Identifier>,
+        statement: ast::Expression,
+        formula: Formula,
+        doc: Option<ast::Documentation>,
+        name_loc: Location,
+        loc: Location,
+    ) -> Self {
+        Self {
+            name,
+            statement,
+            doc,
+            name_loc,
+            loc,
+            goals: vec![Goal::new(0, Vec::new(), Vec::new(), formula)],
+            steps: vec![None],
+        }
+    }
+
+    /// get name of theorem
+    pub fn name(&self) -> &Rc<Identifier> {
+        &self.name
+    }
+
+    /// get documentation of theorem
+    pub fn doc(&self) -> Option<&ast::Documentation> {
+        self.doc.as_ref()
+    }
+
+    /// get location of name of theorem
+    pub fn name_loc(&self) -> &Location {
+        &self.name_loc
+    }
+
+    /// get goals left
+    pub fn goals(&self) -> Goals {
+        Goals::new(self.goals.clone())
+    }
+
+    /// term variables in scope of focused goal
+    pub fn vars(&self) -> Vec<Rc<Identifier>> {
+        self.goals
+            .first()
+            .map(|goal| goal.vars().iter().map(|(id, _)| id.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// new hole, for a goal left by a step
+    fn hole(&mut self) -> usize {
+        self.steps.push(None);
+        self.steps.len() - 1
+    }
+
+    /// refine focused goal with tactic `keyword`, applied to `args`
+    pub fn run(
+        &mut self,
+        terms: &mut impl Terms,
+        keyword: &TacticKeyword,
+        args: &TacticArgs,
+    ) -> Result<()> {
+        let loc = match args.loc() {
+            Some(loc_args) => keyword.loc().union(loc_args),
+            None => keyword.loc(),
+        };
+        let Some(goal) = self.goals.first().cloned() else {
+            return fail(Reason::NoGoal, &loc);
+        };
+        let (steps, goals) = match (&keyword.value, args) {
+            (TacticKeywordT::Intro, TacticArgs::Names(names)) => self.intro(terms, goal, names)?,
+            (TacticKeywordT::Exact, TacticArgs::Expr(expr)) => {
+                let proof = ProofTerm::of(expr)?;
+                kernel::prove(terms, goal.hypotheses(), &proof, goal.formula())?;
+                let step = Step::Apply {
+                    proof,
+                    holes: Vec::new(),
+                };
+                (vec![(goal.hole(), step)], Vec::new())
+            }
+            (TacticKeywordT::Apply, TacticArgs::Expr(expr)) => self.apply(terms, goal, expr)?,
+            (TacticKeywordT::Exists, TacticArgs::Expr(expr)) => match goal.formula() {
+                Formula::Exists(x, ty, body) => {
+                    let witness = kernel::witness(terms, expr, ty)?;
+                    let formula = body.substitute(x, &witness);
+                    let args = vec![Arg::Expr(expr.clone())];
+                    self.constructor(&goal, "ex_intro", args, vec![formula], loc)
+                }
+                _ => return fail(Reason::NotFit(keyword.value, goal.formula().clone()), &loc),
+            },
+            (TacticKeywordT::Split, TacticArgs::None) => match goal.formula() {
+                Formula::And(a, b) => {
+                    let premises = vec![a.as_ref().clone(), b.as_ref().clone()];
+                    self.constructor(&goal, "conj", Vec::new(), premises, loc)
+                }
+                _ => return fail(Reason::NotFit(keyword.value, goal.formula().clone()), &loc),
+            },
+            (TacticKeywordT::Left, TacticArgs::None) => match goal.formula() {
+                Formula::Or(a, _) => {
+                    let premises = vec![a.as_ref().clone()];
+                    self.constructor(&goal, "or_introl", Vec::new(), premises, loc)
+                }
+                _ => return fail(Reason::NotFit(keyword.value, goal.formula().clone()), &loc),
+            },
+            (TacticKeywordT::Right, TacticArgs::None) => match goal.formula() {
+                Formula::Or(_, b) => {
+                    let premises = vec![b.as_ref().clone()];
+                    self.constructor(&goal, "or_intror", Vec::new(), premises, loc)
+                }
+                _ => return fail(Reason::NotFit(keyword.value, goal.formula().clone()), &loc),
+            },
+            (TacticKeywordT::Reflexivity, TacticArgs::None) => match goal.formula() {
+                Formula::Atom(term) if term.is_reflexive() => {
+                    self.constructor(&goal, "eq_refl", Vec::new(), Vec::new(), loc)
+                }
+                _ => return fail(Reason::NotFit(keyword.value, goal.formula().clone()), &loc),
+            },
+            _ => unreachable!("tactics are parsed along with their arguments"),
+        };
+        for (hole, step) in steps {
+            self.steps[hole] = Some(step);
+        }
+        self.goals.splice(0..1, goals);
+        Ok(())
+    }
+
+    /// introduce `names` one after the other
+    fn intro(
+        &mut self,
+        terms: &mut impl Terms,
+        goal: Goal,
+        names: &[cst::pattern::Variable],
+    ) -> Result<Refinement> {
+        let mut steps = Vec::new();
+        let mut goal = goal;
+        for var in names {
+            let hole = self.hole();
+            let next = match goal.formula() {
+                Formula::Implies(hyp, concl) => {
+                    let mut hypotheses = goal.hypotheses().to_vec();
+                    hypotheses.push((var.name().to_string(), hyp.as_ref().clone()));
+                    let vars = goal.vars().to_vec();
+                    Goal::new(hole, vars, hypotheses, concl.as_ref().clone())
+                }
+                Formula::Forall(x, ty, concl) => {
+                    let id = terms.bind(var, ty);
+                    terms.unbind(&id);
+                    let concl = concl.substitute(x, &Term::Var(id.as_ref().clone()));
+                    let mut vars = goal.vars().to_vec();
+                    vars.push((id, ty.clone()));
+                    let hypotheses = goal.hypotheses().to_vec();
+                    Goal::new(hole, vars, hypotheses, concl)
+                }
+                _ => {
+                    let reason = Reason::NotFit(TacticKeywordT::Intro, goal.formula().clone());
+                    return fail(reason, &var.loc());
+                }
+            };
+            let step = Step::Intro {
+                var: var.clone(),
+                hole,
+                loc: var.loc(),
+            };
+            steps.push((goal.hole(), step));
+            goal = next;
+        }
+        Ok((steps, vec![goal]))
+    }
+
+    /// apply proof `expr`, leaving a goal for each premise it needs
+    fn apply(
+        &mut self,
+        terms: &mut impl Terms,
+        goal: Goal,
+        expr: &cst::Expression,
+    ) -> Result<Refinement> {
+        let proof = ProofTerm::of(expr)?;
+        let found = kernel::infer(terms, goal.hypotheses(), &proof)?;
+        let mut premises = Vec::new();
+        let mut concl = &found;
+        while concl != goal.formula() {
+            match concl {
+                Formula::Implies(hyp, next) => {
+                    premises.push(hyp.as_ref().clone());
+                    concl = next;
+                }
+                _ => {
+                    let reason = Reason::Mismatch {
+                        expected: Box::new(goal.formula().clone()),
+                        found: Box::new(found),
+                    };
+                    return fail(reason, &expr.loc());
+                }
+            }
+        }
+        Ok(self.refine(&goal, proof, premises))
+    }
+
+    /// apply proof constructor `name` to `args`, leaving a goal for each premise
+    fn constructor(
+        &mut self,
+        goal: &Goal,
+        name: &str,
+        args: Vec<Arg>,
+        premises: Vec<Formula>,
+        loc: Location,
+    ) -> Refinement {
+        self.refine(goal, ProofTerm::apply(name, args, loc), premises)
+    }
+
+    /// fill `goal` with `proof` applied to a hole for each premise
+    fn refine(&mut self, goal: &Goal, proof: ProofTerm, premises: Vec<Formula>) -> Refinement {
+        let goals = premises
+            .into_iter()
+            .map(|premise| {
+                let hole = self.hole();
+                let vars = goal.vars().to_vec();
+                Goal::new(hole, vars, goal.hypotheses().to_vec(), premise)
+            })
+            .collect::<Vec<_>>();
+        let holes = goals.iter().map(Goal::hole).collect();
+        (vec![(goal.hole(), Step::Apply { proof, holes })], goals)
+    }
+
+    /// assemble proof term filling `hole`
+    fn build(&self, hole: usize) -> ProofTerm {
+        match self.steps[hole].as_ref().expect("no goal is left open") {
+            Step::Intro { var, hole, loc } => ProofTerm::Intro {
+                var: var.clone(),
+                body: Box::new(self.build(*hole)),
+                loc: loc.clone(),
+            },
+            Step::Apply { proof, holes } => match proof {
+                ProofTerm::Apply { head, args, loc } => {
+                    let mut args = args.clone();
+                    args.extend(holes.iter().map(|hole| Arg::Proof(self.build(*hole))));
+                    ProofTerm::Apply {
+                        head: head.clone(),
+                        args,
+                        loc: loc.clone(),
+                    }
+                }
+                intro => intro.clone(),
+            },
+        }
+    }
+
+    /// fail if goals are left to prove
+    pub fn check_complete(&self, loc: &Location) -> Result<()> {
+        match self.goals.len() {
+            0 => Ok(()),
+            left => fail(Reason::Incomplete(left), loc),
+        }
+    }
+
+    /// check assembled proof with the kernel, once all goals are proved
+    pub fn qed(self, terms: &mut impl Terms) -> Result<kernel::Theorem> {
+        let proof = self.build(0);
+        kernel::check(terms, self.name, self.statement, &proof, self.loc)
+    }
+}
+
+impl std::fmt::Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Session")
+            .field("name", &self.name)
+            .field("goals", &self.goals)
+            .finish()
+    }
+}
+
+/// error when no proof is in progress
+pub fn no_proof(loc: &Location) -> Vec<Error> {
+    vec![ErrorTactic::new(Reason::NoProof, loc).into()]
+}
+
+/// error when a proof is already in progress
+pub fn in_progress(session: &Session, loc: &Location) -> Vec<Error> {
+    let reason = Reason::InProgress(session.name().name().to_string());
+    vec![ErrorTactic::new(reason, loc).into()]
+}
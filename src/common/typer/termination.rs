@@ -0,0 +1,241 @@
+use super::ast::{self, Expression, Identifier, Pattern, Typed as _};
+use super::error::{Error, ErrorNotTerminating};
+use crate::utils::location::Located as _;
+use std::collections::HashMap;
+
+// ==========================================================================
+// Size
+// ==========================================================================
+/// size of a variable compared to the decreasing parameter of the definition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Size {
+    /// variable is the decreasing parameter, or a tuple component of it
+    Equal,
+    /// variable is bound inside a constructor of the decreasing parameter
+    Smaller,
+}
+
+// ==========================================================================
+// Checker
+// ==========================================================================
+struct Checker<'a> {
+    /// names of the mutually recursive definitions
+    names: &'a [&'a Identifier],
+    /// position of the decreasing parameter
+    position: usize,
+    /// size of variables bound by patterns
+    sizes: HashMap<&'a Identifier, Size>,
+    /// errors found
+    errors: Vec<Error>,
+}
+
+/// split application `f a1 ... an` into `f` and `[a1, ..., an]`
+fn spine(expr: &Expression) -> (&Expression, Vec<&Expression>) {
+    match expr {
+        Expression::Application(app) => {
+            let (head, mut args) = spine(app.fun());
+            args.push(app.arg());
+            (head, args)
+        }
+        _ => (expr, Vec::new()),
+    }
+}
+
+impl<'a> Checker<'a> {
+    /// bind variables of `pattern` matching a value of size `size`
+    fn bind(&mut self, pattern: &'a Pattern, size: Size) {
+        match pattern {
+            Pattern::Variable(var) => {
+                self.sizes.insert(var.identifier(), size);
+            }
+            Pattern::Tuple(tuple) => {
+                for pattern in tuple.patterns() {
+                    self.bind(pattern, size);
+                }
+            }
+            Pattern::Constructor(ctor) => {
+                for pattern in ctor.args() {
+                    self.bind(pattern, Size::Smaller);
+                }
+            }
+        }
+    }
+
+    /// size of expression, if it is a variable bound by a pattern
+    fn size(&self, expr: Option<&Expression>) -> Option<Size> {
+        match expr {
+            Some(Expression::Variable(var)) => self.sizes.get(var.identifier()).copied(),
+            _ => None,
+        }
+    }
+
+    /// check parameters of definition body `expr`, starting at parameter `index`
+    fn parameters(&mut self, expr: &'a Expression, index: usize) {
+        let size = (index == self.position).then_some(Size::Equal);
+        match expr {
+            Expression::Abstraction(abs) => {
+                if let Some(size) = size {
+                    self.bind(abs.pattern(), size);
+                }
+                self.parameters(abs.body(), index + 1);
+            }
+            Expression::Checked(checked) => {
+                self.clauses(checked.contract());
+                self.parameters(checked.body(), index);
+            }
+            Expression::Match(expr_match) => {
+                for branch in expr_match.branches() {
+                    if let Some(size) = size {
+                        self.bind(branch.pattern(), size);
+                    }
+                    self.parameters(branch.body(), index + 1);
+                }
+            }
+            _ => self.expression(expr),
+        }
+    }
+
+    /// check function `expr` applied to an argument of size `size`
+    fn function(&mut self, expr: &'a Expression, size: Option<Size>) {
+        match expr {
+            Expression::Abstraction(abs) => {
+                if let Some(size) = size {
+                    self.bind(abs.pattern(), size);
+                }
+                self.expression(abs.body());
+            }
+            Expression::Match(expr_match) => {
+                for branch in expr_match.branches() {
+                    if let Some(size) = size {
+                        self.bind(branch.pattern(), size);
+                    }
+                    self.expression(branch.body());
+                }
+            }
+            _ => self.expression(expr),
+        }
+    }
+
+    /// check recursive calls in clauses of `contract`, evaluated along with the body
+    fn clauses(&mut self, contract: &'a ast::Contract) {
+        for clause in contract.clauses() {
+            self.expression(clause.expr());
+        }
+    }
+
+    /// check recursive calls of expression
+    fn expression(&mut self, expr: &'a Expression) {
+        match expr {
+            Expression::Constant(_) | Expression::Instance(_) => {}
+            Expression::Variable(var) => {
+                if self.names.contains(&var.identifier()) {
+                    let err = ErrorNotTerminating::new(var.identifier(), &var.loc());
+                    self.errors.push(err.into());
+                }
+            }
+            Expression::Application(_) => {
+                let (head, args) = spine(expr);
+                match head {
+                    Expression::Variable(var) if self.names.contains(&var.identifier()) => {
+                        let arg = args.get(self.position).copied();
+                        if self.size(arg) != Some(Size::Smaller) {
+                            let err = ErrorNotTerminating::new(var.identifier(), &expr.loc());
+                            self.errors.push(err.into());
+                        }
+                    }
+                    _ => self.function(head, self.size(args.first().copied())),
+                }
+                for arg in args {
+                    self.expression(arg);
+                }
+            }
+            Expression::Tuple(tuple) => {
+                for elem in tuple.elems() {
+                    self.expression(elem);
+                }
+            }
+            Expression::Binary(binary) => {
+                self.expression(binary.left());
+                self.expression(binary.right());
+            }
+            Expression::Unary(unary) => self.expression(unary.expr()),
+            Expression::Alloc(alloc) => self.expression(alloc.value()),
+            Expression::Deref(deref) => self.expression(deref.reference()),
+            Expression::Assign(assign) => {
+                self.expression(assign.reference());
+                self.expression(assign.value());
+            }
+            Expression::Record(record) => {
+                for field in record.fields() {
+                    self.expression(field.value());
+                }
+            }
+            Expression::Update(update) => {
+                self.expression(update.record());
+                for field in update.fields() {
+                    self.expression(field.value());
+                }
+            }
+            Expression::Projection(projection) => self.expression(projection.record()),
+            Expression::Coercion(coercion) => self.expression(coercion.expr()),
+            Expression::Let(expr_let) => {
+                self.expression(expr_let.value());
+                if let Some(size) = self.size(Some(expr_let.value())) {
+                    self.bind(expr_let.pattern(), size);
+                }
+                self.expression(expr_let.body());
+            }
+            Expression::If(expr_if) => {
+                self.expression(expr_if.cond());
+                self.expression(expr_if.then_branch());
+                self.expression(expr_if.else_branch());
+            }
+            Expression::Quantifier(quantifier) => self.expression(quantifier.body()),
+            Expression::Checked(checked) => {
+                self.clauses(checked.contract());
+                self.expression(checked.body());
+            }
+            Expression::Abstraction(_) | Expression::Match(_) => self.function(expr, None),
+        }
+    }
+}
+
+/// check mutually recursive definitions `defs` with decreasing parameter at `position`
+fn check_at(defs: &[ast::ExpressionDefinition], position: usize) -> Vec<Error> {
+    let names = defs
+        .iter()
+        .flat_map(|def| def.pattern().names())
+        .collect::<Vec<_>>();
+    defs.iter()
+        .flat_map(|def| {
+            let mut checker = Checker {
+                names: &names,
+                position,
+                sizes: HashMap::new(),
+                errors: Vec::new(),
+            };
+            checker.parameters(def.body(), 0);
+            checker.errors
+        })
+        .collect()
+}
+
+/// number of parameters of a function of type `ty`
+fn arity(ty: &ast::Type) -> usize {
+    match ty.unfold() {
+        ast::Type::Arrow(arrow) => 1 + arity(arrow.ret()),
+        _ => 0,
+    }
+}
+
+/// check that the mutually recursive definitions `defs` terminate, trying each position
+/// of parameter as decreasing one: recursive calls pass a strict subterm at that position
+pub fn check(defs: &[ast::ExpressionDefinition]) -> Result<(), Vec<Error>> {
+    let arity = defs.iter().map(|def| arity(def.ty())).min().unwrap_or(0);
+    let errors = check_at(defs, 0);
+    if errors.is_empty() || (1..arity).any(|position| check_at(defs, position).is_empty()) {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
@@ -1,23 +1,78 @@
-use super::ast::{self, Typed as _};
-use super::env::Env;
-use super::error::Error;
+use super::ast::{self, Typed as _, TypedMut as _};
+use super::env::{self, Env};
+use super::error::{
+    Error, ErrorAmbiguousRecord, ErrorConstructorArity, ErrorDuplicateField, ErrorMissingField,
+    ErrorModuleEnd, ErrorModuleNotClosed, ErrorModuleNotFound, ErrorNotConstructor,
+    ErrorNotFunction, ErrorPatternMismatch, ErrorShadowedResult, ErrorUndeclaredReference,
+    ErrorUnexpectedType, ErrorUnknownField,
+};
+use super::interface::{self, Export, ExportKind, Interface};
+use super::kernel;
+use super::matching;
+use super::tactic;
+use super::termination;
 use crate::lexer::Meta;
-use crate::parser::cst::{self, AsCharacter as _, AsIdentifier as _, AsNumber as _};
+use crate::parser::cst::{
+    self, AsCharacter as _, AsIdentifier as _, AsInteger as _, AsNumber as _, AsRational as _,
+    AsString as _,
+};
 use crate::utils::error::ResultExt as _;
-use crate::utils::location::{Located as _, LocatedSet};
+use crate::utils::location::{Located, LocatedSet, Location, SourceId};
+use ariadne::Span as _;
+use std::rc::Rc;
 
 #[derive(Debug, Default)]
 pub struct Typer {
-    id_builder: ast::IdentifierBuilder,
+    pub(super) id_builder: ast::IdentifierBuilder,
+    pub(super) env: Env,
+    /// proof by tactics in progress
+    proof: Option<tactic::Session>,
+    /// references of the environment declared by the definition being typed,
+    /// `None` outside of definitions, where every reference is usable
+    effects: Option<Vec<ast::Identifier>>,
+    /// instances of classes used by the command being typed, resolved once it is typed
+    pub(super) instances: Vec<ast::ExpressionInstance>,
+    /// locations of the names of modules being defined, innermost last
+    modules: Vec<Location>,
+}
+
+/// definitions exported by a required file, they are added to the environment of
+/// each file requiring it
+#[derive(Debug, Default)]
+pub struct Exports {
+    names: ast::ExportedNames,
     env: Env,
 }
 
-type Result<T, E = Vec<Error>> = std::result::Result<T, E>;
+pub(super) type Result<T, E = Vec<Error>> = std::result::Result<T, E>;
+
+/// head of a definition of a fixpoint, its type variables, pattern, type and declared effects
+type FixpointHead = (
+    Vec<Rc<ast::Identifier>>,
+    ast::Pattern,
+    ast::Type,
+    Vec<ast::Identifier>,
+);
 
-fn to_errs(err: Error) -> Vec<Error> {
+/// name of the result of a definition in its postconditions
+const RESULT: &str = "r";
+
+pub(super) fn to_errs(err: Error) -> Vec<Error> {
     vec![err]
 }
 
+/// combine results, keeping errors of all of them
+fn combine_all<T>(results: impl IntoIterator<Item = Result<T>>) -> Result<Vec<T>> {
+    let mut acc = Ok(Vec::new());
+    for res in results {
+        acc = acc.combine(res).map(|(mut values, value)| {
+            values.push(value);
+            values
+        });
+    }
+    acc
+}
+
 impl Typer {
     /// get environment
     pub fn env(&self) -> &Env {
@@ -28,12 +83,24 @@ impl Typer {
     fn constant(&self, constant: &cst::Constant) -> ast::Constant {
         match constant {
             cst::Constant::Number(n) => ast::Constant::nat(n.as_number().clone()),
+            cst::Constant::Integer(i) => ast::Constant::int(i.as_integer().clone()),
+            cst::Constant::Rational(q) => ast::Constant::rat(q.as_rational().clone()),
             cst::Constant::Character(c) => ast::Constant::character(c.as_character()),
+            cst::Constant::String(s) => ast::Constant::string(s.as_string().to_string()),
             cst::Constant::Builtin(Meta { value: builtin, .. }) => {
                 use cst::constant::BuiltinT;
                 match builtin {
                     BuiltinT::True => ast::Constant::boolean(true),
                     BuiltinT::False => ast::Constant::boolean(false),
+                    BuiltinT::IntOfNat => ast::Constant::primitive(ast::Primitive::IntOfNat),
+                    BuiltinT::NatOfInt => ast::Constant::primitive(ast::Primitive::NatOfInt),
+                    BuiltinT::StringLength => {
+                        ast::Constant::primitive(ast::Primitive::StringLength)
+                    }
+                    BuiltinT::StringAppend => {
+                        ast::Constant::primitive(ast::Primitive::StringAppend)
+                    }
+                    BuiltinT::StringGet => ast::Constant::primitive(ast::Primitive::StringGet),
                 }
             }
         }
@@ -52,16 +119,289 @@ impl Typer {
                 Ok(ast::Expression::Constant(c_ty))
             }
             Expression0::Variable(var) => {
-                let loc = var.loc();
                 let id = self.id_builder.get(var.name());
-                let var = self
-                    .env
-                    .get_expr_var(&id, loc.clone())
-                    .map_err(Error::from)
-                    .map_err(to_errs)?;
-                Ok(ast::Expression::Variable(var))
+                self.variable(&id, var.loc())
             }
+            Expression0::Unit(_) => Ok(ast::Expression::Constant(ast::Constant::unit())),
             Expression0::Paren(expr) => self.expression(expr.inner()),
+            Expression0::Record(record) => self.record(record, None),
+            Expression0::Projection { expr, field, .. } => {
+                if let Some(name) = Self::qualified_name(expression) {
+                    let root = name.split('.').next().unwrap_or_default();
+                    // a projection on an unknown variable is a qualified name
+                    let qualified = match self.id_builder.lookup(&name) {
+                        None if self.id_builder.lookup(root).is_none() => {
+                            Some(self.id_builder.get(&name))
+                        }
+                        qualified => qualified,
+                    };
+                    if let Some(id) = qualified {
+                        return self.variable(&id, expression.loc());
+                    }
+                }
+                let record = self.expression0(expr)?;
+                self.projection(record, field, expression)
+            }
+        }
+    }
+
+    /// convert variable `id` used at `loc`, a dictionary of each class of the variable
+    /// is given by an instance resolved once the command is typed
+    fn variable(&mut self, id: &ast::Identifier, loc: Location) -> Result<ast::Expression> {
+        let (mut var, classes) = self
+            .env
+            .get_constrained_var(id, loc.clone())
+            .map_err(Error::from)
+            .map_err(to_errs)?;
+        self.check_effects(id, &loc)?;
+        let instances = classes
+            .into_iter()
+            .map(|class| ast::ExpressionInstance::new(class, loc.clone()))
+            .collect::<Vec<_>>();
+        self.instances.extend(instances.iter().cloned());
+        // a method is the field of the dictionary of its class
+        if let Some(class) = self.env.get_method(id) {
+            let index = self
+                .env
+                .get_record(class)
+                .and_then(|record| record.position(id.name()))
+                .expect("method is a field of its class");
+            let dict = ast::Expression::Instance(instances[0].clone());
+            let name = id.name().to_string();
+            let projection =
+                ast::ExpressionProjection::new(dict, name, index, var.ty().clone()).with_loc(&var);
+            return Ok(ast::Expression::Projection(projection));
+        }
+        *var.ty_mut() = instances
+            .iter()
+            .rev()
+            .fold(var.ty().clone(), |ty, instance| {
+                ast::Type::Arrow(ast::TypeArrow::new(instance.ty().clone(), ty))
+            });
+        let expr = instances
+            .into_iter()
+            .fold(ast::Expression::Variable(var), |fun, instance| {
+                let ty = fun.ty().as_arrow().expect("dictionary is an argument");
+                let ty = ty.ret().clone();
+                let dict = ast::Expression::Instance(instance.clone());
+                let app = ast::ExpressionApplication::new(fun, dict, ty).with_loc(&instance);
+                ast::Expression::Application(app)
+            });
+        Ok(expr)
+    }
+
+    /// get name `M.x` of a projection on a variable, qualified access to a module
+    /// when the name is visible
+    fn qualified_name(expression: &cst::expression::Expression0) -> Option<String> {
+        use cst::expression::Expression0;
+        match expression {
+            Expression0::Variable(var) => Some(var.name().to_string()),
+            Expression0::Projection { expr, field, .. } => {
+                Some(format!("{}.{}", Self::qualified_name(expr)?, field.name()))
+            }
+            _ => None,
+        }
+    }
+
+    /// get record type of an expression of type `ty` with a field `name`:
+    /// the type itself when it is known, the last record type defining the field otherwise
+    fn record_of(&self, ty: &ast::Type, name: &cst::record::Field) -> Result<env::Record> {
+        let record = match ty.unfold() {
+            ast::Type::Record(record) => self.env.get_record(record.name()),
+            ast::Type::Meta(_) => self.env.record_of_field(name.name()),
+            _ => None,
+        };
+        match record {
+            Some(record) => Ok(record.clone()),
+            None => {
+                let ty = (!matches!(ty.unfold(), ast::Type::Meta(_))).then_some(ty);
+                let err = ErrorUnknownField::new(name.name(), ty, Vec::new(), &name.loc());
+                Err(to_errs(err.into()))
+            }
+        }
+    }
+
+    /// get record literal `{x := e; …}` of expression, possibly parenthesized
+    fn record_literal(expression: &cst::expression::Expression) -> Option<&cst::record::Record> {
+        use cst::expression::{Expression3, Expression4, Expression5};
+        match expression {
+            Expression5::Expression4(Expression4::Expression3(Expression3::Expression2(expr))) => {
+                Self::record_literal2(expr)
+            }
+            _ => None,
+        }
+    }
+
+    /// get record literal `{x := e; …}` of expression2, possibly parenthesized
+    fn record_literal2(expression: &cst::expression::Expression2) -> Option<&cst::record::Record> {
+        use cst::expression::{Expression0, Expression1, Expression2};
+        match expression {
+            Expression2::Expression1(Expression1::Expression0(expr)) => match expr {
+                Expression0::Record(record) if record.base.is_none() => Some(record),
+                Expression0::Paren(expr) => Self::record_literal(expr.inner()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// convert expression expected of type `ty`, a record literal is of type `ty`
+    /// when it is a record type
+    pub(super) fn expression_expecting(
+        &mut self,
+        expression: &cst::expression::Expression,
+        ty: &ast::Type,
+    ) -> Result<ast::Expression> {
+        match Self::record_literal(expression) {
+            Some(record) => self
+                .record(record, Some(ty))
+                .map(|expr| expr.with_loc(expression)),
+            None => self.expression(expression),
+        }
+    }
+
+    /// get record type of record literal `record` expected of type `expected`:
+    /// the expected record type when it is known, otherwise the record type
+    /// with the given fields
+    fn record_type(
+        &self,
+        record: &cst::record::Record,
+        expected: Option<&ast::Type>,
+    ) -> Result<env::Record> {
+        if let Some(ast::Type::Record(ty)) = expected.map(ast::Type::unfold) {
+            if let Some(info) = self.env.get_record(ty.name()) {
+                return Ok(info.clone());
+            }
+        }
+        let names = record
+            .fields
+            .iter()
+            .map(|field| field.name.name())
+            .collect::<Vec<_>>();
+        match self.env.records_of_fields(&names).as_slice() {
+            [info] => Ok((*info).clone()),
+            [] => self.record_of(
+                &ast::Type::Meta(ast::TypeMeta::new()),
+                &record.fields[0].name,
+            ),
+            infos => {
+                let mut records = infos
+                    .iter()
+                    .map(|info| info.id().qualified_name())
+                    .collect::<Vec<_>>();
+                records.sort();
+                let fields = names.iter().map(|name| name.to_string()).collect();
+                let err = ErrorAmbiguousRecord::new(fields, records, &record.loc());
+                Err(to_errs(err.into()))
+            }
+        }
+    }
+
+    /// get position of field `name` in `record` of type `ty`
+    fn field_index(
+        record: &env::Record,
+        ty: &ast::Type,
+        name: &cst::record::Field,
+    ) -> Result<usize> {
+        record.position(name.name()).ok_or_else(|| {
+            let err = ErrorUnknownField::new(name.name(), Some(ty), record.names(), &name.loc());
+            to_errs(err.into())
+        })
+    }
+
+    /// type projection on field `field` of `record`
+    fn projection(
+        &mut self,
+        record: ast::Expression,
+        field: &cst::record::Field,
+        loc: &impl Located,
+    ) -> Result<ast::Expression> {
+        let info = self.record_of(record.ty(), field)?;
+        let ty_record = info.instantiate();
+        let record = Self::operand(record, &ast::Type::Record(ty_record.clone()))?;
+        let index = Self::field_index(&info, record.ty(), field)?;
+        let ty = info.field_ty(index, &ty_record);
+        let name = field.name().to_string();
+        let projection = ast::ExpressionProjection::new(record, name, index, ty).with_loc(loc);
+        Ok(ast::Expression::Projection(projection))
+    }
+
+    /// type values of fields of `record` of type `ty`, each field is given once
+    fn field_values(
+        &mut self,
+        info: &env::Record,
+        ty: &ast::TypeRecord,
+        fields: &[cst::record::FieldValue],
+    ) -> Result<Vec<ast::RecordField>> {
+        let ty_record = ast::Type::Record(ty.clone());
+        let fields_res = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let name = field.name.name();
+                let index_res = Self::field_index(info, &ty_record, &field.name);
+                let given = fields[..i].iter().any(|other| other.name.name() == name);
+                let index_res = match index_res {
+                    Ok(_) if given => {
+                        let err = ErrorDuplicateField::new(name, &field.name.loc());
+                        Err(to_errs(err.into()))
+                    }
+                    index_res => index_res,
+                };
+                let value_res = match &index_res {
+                    Ok(index) => {
+                        self.expression_expecting(&field.value, &info.field_ty(*index, ty))
+                    }
+                    Err(_) => self.expression(&field.value),
+                };
+                let (index, value) = index_res.combine(value_res)?;
+                let value = Self::operand(value, &info.field_ty(index, ty))?;
+                Ok(ast::RecordField::new(
+                    name.to_string(),
+                    index,
+                    value,
+                    field.loc(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        combine_all(fields_res)
+    }
+
+    /// type record or update of a record, a record expected of type `expected`
+    fn record(
+        &mut self,
+        record: &cst::record::Record,
+        expected: Option<&ast::Type>,
+    ) -> Result<ast::Expression> {
+        // the parser ensures that at least one field is given
+        let first = &record.fields[0].name;
+        match &record.base {
+            Some((base, _)) => {
+                let base = self.expression(base)?;
+                let info = self.record_of(base.ty(), first)?;
+                let ty = info.instantiate();
+                let base = Self::operand(base, &ast::Type::Record(ty.clone()))?;
+                let fields = self.field_values(&info, &ty, &record.fields)?;
+                let update = ast::ExpressionUpdate::new(base, fields).with_loc(record);
+                Ok(ast::Expression::Update(update))
+            }
+            None => {
+                let info = self.record_type(record, expected)?;
+                let ty = info.instantiate();
+                let fields = self.field_values(&info, &ty, &record.fields)?;
+                let ty = ast::Type::Record(ty);
+                let missing = info
+                    .names()
+                    .into_iter()
+                    .filter(|name| fields.iter().all(|field| field.name() != name))
+                    .collect::<Vec<_>>();
+                if !missing.is_empty() {
+                    let err = ErrorMissingField::new(missing, &ty, &record.loc());
+                    return Err(to_errs(err.into()));
+                }
+                let record = ast::ExpressionRecord::new(fields, ty).with_loc(record);
+                Ok(ast::Expression::Record(record))
+            }
         }
     }
 
@@ -72,94 +412,928 @@ impl Typer {
     ) -> Result<ast::Expression> {
         use cst::expression::Expression1;
         match expression {
-            Expression1::TypedExpression { expr, ty, .. } => {
-                let (expr, ty) = {
-                    let expr_res = self.expression0(expr);
-                    let ty_res = self.ty(ty);
-                    expr_res.combine(ty_res)?
+            Expression1::Application { fun, arg } => {
+                let (fun, arg) = {
+                    let fun_res = self.expression1(fun);
+                    let arg_res = self.expression0(arg);
+                    fun_res.combine(arg_res)?
+                };
+                let (arg, ty) = match fun.ty().unfold() {
+                    ast::Type::Arrow(arrow) => {
+                        (Self::operand(arg, arrow.arg())?, arrow.ret().clone())
+                    }
+                    ast::Type::Meta(_) => {
+                        let ty_ret = ast::Type::Meta(ast::TypeMeta::new());
+                        let ty_fun =
+                            ast::Type::Arrow(ast::TypeArrow::new(arg.ty().clone(), ty_ret.clone()));
+                        if !fun.ty().is_compatible(&ty_fun) {
+                            let err = ErrorUnexpectedType::new(&ty_fun, fun.ty(), &fun.loc());
+                            return Err(to_errs(err.into()));
+                        }
+                        (arg, ty_ret)
+                    }
+                    _ => {
+                        let err = ErrorNotFunction::new(fun.ty(), &fun.loc());
+                        return Err(to_errs(err.into()));
+                    }
                 };
-                expr.restrict_ty(ty)
-                    .map_err(|e| Error::from(*e))
-                    .map_err(to_errs)
+                let app = ast::ExpressionApplication::new(fun, arg, ty).with_loc(expression);
+                Ok(ast::Expression::Application(app))
+            }
+            Expression1::Reference { op, expr } => {
+                use cst::operator::ReferenceOperatorT;
+                let expr = self.expression1(expr)?;
+                match op.value {
+                    ReferenceOperatorT::Alloc => {
+                        let alloc = ast::ExpressionAlloc::new(expr).with_loc(expression);
+                        Ok(ast::Expression::Alloc(alloc))
+                    }
+                    ReferenceOperatorT::Deref => {
+                        let ty = ast::Type::Meta(ast::TypeMeta::new());
+                        let ty_ref = ast::Type::Reference(ast::TypeReference::new(ty.clone()));
+                        let expr = Self::operand(expr, &ty_ref)?;
+                        let deref = ast::ExpressionDeref::new(expr, ty).with_loc(expression);
+                        Ok(ast::Expression::Deref(deref))
+                    }
+                }
             }
             Expression1::Expression0(expr) => self.expression0(expr),
         }
     }
 
-    /// convert expression1
-    pub fn expression(
+    /// check that references of the environment used by variable `id` are declared
+    /// by the definition being typed
+    fn check_effects(&self, id: &ast::Identifier, loc: &Location) -> Result<()> {
+        let Some(declared) = &self.effects else {
+            return Ok(());
+        };
+        let errs = self
+            .env
+            .get_effects(id)
+            .iter()
+            .filter(|reference| !declared.contains(reference))
+            .map(|reference| {
+                let ty = self
+                    .env
+                    .get_expr_ty(reference)
+                    .cloned()
+                    .unwrap_or_else(|| ast::Type::Meta(ast::TypeMeta::new()));
+                let through = (reference != id).then_some(id);
+                ErrorUndeclaredReference::new(reference, &ty, through, loc).into()
+            })
+            .collect::<Vec<_>>();
+        if errs.is_empty() {
+            Ok(())
+        } else {
+            Err(errs)
+        }
+    }
+
+    /// check that operand has the type `ty` expected by its operator or construct,
+    /// a number is coerced to a larger number type
+    pub(super) fn operand(operand: ast::Expression, ty: &ast::Type) -> Result<ast::Expression> {
+        if operand.ty().is_subtype(ty) {
+            let coercion = ast::ExpressionCoercion::new(operand, ty.clone());
+            Ok(ast::Expression::Coercion(coercion))
+        } else if operand.ty().is_compatible(ty) {
+            Ok(operand)
+        } else {
+            let err = ErrorUnexpectedType::new(ty, operand.ty(), &operand.loc());
+            Err(to_errs(err.into()))
+        }
+    }
+
+    /// give expression the type `ty` of an annotation,
+    /// a number is coerced to a larger number type
+    fn annotate(expr: ast::Expression, ty: ast::Type) -> Result<ast::Expression> {
+        if expr.ty().is_subtype(&ty) {
+            return Ok(ast::Expression::Coercion(ast::ExpressionCoercion::new(
+                expr, ty,
+            )));
+        }
+        expr.restrict_ty(ty)
+            .map_err(|e| Error::from(*e))
+            .map_err(to_errs)
+    }
+
+    /// check that operand is a proposition, a boolean `b` stands for the proposition `b = true`
+    fn proposition(operand: ast::Expression) -> Result<ast::Expression> {
+        let bool = ast::TypeBuiltin::bool();
+        match operand.ty().unfold() {
+            ast::Type::Builtin(builtin) if *builtin == bool => Ok(operand),
+            _ => Self::operand(operand, &ast::Type::Builtin(ast::TypeBuiltin::prop())),
+        }
+    }
+
+    /// convert expression2
+    fn expression2(
+        &mut self,
+        expression: &cst::expression::Expression2,
+    ) -> Result<ast::Expression> {
+        use cst::expression::Expression2;
+        match expression {
+            Expression2::Binary { left, op, right } => {
+                let (left, right) = {
+                    let left_res = self.expression2(left);
+                    let right_res = self.expression2(right);
+                    left_res.combine(right_res)?
+                };
+                let (ty, ty_ret) = op.value.signature(op.value.operand(left.ty(), right.ty()));
+                let (left, right) = if op.value.is_connective() {
+                    let left_res = Self::proposition(left);
+                    let right_res = Self::proposition(right);
+                    left_res.combine(right_res)?
+                } else {
+                    let left_res = Self::operand(left, &ty);
+                    let right_res = Self::operand(right, &ty);
+                    left_res.combine(right_res)?
+                };
+                let binary =
+                    ast::ExpressionBinary::new(op.value, left, right, ty_ret).with_loc(expression);
+                Ok(ast::Expression::Binary(binary))
+            }
+            Expression2::Unary { op, expr } => {
+                let expr = self.expression2(expr)?;
+                let (ty, _) = op.value.signature(expr.ty());
+                let expr = Self::operand(expr, &ty)?;
+                let unary = ast::ExpressionUnary::new(op.value, expr).with_loc(expression);
+                Ok(ast::Expression::Unary(unary))
+            }
+            Expression2::Assign {
+                reference, value, ..
+            } => {
+                let (reference, value) = {
+                    let reference_res = self.expression2(reference);
+                    let value_res = self.expression2(value);
+                    reference_res.combine(value_res)?
+                };
+                let ty_ref = ast::Type::Reference(ast::TypeReference::new(value.ty().clone()));
+                let reference = Self::operand(reference, &ty_ref)?;
+                let assign = ast::ExpressionAssign::new(reference, value).with_loc(expression);
+                Ok(ast::Expression::Assign(assign))
+            }
+            Expression2::Expression1(expr) => self.expression1(expr),
+        }
+    }
+
+    /// convert expression3
+    fn expression3(
+        &mut self,
+        expression: &cst::expression::Expression3,
+    ) -> Result<ast::Expression> {
+        use cst::expression::Expression3;
+        match expression {
+            Expression3::TypedExpression { expr, ty, .. } => {
+                let ty = self.ty(ty);
+                let expr_res = match (Self::record_literal2(expr), &ty) {
+                    (Some(record), Ok(ty)) => self
+                        .record(record, Some(ty))
+                        .map(|record| record.with_loc(expr)),
+                    (_, _) => self.expression2(expr),
+                };
+                let (expr, ty) = expr_res.combine(ty)?;
+                Self::annotate(expr, ty)
+            }
+            Expression3::Expression2(expr) => self.expression2(expr),
+        }
+    }
+
+    /// convert expression4
+    fn expression4(
+        &mut self,
+        expression: &cst::expression::Expression4,
+    ) -> Result<ast::Expression> {
+        use cst::expression::Expression4;
+        match expression {
+            Expression4::Tuple { first, rest } => {
+                let elems_res = std::iter::once(first)
+                    .chain(rest.iter().map(|(_, expr)| expr))
+                    .map(|expr| self.expression3(expr))
+                    .collect::<Vec<_>>();
+                let elems = combine_all(elems_res)?;
+                let tuple = ast::ExpressionTuple::new(elems).with_loc(expression);
+                Ok(ast::Expression::Tuple(tuple))
+            }
+            Expression4::Expression3(expr) => self.expression3(expr),
+        }
+    }
+
+    /// convert expression5
+    fn expression5(
+        &mut self,
+        expression: &cst::expression::Expression5,
+    ) -> Result<ast::Expression> {
+        use cst::expression::Expression5;
+        match expression {
+            Expression5::Abstraction { pattern, body, .. } => {
+                let ty_arg = self
+                    .pattern_ty(pattern)?
+                    .unwrap_or_else(|| ast::Type::Meta(ast::TypeMeta::new()));
+                let pattern = self.pattern(pattern, &ty_arg)?;
+                let body_res = self.expression5(body);
+                for id in pattern.names() {
+                    self.id_builder.pop(id);
+                }
+                let check_res = matching::check(&self.env, &ty_arg, [&pattern], pattern.loc());
+                let (body, ()) = body_res.combine(check_res)?;
+                let abs =
+                    ast::ExpressionAbstraction::new(pattern, ty_arg, body).with_loc(expression);
+                Ok(ast::Expression::Abstraction(abs))
+            }
+            Expression5::Match { branches } => {
+                let tys_res = branches
+                    .iter()
+                    .map(|branch| self.pattern_ty(&branch.pattern))
+                    .collect::<Vec<_>>();
+                let ty_arg = combine_all(tys_res)?
+                    .into_iter()
+                    .flatten()
+                    .next()
+                    .unwrap_or_else(|| ast::Type::Meta(ast::TypeMeta::new()));
+                let branches_res = branches
+                    .iter()
+                    .map(|branch| self.branch(branch, &ty_arg))
+                    .collect::<Vec<_>>();
+                let branches = combine_all(branches_res)?;
+
+                let ty_ret = branches[0].body().ty().clone();
+                let errs = branches
+                    .iter()
+                    .map(|branch| branch.body())
+                    .filter(|body| !body.ty().is_compatible(&ty_ret))
+                    .map(|body| ErrorUnexpectedType::new(&ty_ret, body.ty(), &body.loc()).into())
+                    .collect::<Vec<_>>();
+                if !errs.is_empty() {
+                    return Err(errs);
+                }
+                let patterns = branches.iter().map(|branch| branch.pattern().as_ref());
+                matching::check(&self.env, &ty_arg, patterns, expression.loc())?;
+
+                let expr_match = ast::ExpressionMatch::new(branches, ty_arg, ty_ret);
+                Ok(ast::Expression::Match(expr_match.with_loc(expression)))
+            }
+            Expression5::If {
+                cond,
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                let bool = ast::Type::Builtin(ast::TypeBuiltin::bool());
+                let cond_res = self
+                    .expression5(cond)
+                    .and_then(|cond| Self::operand(cond, &bool));
+                let then_res = self.expression5(then_branch);
+                let else_res = self.expression5(else_branch);
+                let ((cond, then_branch), else_branch) =
+                    cond_res.combine(then_res).combine(else_res)?;
+                // both branches are coerced to the largest of their types
+                let ty = then_branch.ty().join(else_branch.ty());
+                let (then_branch, else_branch) =
+                    Self::operand(then_branch, &ty).combine(Self::operand(else_branch, &ty))?;
+                let expr_if =
+                    ast::ExpressionIf::new(cond, then_branch, else_branch).with_loc(expression);
+                Ok(ast::Expression::If(expr_if))
+            }
+            Expression5::Let {
+                pattern,
+                value,
+                body,
+                ..
+            } => {
+                // the value is typed before binding, the pattern is only visible in the body
+                let value = self.expression5(value)?;
+                let pattern = self.pattern(pattern, value.ty())?;
+                // as for a definition, a value is generalised, but not over the unknown
+                // types of the visible names or of the instances still to resolve
+                if value.is_value() {
+                    let fixed = self
+                        .id_builder
+                        .visible()
+                        .filter(|id| pattern.names().all(|name| name != id.as_ref()))
+                        .filter_map(|id| self.env.get_expr_ty(id))
+                        .chain(self.instances.iter().map(|instance| instance.ty()))
+                        .flat_map(ast::Type::metas)
+                        .collect::<Vec<_>>();
+                    let binders = self.generalize_except(value.ty(), &[], fixed);
+                    for id in pattern.names() {
+                        self.env.set_binders(id, binders.clone());
+                    }
+                }
+                let body_res = self.expression5(body);
+                for id in pattern.names() {
+                    self.id_builder.pop(id);
+                }
+                let check_res = matching::check(&self.env, value.ty(), [&pattern], pattern.loc());
+                let (body, ()) = body_res.combine(check_res)?;
+                let expr_let = ast::ExpressionLet::new(pattern, value, body).with_loc(expression);
+                Ok(ast::Expression::Let(expr_let))
+            }
+            Expression5::Quantifier {
+                kw,
+                vars,
+                typed,
+                body,
+                ..
+            } => {
+                let ty_var = match typed {
+                    Some((_, ty)) => self.ty(ty)?,
+                    None => ast::Type::Meta(ast::TypeMeta::new()),
+                };
+                let vars = vars
+                    .iter()
+                    .map(|var| {
+                        let id = self.id_builder.build(var.name());
+                        self.env.add_expr_def(id.clone(), ty_var.clone(), var.loc());
+                        ast::PatternVar::from(id).with_loc(var)
+                    })
+                    .collect::<Vec<_>>();
+                let body_res = self.expression5(body).and_then(Self::proposition);
+                for var in vars.iter().rev() {
+                    self.id_builder.pop(var.identifier());
+                }
+                let quantifier = ast::ExpressionQuantifier::new(kw.value, vars, ty_var, body_res?)
+                    .with_loc(expression);
+                Ok(ast::Expression::Quantifier(quantifier))
+            }
+            Expression5::Expression4(expr) => self.expression4(expr),
+        }
+    }
+
+    /// convert branch of pattern matching on type `ty`
+    fn branch(
+        &mut self,
+        branch: &cst::expression::Branch,
+        ty: &ast::Type,
+    ) -> Result<ast::MatchBranch> {
+        let pattern = self.pattern(&branch.pattern, ty)?;
+        let body_res = self.expression5(&branch.body);
+        for id in pattern.names() {
+            self.id_builder.pop(id);
+        }
+        Ok(ast::MatchBranch::new(pattern, body_res?))
+    }
+
+    /// convert expression
+    pub(super) fn expression(
         &mut self,
         expression: &cst::expression::Expression,
     ) -> Result<ast::Expression> {
-        self.expression1(expression)
+        self.expression5(expression)
             .map(|expr_ast| expr_ast.with_loc(expression))
     }
 
-    /// convert type
-    pub fn ty(&mut self, ty: &cst::Type) -> Result<ast::Type> {
+    /// convert type0
+    fn ty0(&mut self, ty: &cst::ty::Type0) -> Result<ast::Type> {
+        use cst::ty::Type0;
         match ty {
-            cst::Type::Builtin(Meta { value: builtin, .. }) => {
+            Type0::Builtin(Meta { value: builtin, .. }) => {
                 use cst::ty::BuiltinT;
                 let builtin = match builtin {
                     BuiltinT::Nat => ast::TypeBuiltin::nat(),
+                    BuiltinT::Int => ast::TypeBuiltin::int(),
+                    BuiltinT::Rat => ast::TypeBuiltin::rat(),
                     BuiltinT::Bool => ast::TypeBuiltin::bool(),
                     BuiltinT::Char => ast::TypeBuiltin::char(),
+                    BuiltinT::String => ast::TypeBuiltin::string(),
+                    BuiltinT::Prop => ast::TypeBuiltin::prop(),
                 };
                 Ok(ast::Type::Builtin(builtin))
             }
-            cst::Type::Variable(ty_var) => {
-                let loc = ty_var.loc();
+            Type0::Variable(ty_var) => {
                 let id = self.id_builder.get(ty_var.name());
-                let alias = self
-                    .env
-                    .get_alias_ty(&id, loc.clone())
-                    .map_err(Error::from)
-                    .map_err(to_errs)?;
-                Ok(ast::Type::Alias(alias))
+                self.env
+                    .get_ty(&id, Vec::new(), ty_var.loc())
+                    .map_err(|e| to_errs(*e))
             }
+            Type0::Unit(_) => Ok(ast::Type::Builtin(ast::TypeBuiltin::unit())),
+            Type0::Paren(ty) => self.ty(ty.inner()),
         }
         .map(|ast_ty| ast_ty.with_loc(ty))
     }
 
-    fn pattern(&mut self, pattern: &cst::Pattern, ty: &ast::Type) -> Result<ast::Pattern> {
-        use cst::Pattern;
+    /// convert type1
+    fn ty1(&mut self, ty: &cst::ty::Type1) -> Result<ast::Type> {
+        use cst::ty::Type1;
+        match ty {
+            Type1::Application { fun, args } => {
+                let args_res = args.iter().map(|ty| self.ty0(ty)).collect::<Vec<_>>();
+                let args = combine_all(args_res)?;
+                let id = self.id_builder.get(fun.name());
+                self.env
+                    .get_ty(&id, args, fun.loc())
+                    .map_err(|e| to_errs(*e))
+            }
+            Type1::Reference { ty, .. } => {
+                let ty = self.ty0(ty)?;
+                Ok(ast::Type::Reference(ast::TypeReference::new(ty)))
+            }
+            Type1::Type0(ty) => self.ty0(ty),
+        }
+        .map(|ast_ty| ast_ty.with_loc(ty))
+    }
+
+    /// convert type2
+    fn ty2(&mut self, ty: &cst::ty::Type2) -> Result<ast::Type> {
+        use cst::ty::Type2;
+        match ty {
+            Type2::Product { first, rest } => {
+                let tys_res = std::iter::once(first)
+                    .chain(rest.iter().map(|(_, ty)| ty))
+                    .map(|ty| self.ty1(ty))
+                    .collect::<Vec<_>>();
+                let tys = combine_all(tys_res)?;
+                Ok(ast::Type::Product(ast::TypeProduct::new(tys)))
+            }
+            Type2::Type1(ty) => self.ty1(ty),
+        }
+        .map(|ast_ty| ast_ty.with_loc(ty))
+    }
+
+    /// convert type
+    pub fn ty(&mut self, ty: &cst::Type) -> Result<ast::Type> {
+        use cst::ty::Type3;
+        match ty {
+            Type3::Arrow { arg, ret, .. } => {
+                let (arg, ret) = {
+                    let arg_res = self.ty2(arg);
+                    let ret_res = self.ty(ret);
+                    arg_res.combine(ret_res)?
+                };
+                Ok(ast::Type::Arrow(ast::TypeArrow::new(arg, ret)))
+            }
+            Type3::Type2(ty) => self.ty2(ty),
+        }
+        .map(|ast_ty| ast_ty.with_loc(ty))
+    }
+
+    /// bind type variables `vars`, to be popped after use
+    fn ty_binders(&mut self, vars: &[cst::ty::Variable]) -> Vec<Rc<ast::Identifier>> {
+        vars.iter()
+            .map(|var| {
+                let id = self.id_builder.build(var.name());
+                self.env.add_type_var(id.clone(), var.loc());
+                id
+            })
+            .collect()
+    }
+
+    /// get constructor type of name if it is a constructor
+    fn constructor_ty(&self, name: &str) -> Option<ast::Type> {
+        let id = self.id_builder.get(name);
+        self.env
+            .get_constructor(&id)
+            .map(|ctor| ctor.instantiate_union())
+    }
+
+    /// get type annotation of pattern0
+    fn pattern0_ty(&mut self, pattern: &cst::pattern::Pattern0) -> Result<Option<ast::Type>> {
+        use cst::pattern::Pattern0;
+        match pattern {
+            Pattern0::Variable(var) => Ok(self.constructor_ty(var.name())),
+            Pattern0::Typed(typed) => self.ty(&typed.inner().ty).map(Some),
+            Pattern0::Paren(pattern) => self.pattern_ty(pattern.inner()),
+        }
+    }
+
+    /// get type annotation of pattern1
+    fn pattern1_ty(&mut self, pattern: &cst::pattern::Pattern1) -> Result<Option<ast::Type>> {
+        use cst::pattern::Pattern1;
+        match pattern {
+            Pattern1::Constructor { name, .. } => Ok(self.constructor_ty(name.name())),
+            Pattern1::Pattern0(pattern) => self.pattern0_ty(pattern),
+        }
+    }
+
+    /// get type annotation of pattern
+    fn pattern_ty(&mut self, pattern: &cst::Pattern) -> Result<Option<ast::Type>> {
+        use cst::pattern::Pattern2;
         match pattern {
-            Pattern::Variable(var) => {
+            Pattern2::Tuple { first, rest } => {
+                let tys_res = std::iter::once(first)
+                    .chain(rest.iter().map(|(_, pattern)| pattern))
+                    .map(|pattern| self.pattern1_ty(pattern))
+                    .collect::<Vec<_>>();
+                let tys = combine_all(tys_res)?
+                    .into_iter()
+                    .collect::<Option<Vec<_>>>();
+                Ok(tys.map(|tys| ast::Type::Product(ast::TypeProduct::new(tys))))
+            }
+            Pattern2::Pattern1(pattern) => self.pattern1_ty(pattern),
+        }
+    }
+
+    /// convert constructor pattern `name args` of type `ty`
+    fn constructor_pattern(
+        &mut self,
+        name: &cst::pattern::Variable,
+        args: &[cst::pattern::Pattern0],
+        loc: &impl Located,
+        ty: &ast::Type,
+    ) -> Result<ast::Pattern> {
+        let id = self.id_builder.get(name.name());
+        let ctor = match self.env.get_constructor(&id) {
+            Some(ctor) => ctor.clone(),
+            None => {
+                let err = ErrorNotConstructor::new(&id, &name.loc());
+                return Err(to_errs(err.into()));
+            }
+        };
+        let ty_ctor = ctor.instantiate_union();
+        if !ty.is_compatible(&ty_ctor) {
+            let err = ErrorPatternMismatch::new(ty, &loc.loc());
+            return Err(to_errs(err.into()));
+        }
+        if ctor.args().len() != args.len() {
+            let err = ErrorConstructorArity::new(&id, ctor.args().len(), args.len(), &loc.loc());
+            return Err(to_errs(err.into()));
+        }
+        // constructor is a reference to its definition
+        let _ = self.env.get_expr_var(&id, name.loc());
+
+        let tys_args = ty_ctor
+            .as_union()
+            .map(|union| ctor.args_of(union))
+            .unwrap_or_default();
+        let args_res = args
+            .iter()
+            .zip(&tys_args)
+            .map(|(arg, ty)| self.pattern0(arg, ty))
+            .collect::<Vec<_>>();
+        let args = combine_all(args_res)?;
+        let pattern = ast::PatternConstructor::new(ctor.id().clone(), args).with_loc(loc);
+        Ok(ast::Pattern::Constructor(pattern))
+    }
+
+    /// convert pattern0 of type `ty`
+    fn pattern0(
+        &mut self,
+        pattern: &cst::pattern::Pattern0,
+        ty: &ast::Type,
+    ) -> Result<ast::Pattern> {
+        use cst::pattern::Pattern0;
+        match pattern {
+            Pattern0::Variable(var) if self.constructor_ty(var.name()).is_some() => {
+                self.constructor_pattern(var, &[], var, ty)
+            }
+            Pattern0::Variable(var) if var.name().contains('.') => {
+                let id = self.id_builder.get(var.name());
+                let err = ErrorNotConstructor::new(&id, &var.loc());
+                Err(to_errs(err.into()))
+            }
+            Pattern0::Variable(var) => {
                 let id = self.id_builder.build(var.name());
                 self.env.add_expr_def(id.clone(), ty.clone(), var.loc());
                 let pattern_var = ast::PatternVar::from(id).with_loc(var);
                 Ok(ast::Pattern::Variable(pattern_var))
             }
+            Pattern0::Typed(typed) => {
+                let typed = typed.inner();
+                let ty_annot = self.ty(&typed.ty)?;
+                if !ty.is_compatible(&ty_annot) {
+                    let err = ErrorUnexpectedType::new(&ty_annot, ty, &ty_annot.loc());
+                    return Err(to_errs(err.into()));
+                }
+                self.pattern(&typed.pattern, &ty_annot)
+            }
+            Pattern0::Paren(pattern) => self.pattern(pattern.inner(), ty),
+        }
+    }
+
+    /// convert pattern1 of type `ty`
+    fn pattern1(
+        &mut self,
+        pattern: &cst::pattern::Pattern1,
+        ty: &ast::Type,
+    ) -> Result<ast::Pattern> {
+        use cst::pattern::Pattern1;
+        match pattern {
+            Pattern1::Constructor { name, args } => {
+                self.constructor_pattern(name, args, pattern, ty)
+            }
+            Pattern1::Pattern0(pattern) => self.pattern0(pattern, ty),
+        }
+    }
+
+    /// convert pattern of type `ty`
+    fn pattern(&mut self, pattern: &cst::Pattern, ty: &ast::Type) -> Result<ast::Pattern> {
+        use cst::pattern::Pattern2;
+        match pattern {
+            Pattern2::Tuple { first, rest } => {
+                let patterns = std::iter::once(first)
+                    .chain(rest.iter().map(|(_, pattern)| pattern))
+                    .collect::<Vec<_>>();
+                let ty_tuple = ast::Type::Product(ast::TypeProduct::new(
+                    patterns
+                        .iter()
+                        .map(|_| ast::Type::Meta(ast::TypeMeta::new()))
+                        .collect(),
+                ));
+                let tys = match ty_tuple.as_product() {
+                    Some(product) if ty.is_compatible(&ty_tuple) => product.tys(),
+                    _ => {
+                        let err = ErrorPatternMismatch::new(ty, &pattern.loc());
+                        return Err(to_errs(err.into()));
+                    }
+                };
+                let patterns_res = patterns
+                    .into_iter()
+                    .zip(tys)
+                    .map(|(pattern, ty)| self.pattern1(pattern, ty))
+                    .collect::<Vec<_>>();
+                let patterns = combine_all(patterns_res)?;
+                let tuple = ast::PatternTuple::from(patterns).with_loc(pattern);
+                Ok(ast::Pattern::Tuple(tuple))
+            }
+            Pattern2::Pattern1(pattern) => self.pattern1(pattern, ty),
+        }
+    }
+
+    /// name and parameters of function head `f p1 ⋯ pn`, a pattern applying a name
+    /// that is not a constructor, which defines `f` as `p1 => ⋯ => pn => body`
+    fn function_head<'a>(
+        &self,
+        pattern: &'a cst::Pattern,
+    ) -> Option<(cst::Pattern, &'a [cst::pattern::Pattern0])> {
+        use cst::pattern::{Pattern0, Pattern1, Pattern2};
+        match pattern {
+            Pattern2::Pattern1(Pattern1::Constructor { name, args })
+                if self.constructor_ty(name.name()).is_none() =>
+            {
+                let name = Pattern2::Pattern1(Pattern1::Pattern0(Pattern0::Variable(name.clone())));
+                Some((name, args))
+            }
+            _ => None,
+        }
+    }
+
+    /// convert clause of a contract, a boolean or a proposition
+    fn clause(&mut self, contract: &cst::expression_definition::Contract) -> Result<ast::Clause> {
+        let expr = self
+            .expression(&contract.expr)
+            .and_then(Self::proposition)?;
+        Ok(ast::Clause::new(
+            contract.keyword.value,
+            expr,
+            contract.expr.clone(),
+        ))
+    }
+
+    /// convert body of definition as a function of `params`, its result has type `ty_ret`,
+    /// preconditions see the parameters, postconditions also see the result as `r`
+    fn function_body(
+        &mut self,
+        definition: &cst::ExpressionDefinition,
+        params: &[cst::pattern::Pattern0],
+        ty_ret: Option<&ast::Type>,
+    ) -> Result<(ast::Expression, Option<Rc<ast::Contract>>)> {
+        use cst::expression_definition::ContractKeywordT;
+        let params_res = params
+            .iter()
+            .map(|param| {
+                let ty = self
+                    .pattern0_ty(param)?
+                    .unwrap_or_else(|| ast::Type::Meta(ast::TypeMeta::new()));
+                let pattern = self.pattern0(param, &ty)?;
+                Ok((pattern, ty))
+            })
+            .collect::<Vec<_>>();
+        let names = params_res
+            .iter()
+            .flatten()
+            .flat_map(|(pattern, _)| pattern.names().cloned())
+            .collect::<Vec<_>>();
+
+        let (requires, ensures): (Vec<_>, Vec<_>) = definition
+            .contracts
+            .iter()
+            .partition(|contract| contract.keyword.value == ContractKeywordT::Requires);
+        let requires_res = requires
+            .into_iter()
+            .map(|contract| self.clause(contract))
+            .collect::<Vec<_>>();
+        let body_res = match ty_ret {
+            Some(ty) => self
+                .expression_expecting(&definition.body, ty)
+                .and_then(|body| Self::annotate(body, ty.clone())),
+            None => self.expression(&definition.body),
+        };
+        // a parameter named as the result would be hidden in postconditions
+        let shadowed = params_res
+            .iter()
+            .flatten()
+            .filter(|(pattern, _)| {
+                !ensures.is_empty() && pattern.names().any(|id| id.name() == RESULT)
+            })
+            .map(|(pattern, _)| ErrorShadowedResult::new(RESULT, &pattern.loc()).into())
+            .collect::<Vec<_>>();
+        let shadowed_res = if shadowed.is_empty() {
+            Ok(())
+        } else {
+            Err(shadowed)
+        };
+        let (result, ensures_res) = match ensures.first() {
+            Some(first) => {
+                let ty = match &body_res {
+                    Ok(body) => body.ty().clone(),
+                    Err(_) => ast::Type::Meta(ast::TypeMeta::new()),
+                };
+                let result = self.id_builder.build(RESULT);
+                self.env
+                    .add_expr_def(result.clone(), ty, first.keyword.loc());
+                let ensures_res = ensures
+                    .into_iter()
+                    .map(|contract| self.clause(contract))
+                    .collect::<Vec<_>>();
+                self.id_builder.pop(&result);
+                (Some(result), ensures_res)
+            }
+            None => (None, Vec::new()),
+        };
+        for id in &names {
+            self.id_builder.pop(id);
+        }
+
+        let ((((params, requires), body), ensures), ()) = combine_all(params_res)
+            .combine(combine_all(requires_res))
+            .combine(body_res)
+            .combine(combine_all(ensures_res))
+            .combine(shadowed_res)?;
+        for (pattern, ty) in &params {
+            matching::check(&self.env, ty, [pattern], pattern.loc())?;
         }
+        let clauses = requires.into_iter().chain(ensures).collect::<Vec<_>>();
+        let (body, contract) = if clauses.is_empty() {
+            (body, None)
+        } else {
+            let contract = Rc::new(ast::Contract::new(names, result, clauses));
+            let checked = ast::ExpressionChecked::new(contract.clone(), body);
+            (ast::Expression::Checked(checked), Some(contract))
+        };
+        let body = params.into_iter().rev().fold(body, |body, (pattern, ty)| {
+            let abs = ast::ExpressionAbstraction::new(pattern, ty, body).with_loc(definition);
+            ast::Expression::Abstraction(abs)
+        });
+        Ok((body, contract))
     }
 
     /// type expression definition
     fn expression_definition(
         &mut self,
         definition: &cst::ExpressionDefinition,
+        binders: Vec<Rc<ast::Identifier>>,
     ) -> Result<ast::ExpressionDefinition> {
-        let body_res = self.expression(&definition.body);
+        let (pattern, params) = match self.function_head(&definition.pattern) {
+            Some((name, params)) => (std::borrow::Cow::Owned(name), params),
+            None => (std::borrow::Cow::Borrowed(&definition.pattern), &[][..]),
+        };
+        // with parameters, the annotation is the type of the result
         let ty_opt_res = definition.typed_by().map(|ty| self.ty(ty)).transpose();
+        let ty_ret = ty_opt_res.as_ref().ok().cloned().flatten();
+        let body_res = self.function_body(definition, params, ty_ret.as_ref());
         let pattern_res = {
             match (&ty_opt_res, &body_res) {
-                (Ok(Some(ty)), _) => self.pattern(&definition.pattern, ty),
-                (Ok(None), Ok(body)) => self.pattern(&definition.pattern, body.ty()),
+                (Ok(Some(ty)), _) if params.is_empty() => self.pattern(&pattern, ty),
+                (Ok(_), Ok((body, _))) => self.pattern(&pattern, body.ty()),
                 (_, _) => Err(Vec::new()),
             }
         };
+        let body_res = body_res.combine(ty_opt_res).map(|(body, _)| body);
 
-        let body_res = body_res
-            .combine(ty_opt_res)
-            .and_then(|(body, opt_ty)| match opt_ty {
-                Some(ty) => body
-                    .restrict_ty(ty)
-                    .map_err(|e| Error::from(*e))
-                    .map_err(to_errs),
-                None => Ok(body),
-            });
+        if let Ok(pattern) = &pattern_res {
+            for id in pattern.names() {
+                self.env.set_binders(id, binders.clone());
+            }
+        }
+
+        let ((body, contract), pattern) = body_res.combine(pattern_res)?;
+        matching::check(&self.env, body.ty(), [&pattern], pattern.loc())?;
+        let mut binders = binders;
+        // a reference allocated by the body would be shared by all instances
+        if body.is_value() {
+            binders.extend(self.generalize(body.ty(), &binders));
+        } else if !body.ty().metas().is_empty() {
+            self.env.add_weak(body.ty().clone());
+        }
+        // dictionaries of the classes of its type variables are the first parameters
+        let dicts = match &pattern {
+            ast::Pattern::Variable(_) => self.dictionaries(&binders),
+            _ => Vec::new(),
+        };
+        // a definition whose instances cannot be chosen is not bound
+        let body = self.resolve(Ok(body), &dicts).inspect_err(|_| {
+            for id in pattern.names() {
+                self.id_builder.pop(id);
+            }
+        })?;
+        let body = dicts.iter().rev().fold(body, |body, (class, dict)| {
+            let param = ast::Pattern::Variable(ast::PatternVar::from(dict.clone()));
+            let ty = ast::Type::Record(class.clone());
+            let abs = ast::ExpressionAbstraction::new(param, ty, body).with_loc(definition);
+            ast::Expression::Abstraction(abs)
+        });
+        let classes = dicts
+            .into_iter()
+            .map(|(class, _)| class)
+            .collect::<Vec<_>>();
+        for id in pattern.names() {
+            self.env.set_binders(id, binders.clone());
+            self.env.set_constraints(id, classes.clone());
+            if let Some(contract) = &contract {
+                self.env.set_contract(id, contract.clone());
+            }
+        }
+        Ok(ast::ExpressionDefinition::new(pattern, binders, body).with_contract(contract))
+    }
+
+    /// generalise unknown types left in `ty` as type variables, named apart from
+    /// type variables `bound`, except those of definitions that are not generalised
+    pub fn generalize(
+        &mut self,
+        ty: &ast::Type,
+        bound: &[Rc<ast::Identifier>],
+    ) -> Vec<Rc<ast::Identifier>> {
+        self.generalize_except(ty, bound, Vec::new())
+    }
+
+    /// generalise unknown types left in `ty` as type variables, named apart from
+    /// type variables `bound`, except unknown types `fixed` and those of
+    /// definitions that are not generalised
+    fn generalize_except(
+        &mut self,
+        ty: &ast::Type,
+        bound: &[Rc<ast::Identifier>],
+        mut fixed: Vec<ast::TypeMeta>,
+    ) -> Vec<Rc<ast::Identifier>> {
+        const NAMES: &[&str] = &["α", "β", "γ", "δ", "ε", "ζ", "η", "θ", "ι", "κ"];
+        let mut names = NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .chain((1..).map(|n| format!("α{n}")))
+            .filter(|name| bound.iter().all(|id| id.name() != name));
+        fixed.extend(self.env.weak_metas());
+        ty.metas()
+            .into_iter()
+            .filter(|meta| !fixed.contains(meta))
+            .zip(&mut names)
+            .map(|(meta, name)| {
+                let id = self.id_builder.fresh(&name);
+                meta.solve(&ast::Type::Variable(ast::TypeVariable::new(id.clone())));
+                id
+            })
+            .collect()
+    }
+
+    /// convert references of the environment declared by definition,
+    /// each one is a variable of reference type
+    fn env_params(
+        &mut self,
+        definition: &cst::ExpressionDefinition,
+    ) -> Result<Vec<ast::Identifier>> {
+        let params_res = definition
+            .env()
+            .iter()
+            .map(|param| {
+                let id = self.id_builder.get(param.name.name());
+                let (var, ty) = {
+                    let var_res = self
+                        .env
+                        .get_expr_var(&id, param.name.loc())
+                        .map_err(Error::from)
+                        .map_err(to_errs);
+                    let ty_res = self.ty(&param.ty);
+                    var_res.combine(ty_res)?
+                };
+                let ty_ref = ast::Type::Reference(ast::TypeReference::new(ast::Type::Meta(
+                    ast::TypeMeta::new(),
+                )));
+                if !ty.is_compatible(&ty_ref) {
+                    let err = ErrorUnexpectedType::new(&ty_ref, &ty, &param.ty.loc());
+                    return Err(to_errs(err.into()));
+                }
+                Self::operand(ast::Expression::Variable(var), &ty)?;
+                Ok(id.as_ref().clone())
+            })
+            .collect::<Vec<_>>();
+        combine_all(params_res)
+    }
 
-        let (body, pattern) = body_res.combine(pattern_res)?;
-        Ok(ast::ExpressionDefinition::new(pattern, body))
+    /// record references of the environment used by names of definition,
+    /// a name of reference type is itself a reference of the environment
+    fn set_effects(&mut self, def: &ast::ExpressionDefinition, declared: &[ast::Identifier]) {
+        for id in def.pattern().names() {
+            let mut effects = declared.to_vec();
+            if self
+                .env
+                .get_expr_ty(id)
+                .and_then(ast::Type::as_reference)
+                .is_some()
+            {
+                effects.push(id.clone());
+            }
+            if !effects.is_empty() {
+                self.env.set_effects(id, effects);
+            }
+        }
     }
 
     /// convert definition
@@ -168,7 +1342,18 @@ impl Typer {
         definition: &cst::ExpressionDefinition,
         doc_opt: Option<ast::Documentation>,
     ) -> Result<ast::ExpressionDefinition> {
-        let expr_def = self.expression_definition(definition)?;
+        let binders = self.ty_binders(definition.binders());
+        let effects_res = self.env_params(definition);
+        self.effects = effects_res.as_ref().ok().cloned();
+        let expr_def_res = self.expression_definition(definition, binders.clone());
+        self.effects = None;
+        // instances used by a definition that is not typed are not resolved
+        self.instances.clear();
+        for id in &binders {
+            self.id_builder.pop(id);
+        }
+        let (expr_def, effects) = expr_def_res.combine(effects_res)?;
+        self.set_effects(&expr_def, &effects);
         if let Some(doc) = doc_opt {
             for id in expr_def.pattern().names() {
                 self.env.set_doc(id, doc.clone());
@@ -177,20 +1362,527 @@ impl Typer {
         Ok(expr_def)
     }
 
+    /// convert mutually recursive definitions, dictionaries of the classes of their
+    /// type variables are the first parameters of each of them
+    pub fn fixpoint(
+        &mut self,
+        definitions: &[(&cst::ExpressionDefinition, Option<ast::Documentation>)],
+    ) -> Result<ast::Fixpoint> {
+        let heads_res = self.fixpoint_heads(definitions);
+        // names bound before typing the bodies are unbound when a definition fails,
+        // as a failed definition is never bound
+        let names = heads_res
+            .iter()
+            .flatten()
+            .flat_map(|(_, pattern, _, _)| pattern.names().cloned())
+            .collect::<Vec<_>>();
+        let defs_res =
+            combine_all(heads_res).and_then(|heads| self.fixpoint_definitions(definitions, heads));
+        let dicts = match &defs_res {
+            Ok(defs)
+                if defs
+                    .iter()
+                    .all(|def| matches!(def.pattern(), ast::Pattern::Variable(_))) =>
+            {
+                let binders = defs
+                    .iter()
+                    .flat_map(|def| def.binders().iter().cloned())
+                    .collect::<Vec<_>>();
+                self.dictionaries(&binders)
+            }
+            _ => Vec::new(),
+        };
+        match self.resolve(defs_res, &dicts) {
+            Ok(defs) => Ok(ast::Fixpoint::new(self.fixpoint_dictionaries(
+                defs,
+                definitions,
+                dicts,
+            ))),
+            Err(errs) => {
+                for id in &names {
+                    self.id_builder.pop(id);
+                }
+                Err(errs)
+            }
+        }
+    }
+
+    /// bind names of mutually recursive definitions, get their heads
+    fn fixpoint_heads(
+        &mut self,
+        definitions: &[(&cst::ExpressionDefinition, Option<ast::Documentation>)],
+    ) -> Vec<Result<FixpointHead>> {
+        definitions
+            .iter()
+            .map(|(definition, _)| {
+                let binders = self.ty_binders(definition.binders());
+                let effects_res = self.env_params(definition);
+                let head = self.function_head(&definition.pattern);
+                let ty_res = match definition.typed_by() {
+                    Some(ty) if head.is_none() => self.ty(ty),
+                    _ => Ok(ast::Type::Meta(ast::TypeMeta::new())),
+                };
+                let pattern_res = ty_res.and_then(|ty| {
+                    let pattern = match &head {
+                        Some((name, _)) => self.pattern(name, &ty)?,
+                        None => self.pattern(&definition.pattern, &ty)?,
+                    };
+                    Ok((pattern, ty))
+                });
+                for id in &binders {
+                    self.id_builder.pop(id);
+                }
+                let ((pattern, ty), effects) = pattern_res.combine(effects_res)?;
+                Ok((binders, pattern, ty, effects))
+            })
+            .collect()
+    }
+
+    /// convert mutually recursive definitions of heads `heads`, their names are bound
+    /// before typing their bodies
+    fn fixpoint_definitions(
+        &mut self,
+        definitions: &[(&cst::ExpressionDefinition, Option<ast::Documentation>)],
+        heads: Vec<FixpointHead>,
+    ) -> Result<Vec<ast::ExpressionDefinition>> {
+        // calls between definitions are checked against their declared references
+        for (_, pattern, _, effects) in &heads {
+            for id in pattern.names() {
+                self.env.set_effects(id, effects.clone());
+            }
+        }
+
+        let defs_res = definitions
+            .iter()
+            .zip(heads)
+            .map(|((definition, _), (binders, pattern, ty, effects))| {
+                for id in &binders {
+                    self.id_builder.push(id.clone());
+                }
+                self.effects = Some(effects.clone());
+                let params = match self.function_head(&definition.pattern) {
+                    Some((_, params)) => params,
+                    None => &[],
+                };
+                // with parameters, the annotation is the type of the result
+                let ty_ret_res = match definition.typed_by() {
+                    Some(ty) if !params.is_empty() => self.ty(ty).map(Some),
+                    _ => Ok(None),
+                };
+                let body_res = ty_ret_res.and_then(|ty_ret| {
+                    let (body, contract) =
+                        self.function_body(definition, params, ty_ret.as_ref())?;
+                    let body = body
+                        .restrict_ty(ty)
+                        .map_err(|e| Error::from(*e))
+                        .map_err(to_errs)?;
+                    Ok((body, contract))
+                });
+                self.effects = None;
+                for id in &binders {
+                    self.id_builder.pop(id);
+                }
+                let (body, contract) = body_res?;
+                matching::check(&self.env, body.ty(), [&pattern], pattern.loc())?;
+                if let Some(contract) = &contract {
+                    for id in pattern.names() {
+                        self.env.set_contract(id, contract.clone());
+                    }
+                }
+                let def =
+                    ast::ExpressionDefinition::new(pattern, binders, body).with_contract(contract);
+                Ok((def, effects))
+            })
+            .collect::<Vec<_>>();
+        let defs = combine_all(defs_res)?
+            .into_iter()
+            .map(|(def, effects)| {
+                self.set_effects(&def, &effects);
+                def
+            })
+            .collect::<Vec<_>>();
+        termination::check(&defs)?;
+
+        // unknown types shared by definitions are generalised together
+        let bound = defs
+            .iter()
+            .flat_map(|def| def.binders().iter().cloned())
+            .collect::<Vec<_>>();
+        let metas_defs = defs.iter().map(|def| def.ty().metas()).collect::<Vec<_>>();
+        let ty_group = ast::Type::Product(ast::TypeProduct::new(
+            defs.iter().map(|def| def.ty().clone()).collect(),
+        ));
+        let metas_group = ty_group.metas();
+        let generalized = self.generalize(&ty_group, &bound);
+
+        let defs = defs
+            .into_iter()
+            .zip(metas_defs)
+            .zip(definitions)
+            .map(|((def, metas), (_, doc_opt))| {
+                let mut binders = def.binders().to_vec();
+                binders.extend(metas.iter().filter_map(|meta| {
+                    let index = metas_group.iter().position(|other| other == meta)?;
+                    Some(generalized[index].clone())
+                }));
+                for id in def.pattern().names() {
+                    self.env.set_binders(id, binders.clone());
+                    if let Some(doc) = doc_opt {
+                        self.env.set_doc(id, doc.clone());
+                    }
+                }
+                def.with_binders(binders)
+            })
+            .collect();
+        Ok(defs)
+    }
+
+    /// add constructor of union type `union`
+    fn constructor(
+        &mut self,
+        constructor: &cst::ty_definition::Constructor,
+        union: &Rc<ast::Identifier>,
+        ty_union: &ast::Type,
+    ) -> Result<ast::DefinitionConstructor> {
+        use cst::ty_definition::ConstructorArgs;
+        let (args, ty) = match &constructor.args {
+            ConstructorArgs::Typed { ty, .. } => {
+                let ty = self.ty(ty)?;
+                let mut args = Vec::new();
+                let mut ty_ret = &ty;
+                while !ty_ret.is_compatible(ty_union) {
+                    match ty_ret.as_arrow() {
+                        Some(arrow) => {
+                            args.push(arrow.arg().clone());
+                            ty_ret = arrow.ret();
+                        }
+                        None => {
+                            let err = ErrorUnexpectedType::new(ty_union, ty_ret, &ty_ret.loc());
+                            return Err(to_errs(err.into()));
+                        }
+                    }
+                }
+                (args, ty)
+            }
+            ConstructorArgs::Args(tys) => {
+                let args_res = tys.iter().map(|ty| self.ty0(ty)).collect::<Vec<_>>();
+                let args = combine_all(args_res)?;
+                let ty = args.iter().rev().fold(ty_union.clone(), |ret, arg| {
+                    ast::Type::Arrow(ast::TypeArrow::new(arg.clone(), ret))
+                });
+                (args, ty)
+            }
+        };
+        let id = self.id_builder.build(constructor.name.name());
+        self.env.add_constructor(
+            id.clone(),
+            union.clone(),
+            args.clone(),
+            ty.clone(),
+            constructor.name.loc(),
+        );
+        Ok(ast::DefinitionConstructor::new(id, args, ty))
+    }
+
     /// add type definition
     pub fn type_definition(
         &mut self,
         definition: &cst::TypeDefinition,
         doc: Option<ast::Documentation>,
-    ) -> Result<()> {
+    ) -> Result<ast::TypeDefinition> {
         let id = self.id_builder.build(definition.name.name());
-        let ty = self.ty(&definition.ty)?;
-        self.env
-            .add_type_def(id.clone(), ty.clone(), definition.name.loc());
+        let params = self.ty_binders(&definition.params);
+        let def_res = self.type_definition_body(definition, &id, &params);
+        for param in &params {
+            self.id_builder.pop(param);
+        }
+        let (ty, constructors, fields) = def_res?;
         if let Some(doc) = doc {
             self.env.set_doc(&id, doc.clone());
         }
-        Ok(())
+        Ok(ast::TypeDefinition::new(
+            id,
+            params,
+            ty,
+            constructors,
+            fields,
+            definition.loc(),
+        ))
+    }
+
+    /// add body of type definition `id` with type parameters `params`
+    fn type_definition_body(
+        &mut self,
+        definition: &cst::TypeDefinition,
+        id: &Rc<ast::Identifier>,
+        params: &[Rc<ast::Identifier>],
+    ) -> Result<(
+        ast::Type,
+        Vec<ast::DefinitionConstructor>,
+        Vec<ast::DefinitionField>,
+    )> {
+        use cst::ty_definition::TypeDefinitionBody;
+        match &definition.body {
+            TypeDefinitionBody::Alias(ty) => {
+                let ty = self.ty(ty)?;
+                self.env
+                    .add_type_def(id.clone(), ty.clone(), definition.name.loc());
+                self.env.set_binders(id, params.to_vec());
+                Ok((ty, Vec::new(), Vec::new()))
+            }
+            TypeDefinitionBody::Union(constructors) => {
+                self.env
+                    .add_union_def(id.clone(), params.to_vec(), definition.name.loc());
+                let args = params
+                    .iter()
+                    .map(|param| ast::Type::Variable(ast::TypeVariable::new(param.clone())))
+                    .collect();
+                let ty = ast::Type::Union(ast::TypeUnion::new(id.clone(), args))
+                    .with_loc(&definition.name);
+                let constructors_res = constructors
+                    .iter()
+                    .map(|constructor| self.constructor(constructor, id, &ty))
+                    .collect::<Vec<_>>();
+                Ok((ty, combine_all(constructors_res)?, Vec::new()))
+            }
+            TypeDefinitionBody::Record(record) => {
+                self.env
+                    .add_record_def(id.clone(), params.to_vec(), definition.name.loc());
+                let args = params
+                    .iter()
+                    .map(|param| ast::Type::Variable(ast::TypeVariable::new(param.clone())))
+                    .collect();
+                let ty = ast::Type::Record(ast::TypeRecord::new(id.clone(), args))
+                    .with_loc(&definition.name);
+                let fields_res = record
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| {
+                        let name = field.name.name();
+                        if record.fields[..i]
+                            .iter()
+                            .any(|other| other.name.name() == name)
+                        {
+                            let err = ErrorDuplicateField::new(name, &field.name.loc());
+                            return Err(to_errs(err.into()));
+                        }
+                        let ty = self.ty(&field.ty)?;
+                        Ok(ast::DefinitionField::new(name.to_string(), ty))
+                    })
+                    .collect::<Vec<_>>();
+                let fields = combine_all(fields_res)?;
+                self.env.set_fields(id, fields.clone());
+                Ok((ty, Vec::new(), fields))
+            }
+        }
+    }
+
+    /// type evaluated expression
+    pub fn evaluation(&mut self, expr: &cst::Expression) -> Result<ast::Expression> {
+        let expr_res = self.expression(expr);
+        self.resolve(expr_res, &[])
+    }
+
+    /// type of expression with unknown types generalised, with the classes of its
+    /// type variables, the expression is not evaluated so its instances are not resolved
+    pub fn type_of(&mut self, expr: &cst::Expression) -> Result<ast::TypeConstrained> {
+        let expr_res = self.expression(expr);
+        let ty = match expr_res {
+            Ok(expr) => expr.ty().clone(),
+            Err(errs) => {
+                self.instances.clear();
+                return Err(errs);
+            }
+        };
+        let binders = self.generalize(&ty, &[]);
+        let classes = self.classes(&binders);
+        self.instances.clear();
+        Ok(ast::TypeConstrained::new(classes, ty))
+    }
+
+    /// type asserted expression, it must be a boolean
+    pub fn assertion(&mut self, expr: &cst::Expression) -> Result<ast::Expression> {
+        let bool = ast::Type::Builtin(ast::TypeBuiltin::bool());
+        let expr_res = self
+            .expression(expr)
+            .and_then(|expr| Self::operand(expr, &bool));
+        self.resolve(expr_res, &[])
+    }
+
+    /// type statement of theorem and name it
+    fn statement(
+        &mut self,
+        theorem: &cst::Theorem,
+    ) -> Result<(Rc<ast::Identifier>, ast::Expression)> {
+        let statement_res = self
+            .expression(&theorem.statement)
+            .and_then(Self::proposition);
+        let statement = self.resolve(statement_res, &[])?;
+        Ok((self.id_builder.fresh(theorem.name.name()), statement))
+    }
+
+    /// add theorem checked by the kernel
+    fn add_theorem(
+        &mut self,
+        theorem: kernel::Theorem,
+        loc_def: Location,
+        doc_opt: Option<ast::Documentation>,
+    ) -> Rc<kernel::Theorem> {
+        let id = theorem.name().clone();
+        self.id_builder.push(id.clone());
+        let theorem = self.env.add_theorem(theorem, loc_def);
+        if let Some(doc) = doc_opt {
+            self.env.set_doc(&id, doc);
+        }
+        theorem
+    }
+
+    /// type statement of theorem and check its proof with the kernel
+    pub fn theorem(
+        &mut self,
+        theorem: &cst::Theorem,
+        proof: &cst::Expression,
+        doc_opt: Option<ast::Documentation>,
+    ) -> Result<Rc<kernel::Theorem>> {
+        let (id, statement) = self.statement(theorem)?;
+        let proof = kernel::ProofTerm::of(proof)?;
+        let checked = kernel::check(self, id, statement, &proof, theorem.loc())?;
+        Ok(self.add_theorem(checked, theorem.name.loc(), doc_opt))
+    }
+
+    /// type statement of theorem and start its proof by tactics
+    pub fn start_proof(
+        &mut self,
+        theorem: &cst::Theorem,
+        doc_opt: Option<ast::Documentation>,
+    ) -> Result<tactic::Goals> {
+        if let Some(session) = &self.proof {
+            return Err(tactic::in_progress(session, &theorem.name.loc()));
+        }
+        let (id, statement) = self.statement(theorem)?;
+        let formula = kernel::formula(&statement)?;
+        let session = tactic::Session::new(
+            id,
+            statement,
+            formula,
+            doc_opt,
+            theorem.name.loc(),
+            theorem.loc(),
+        );
+        let goals = session.goals();
+        self.proof = Some(session);
+        Ok(goals)
+    }
+
+    /// check that a proof by tactics is in progress
+    pub fn resume_proof(&self, loc: &Location) -> Result<()> {
+        match &self.proof {
+            Some(_) => Ok(()),
+            None => Err(tactic::no_proof(loc)),
+        }
+    }
+
+    /// refine focused goal of proof in progress with a tactic
+    pub fn tactic(
+        &mut self,
+        keyword: &cst::command::TacticKeyword,
+        args: &cst::tactic::TacticArgs,
+    ) -> Result<tactic::Goals> {
+        let Some(mut session) = self.proof.take() else {
+            return Err(tactic::no_proof(&keyword.loc()));
+        };
+        let vars = session.vars();
+        for id in &vars {
+            self.id_builder.push(id.clone());
+        }
+        let res = session.run(self, keyword, args);
+        for id in vars.iter().rev() {
+            self.id_builder.pop(id);
+        }
+        let goals = session.goals();
+        self.proof = Some(session);
+        res.map(|()| goals)
+    }
+
+    /// check proof in progress with the kernel and add its theorem
+    pub fn qed(&mut self, loc: &Location) -> Result<Rc<kernel::Theorem>> {
+        let Some(session) = &self.proof else {
+            return Err(tactic::no_proof(loc));
+        };
+        session.check_complete(loc)?;
+        let session = self.proof.take().expect("proof is in progress");
+        let doc = session.doc().cloned();
+        let loc_def = session.name_loc().clone();
+        let checked = session.qed(self)?;
+        Ok(self.add_theorem(checked, loc_def, doc))
+    }
+
+    /// drop proof in progress
+    pub fn abort(&mut self, loc: &Location) -> Result<()> {
+        match self.proof.take() {
+            Some(_) => Ok(()),
+            None => Err(tactic::no_proof(loc)),
+        }
+    }
+
+    /// start definition of module
+    pub fn module(&mut self, name: &cst::module::Name) {
+        self.id_builder.enter(name.name());
+        self.modules.push(name.loc());
+    }
+
+    /// end definition of module, its public definitions are visible qualified by its name
+    pub fn end_module(&mut self, name: &cst::module::Name) -> Result<()> {
+        let path = self.id_builder.path();
+        match self.id_builder.leave(name.name()) {
+            Some(defined) => {
+                for id in defined {
+                    self.env.set_path(&id, path.clone());
+                }
+                self.modules.pop();
+                Ok(())
+            }
+            None => {
+                let err = ErrorModuleEnd::new(name.name(), path.last().cloned(), &name.loc());
+                Err(to_errs(err.into()))
+            }
+        }
+    }
+
+    /// check that every module of a file is ended at its end, modules left
+    /// open are ended so that they do not extend to the next file
+    pub fn end_of_file(&mut self) -> Result<()> {
+        let path = self.id_builder.path();
+        let locs = std::mem::take(&mut self.modules);
+        let errs = path
+            .iter()
+            .zip(&locs)
+            .map(|(name, loc)| ErrorModuleNotClosed::new(name, loc).into())
+            .collect::<Vec<_>>();
+        for name in path.iter().rev() {
+            self.id_builder.leave(name);
+        }
+        if errs.is_empty() {
+            Ok(())
+        } else {
+            Err(errs)
+        }
+    }
+
+    /// make public definitions of module visible without qualification
+    pub fn open(&mut self, name: &cst::module::Name) -> Result<()> {
+        if self.id_builder.open(name.name()) {
+            Ok(())
+        } else {
+            let err = ErrorModuleNotFound::new(name.name(), &name.loc());
+            Err(to_errs(err.into()))
+        }
+    }
+
+    /// make next definitions private to the current module, or public again
+    pub fn set_private(&mut self, private: bool) {
+        self.id_builder.set_private(private);
     }
 
     /// convert help variable
@@ -201,4 +1893,224 @@ impl Typer {
             .map_err(Error::from)
             .map_err(to_errs)
     }
+
+    /// typer of a file required by the file typed by `self`, in the environment `stdlib`
+    /// of the standard library, identifiers it builds are distinct from those of `self`
+    pub fn required(&self, mut stdlib: Typer) -> Typer {
+        stdlib.id_builder.count_from(self.id_builder.counter());
+        stdlib
+    }
+
+    /// definitions of file `source_id` exported by it, once it is typed
+    pub fn exports(&self, source_id: &SourceId) -> Exports {
+        let env = self.env.exports(|id| {
+            self.env
+                .get_info(id)
+                .is_some_and(|info| info.loc_def.id() == source_id)
+        });
+        let names = self.id_builder.exported(|id| env.get_info(id).is_some());
+        Exports { names, env }
+    }
+
+    /// add definitions exported by a required file
+    pub fn import(&mut self, exports: &Exports) {
+        self.env.extend(&exports.env);
+        self.id_builder.import(&exports.names);
+    }
+
+    /// interface of the definitions of file `source_id` visible by name, `hash` is the hash of
+    /// the checked file and `value` gives the value of expression definitions,
+    /// `None` when a definition cannot be stored in an interface
+    pub fn interface(
+        &self,
+        source_id: &SourceId,
+        hash: u64,
+        value: impl Fn(&ast::Identifier) -> Option<ast::ConstantKind>,
+    ) -> Option<Interface> {
+        let defined = self
+            .env
+            .iter()
+            .filter(|info| info.loc_def.id() == source_id)
+            .collect::<Vec<_>>();
+        // qualified names of modules are not stored
+        if defined.iter().any(|info| !info.path.is_empty()) {
+            return None;
+        }
+        let mut infos = defined
+            .into_iter()
+            .filter(|info| self.id_builder.lookup(info.id.name()).as_ref() == Some(&info.id))
+            .collect::<Vec<_>>();
+        infos.sort_by_key(|info| info.loc_def.start());
+        let exports = infos
+            .into_iter()
+            .map(|info| {
+                if self.env.get_contract(&info.id).is_some()
+                    || !self.env.get_effects(&info.id).is_empty()
+                    || !info.constraints.is_empty()
+                {
+                    return None;
+                }
+                let kind = match info.kind {
+                    env::IdentifierKind::Type => ExportKind::Type,
+                    env::IdentifierKind::Expr => {
+                        ExportKind::Definition(value(&info.id).filter(interface::is_storable)?)
+                    }
+                    env::IdentifierKind::Theorem => return None,
+                };
+                Some(Export {
+                    name: info.id.name().to_string(),
+                    kind,
+                    binders: info
+                        .binders
+                        .iter()
+                        .map(|binder| binder.name().to_string())
+                        .collect(),
+                    ty: self.interface_ty(&info.ty, &info.binders)?,
+                    doc: info
+                        .doc
+                        .as_ref()
+                        .map(|doc| doc.lines().to_vec())
+                        .unwrap_or_default(),
+                    span: (info.loc_def.start(), info.loc_def.end()),
+                })
+            })
+            .collect::<Option<_>>()?;
+        Some(Interface {
+            hash,
+            depends: Vec::new(),
+            exports,
+        })
+    }
+
+    /// convert type of a definition with type variables `binders` to an interface type
+    fn interface_ty(
+        &self,
+        ty: &ast::Type,
+        binders: &[Rc<ast::Identifier>],
+    ) -> Option<interface::Ty> {
+        use interface::Ty;
+        let tys = |tys: &[ast::Type]| {
+            tys.iter()
+                .map(|ty| self.interface_ty(ty, binders))
+                .collect::<Option<Vec<_>>>()
+        };
+        Some(match ty {
+            ast::Type::Builtin(builtin) => Ty::Builtin(builtin.kind().clone()),
+            ast::Type::Alias(alias) => {
+                let name = alias.name();
+                // the alias must still be visible to be found again
+                if self.id_builder.lookup(name.name()).as_deref() != Some(name) {
+                    return None;
+                }
+                Ty::Alias(name.name().to_string(), tys(alias.args())?)
+            }
+            ast::Type::Arrow(arrow) => Ty::Arrow(
+                Box::new(self.interface_ty(arrow.arg(), binders)?),
+                Box::new(self.interface_ty(arrow.ret(), binders)?),
+            ),
+            ast::Type::Product(product) => Ty::Product(tys(product.tys())?),
+            ast::Type::Reference(reference) => {
+                Ty::Reference(Box::new(self.interface_ty(reference.content(), binders)?))
+            }
+            ast::Type::Variable(var) => {
+                Ty::Variable(binders.iter().position(|binder| binder == var.id())?)
+            }
+            ast::Type::Meta(_) => match ty.unfold() {
+                ast::Type::Meta(_) => return None,
+                solved => return self.interface_ty(solved, binders),
+            },
+            ast::Type::Record(_) | ast::Type::Union(_) => return None,
+        })
+    }
+
+    /// declare the definitions of `interface`, located in `source_id`,
+    /// get the values of expression definitions,
+    /// `None` when the interface refers to an unknown type
+    pub fn load_interface(
+        &mut self,
+        interface: &Interface,
+        source_id: &SourceId,
+    ) -> Option<Vec<(Rc<ast::Identifier>, ast::Constant)>> {
+        let mut values = Vec::new();
+        for export in &interface.exports {
+            let loc = Location::new(source_id.clone(), export.span.0, export.span.1);
+            let binders = export
+                .binders
+                .iter()
+                .map(|name| self.id_builder.fresh(name))
+                .collect::<Vec<_>>();
+            let ty = self.load_ty(&export.ty, &binders, &loc)?;
+            let id = self.id_builder.build(&export.name);
+            match &export.kind {
+                ExportKind::Type => self.env.add_type_def(id.clone(), ty, loc),
+                ExportKind::Definition(value) => {
+                    self.env.add_expr_def(id.clone(), ty, loc);
+                    values.push((id.clone(), ast::Constant::from(value.clone())));
+                }
+            }
+            self.env.set_binders(&id, binders);
+            if !export.doc.is_empty() {
+                self.env
+                    .set_doc(&id, ast::Documentation::from(export.doc.clone()));
+            }
+        }
+        Some(values)
+    }
+
+    /// convert interface type of a definition with type variables `binders`,
+    /// aliases are referenced at `loc`
+    fn load_ty(
+        &mut self,
+        ty: &interface::Ty,
+        binders: &[Rc<ast::Identifier>],
+        loc: &Location,
+    ) -> Option<ast::Type> {
+        use interface::Ty;
+        let tys = |typer: &mut Self, tys: &[Ty]| {
+            tys.iter()
+                .map(|ty| typer.load_ty(ty, binders, loc))
+                .collect::<Option<Vec<_>>>()
+        };
+        Some(match ty {
+            Ty::Builtin(kind) => ast::Type::Builtin(ast::TypeBuiltin::new(kind.clone())),
+            Ty::Alias(name, args) => {
+                let args = tys(self, args)?;
+                let id = self.id_builder.lookup(name)?;
+                self.env.get_ty(&id, args, loc.clone()).ok()?
+            }
+            Ty::Arrow(arg, ret) => ast::Type::Arrow(ast::TypeArrow::new(
+                self.load_ty(arg, binders, loc)?,
+                self.load_ty(ret, binders, loc)?,
+            )),
+            Ty::Product(product) => ast::Type::Product(ast::TypeProduct::new(tys(self, product)?)),
+            Ty::Reference(content) => ast::Type::Reference(ast::TypeReference::new(
+                self.load_ty(content, binders, loc)?,
+            )),
+            Ty::Variable(index) => {
+                ast::Type::Variable(ast::TypeVariable::new(binders.get(*index)?.clone()))
+            }
+        })
+    }
+}
+
+impl kernel::Terms for Typer {
+    fn bind(&mut self, var: &cst::pattern::Variable, ty: &ast::Type) -> Rc<ast::Identifier> {
+        let id = self.id_builder.build(var.name());
+        self.env.add_expr_def(id.clone(), ty.clone(), var.loc());
+        id
+    }
+
+    fn unbind(&mut self, id: &ast::Identifier) {
+        self.id_builder.pop(id);
+    }
+
+    fn term(&mut self, expr: &cst::Expression) -> Result<ast::Expression> {
+        let expr_res = self.expression(expr);
+        self.resolve(expr_res, &[])
+    }
+
+    fn theorem(&mut self, var: &cst::expression::Variable) -> Option<kernel::Formula> {
+        let id = self.id_builder.get(var.name());
+        self.env.get_theorem(&id, var.loc())
+    }
 }
@@ -1,6 +1,6 @@
 use super::location::Report;
 use super::location::{Located, SourceId};
-use super::pretty::StreamColored;
+use super::pretty::{self, StreamColored};
 use super::theme::MessageTheme;
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
@@ -207,25 +207,27 @@ pub trait ErrorReport: ErrorCode + Located {
         None
     }
 
-    /// report of error
+    /// report of error, its messages name the same unknown types the same way
     fn report(&self, theme: &Theme) -> Report<'_> {
-        let loc = self.loc();
-        let mut report_builder = Report::build(ReportKind::Error, loc.clone())
-            .with_config(Config::default().with_index_type(IndexType::Byte))
-            .with_code(self.code())
-            .with_message(self.head().make_string(&theme.error.head));
-        if let Some(text) = self.text() {
-            let mut label =
-                Label::new(loc.clone()).with_message(text.make_string(&theme.error.text));
-            if let Some(color) = theme.error.label_color() {
-                label = label.with_color(*color);
+        pretty::message(|| {
+            let loc = self.loc();
+            let mut report_builder = Report::build(ReportKind::Error, loc.clone())
+                .with_config(Config::default().with_index_type(IndexType::Byte))
+                .with_code(self.code())
+                .with_message(self.head().make_string(&theme.error.head));
+            if let Some(text) = self.text() {
+                let mut label =
+                    Label::new(loc.clone()).with_message(text.make_string(&theme.error.text));
+                if let Some(color) = theme.error.label_color() {
+                    label = label.with_color(*color);
+                }
+                report_builder.add_label(label)
             }
-            report_builder.add_label(label)
-        }
-        if let Some(note) = self.note() {
-            report_builder.add_note(note.make_string(&theme.error.note));
-        }
-        report_builder.finish()
+            if let Some(note) = self.note() {
+                report_builder.add_note(note.make_string(&theme.error.note));
+            }
+            report_builder.finish()
+        })
     }
 }
 
@@ -1,4 +1,5 @@
 pub mod error;
 pub mod location;
 pub mod pretty;
+pub mod rational;
 pub mod theme;
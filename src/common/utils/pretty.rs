@@ -1,7 +1,27 @@
 use super::theme::{ColorInfo, Doc, Theme};
 use pretty::{Render, RenderAnnotated};
+use std::cell::Cell;
 use std::collections::VecDeque;
 
+thread_local! {
+    /// number of messages printed, and depth of nested printings of the current message
+    static PRINTING: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+/// print one message with `print`, what is printed inside it belongs to the same message
+pub fn message<R>(print: impl FnOnce() -> R) -> R {
+    let (count, depth) = PRINTING.get();
+    PRINTING.set((count + usize::from(depth == 0), depth + 1));
+    let result = print();
+    PRINTING.set((PRINTING.get().0, depth));
+    result
+}
+
+/// number of the message being printed
+pub fn message_number() -> usize {
+    PRINTING.get().0
+}
+
 // ===========================================================================
 // Pretty Trait
 // ===========================================================================
@@ -12,7 +32,7 @@ pub trait Pretty: Sized {
     /// write with fmt
     fn fmt(&self, theme: &Theme, fmt: &mut impl std::fmt::Write) -> std::fmt::Result {
         let mut stream = StreamColored::new(fmt);
-        self.pretty(theme).render_raw(theme.width, &mut stream)
+        message(|| self.pretty(theme).render_raw(theme.width, &mut stream))
     }
 
     /// get colored string
@@ -0,0 +1,206 @@
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer as _;
+use num_traits::{One as _, Signed as _, Zero as _};
+use std::cmp::Ordering;
+
+// ==========================================================================
+// Rational
+// ==========================================================================
+
+/// exact rational number, always in lowest terms with a positive denominator
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: BigInt,
+    denom: BigInt,
+}
+
+impl Rational {
+    /// make rational `numer / denom`, `numer / 0` is `0` as in Coq
+    pub fn new(numer: BigInt, denom: BigInt) -> Self {
+        if denom.is_zero() {
+            return Self::from(BigInt::ZERO);
+        }
+        let gcd = numer.gcd(&denom);
+        let (numer, denom) = if denom.is_negative() {
+            (-numer / &gcd, -denom / &gcd)
+        } else {
+            (numer / &gcd, denom / &gcd)
+        };
+        Self { numer, denom }
+    }
+
+    /// make rational `mantissa * 10^exponent`
+    pub fn scientific(mantissa: BigInt, exponent: i32) -> Self {
+        let power = BigInt::from(10u8).pow(exponent.unsigned_abs());
+        if exponent < 0 {
+            Self::new(mantissa, power)
+        } else {
+            Self::from(mantissa * power)
+        }
+    }
+
+    /// get numerator
+    pub fn numer(&self) -> &BigInt {
+        &self.numer
+    }
+
+    /// get denominator, always positive
+    pub fn denom(&self) -> &BigInt {
+        &self.denom
+    }
+
+    /// sign of the rational
+    pub fn sign(&self) -> Sign {
+        self.numer.sign()
+    }
+
+    /// absolute value of the rational
+    pub fn abs(&self) -> Self {
+        Self {
+            numer: self.numer.abs(),
+            denom: self.denom.clone(),
+        }
+    }
+
+    /// greatest integer less or equal to the rational
+    pub fn floor(&self) -> BigInt {
+        self.numer.div_floor(&self.denom)
+    }
+
+    /// digits after the point if the rational has a finite decimal expansion,
+    /// i.e. the denominator only has `2` and `5` as prime factors
+    pub fn decimals(&self) -> Option<String> {
+        let (mut denom, mut count2, mut count5) = (self.denom.clone(), 0usize, 0usize);
+        let (two, five) = (BigInt::from(2u8), BigInt::from(5u8));
+        while (&denom % &two).is_zero() {
+            denom /= &two;
+            count2 += 1;
+        }
+        while (&denom % &five).is_zero() {
+            denom /= &five;
+            count5 += 1;
+        }
+        if !denom.is_one() {
+            return None;
+        }
+        let digits = count2.max(count5).max(1);
+        let fraction = self.abs() - Self::from(self.abs().floor());
+        let scaled = fraction.numer * BigInt::from(10u8).pow(digits as u32) / fraction.denom;
+        Some(format!("{:0>digits$}", scaled.to_string()))
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Self {
+            numer: value,
+            denom: BigInt::one(),
+        }
+    }
+}
+
+impl From<BigUint> for Rational {
+    fn from(value: BigUint) -> Self {
+        Self::from(BigInt::from(value))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numer * &other.denom).cmp(&(&other.numer * &self.denom))
+    }
+}
+
+impl std::ops::Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl std::ops::Add for Rational {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.numer * &other.denom + other.numer * &self.denom,
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl std::ops::Sub for Rational {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl std::ops::Mul for Rational {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.numer * other.numer, self.denom * other.denom)
+    }
+}
+
+impl std::ops::Div for Rational {
+    type Output = Self;
+    /// as in Coq, `x / 0` is `0`
+    fn div(self, other: Self) -> Self {
+        Self::new(self.numer * other.denom, self.denom * other.numer)
+    }
+}
+
+impl std::ops::Rem for Rational {
+    type Output = Self;
+    /// `x mod y` is `x - y * floor(x / y)`, it has the sign of `y`, and `x mod 0` is `x`
+    fn rem(self, other: Self) -> Self {
+        if other.numer.is_zero() {
+            return self;
+        }
+        let quotient = Self::from((self.clone() / other.clone()).floor());
+        self - other * quotient
+    }
+}
+
+impl std::fmt::Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.decimals() {
+            Some(decimals) => {
+                let sign = if self.numer.is_negative() { "-" } else { "" };
+                write!(f, "{sign}{}.{decimals}", self.abs().floor())
+            }
+            None => write!(f, "{}.0/{}.0", self.numer, self.denom),
+        }
+    }
+}
+
+impl Pretty for Rational {
+    /// pretty print as a decimal number if it has a finite decimal expansion,
+    /// otherwise as a division of decimal numbers so that it reads back as a rational,
+    /// coloured as a number
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        let sign = if self.sign() == Sign::Minus { "-" } else { "" };
+        let doc = match self.decimals() {
+            Some(decimals) => Doc::text(sign)
+                .append(theme.number(self.abs().floor().magnitude()))
+                .append(Doc::text(format!(".{decimals}"))),
+            None => Doc::text(sign)
+                .append(theme.number(self.numer.magnitude()))
+                .append(Doc::text(".0/"))
+                .append(theme.number(self.denom.magnitude()))
+                .append(Doc::text(".0")),
+        };
+        doc.annotate(theme.number.clone())
+    }
+}
@@ -1,6 +1,6 @@
 pub use colored::{Color, Styles};
 use colored::{ColoredString, Colorize};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint, Sign};
 use pretty::RcDoc;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -119,6 +119,8 @@ pub struct Theme {
     pub expr_var: ColorInfo,
     /// character color
     pub character: ColorInfo,
+    /// string color
+    pub string: ColorInfo,
     /// number
     pub number: ColorInfo,
     /// boolean
@@ -143,6 +145,7 @@ impl Default for Theme {
             def_var: ColorInfo::default(),
             expr_var: ColorInfo::default(),
             character: ColorInfo::default(),
+            string: ColorInfo::default(),
             boolean: ColorInfo::default(),
             number: ColorInfo::default(),
             ty_var: ColorInfo::default(),
@@ -167,6 +170,7 @@ impl Theme {
                 .style(Styles::Bold),
             expr_var: ColorInfo::default().fg_color(Color::Blue),
             character: ColorInfo::default().fg_color(Color::Green),
+            string: ColorInfo::default().fg_color(Color::Green),
             number: ColorInfo::default().fg_color(Color::Green),
             boolean: ColorInfo::default().fg_color(Color::Green),
             ty_var: ColorInfo::default()
@@ -225,19 +229,31 @@ impl Theme {
         Doc::text(var.to_string()).annotate(self.expr_var.clone())
     }
 
+    /// escape character `c` inside literal delimited by `quote`
+    fn escape(c: char, quote: char) -> String {
+        match c {
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            _ if c == quote => format!("\\{c}"),
+            _ if c.is_control() => format!("\\{:03}", c as u32),
+            _ => c.to_string(),
+        }
+    }
+
     /// pprint constant expression
     pub fn character<'a>(&self, c: char) -> Doc<'a> {
-        let c_escaped = match c {
-            '\'' => "\\'",
-            '\\' => "\\\\",
-            '\n' => "\\n",
-            '\r' => "\\r",
-            '\t' => "\\t",
-            _ => &c.to_string(),
-        };
+        let c_escaped = Self::escape(c, '\'');
         Doc::text(format!("'{c_escaped}'")).annotate(self.character.clone())
     }
 
+    /// pretty print string
+    pub fn string<'a>(&self, s: &str) -> Doc<'a> {
+        let s_escaped: String = s.chars().map(|c| Self::escape(c, '"')).collect();
+        Doc::text(format!("\"{s_escaped}\"")).annotate(self.string.clone())
+    }
+
     /// pretty print number
     pub fn number<'a>(&self, n: &BigUint) -> Doc<'a> {
         let number_str: String = {
@@ -254,6 +270,14 @@ impl Theme {
         Doc::text(number_str).annotate(self.number.clone())
     }
 
+    /// pretty print integer, always with its sign
+    pub fn integer<'a>(&self, i: &BigInt) -> Doc<'a> {
+        let sign = if i.sign() == Sign::Minus { "-" } else { "+" };
+        Doc::text(sign)
+            .annotate(self.number.clone())
+            .append(self.number(i.magnitude()))
+    }
+
     /// pretty print boolean
     pub fn boolean<'a>(&self, b: bool) -> Doc<'a> {
         Doc::text(if b { "true" } else { "false" }).annotate(self.boolean.clone())
@@ -1,37 +1,526 @@
-use crate::typer::ast::{Expression, ExpressionDefinition, Identifier, Pattern};
+use crate::typer::ast::{
+    BinaryOperator, Clause, Contract, ContractKind, Expression, ExpressionChecked,
+    ExpressionDefinition, Fixpoint, Identifier, Pattern, Type, TypeDefinition, Typed as _,
+    UnaryOperator,
+};
 
-use super::value::{Constant, Value};
+use super::value::{Closure, Constant, Record, Value, Variant};
+use crate::utils::location::{Located as _, Location};
+use crate::utils::rational::Rational;
+use num_bigint::{BigInt, BigUint};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
+
+// =========================================================================
+// Violation
+// =========================================================================
+
+/// clause of a contract that evaluates to `false`
+pub struct Violation {
+    /// contract of the function
+    contract: Rc<Contract>,
+    /// index of the clause in the contract
+    clause: usize,
+    /// values of the parameters, then of the result for a postcondition
+    values: Vec<(Identifier, Value)>,
+    /// location of the call, once known
+    loc: Option<Location>,
+}
+
+impl Violation {
+    /// get clause that does not hold
+    pub fn clause(&self) -> &Clause {
+        &self.contract.clauses()[self.clause]
+    }
+
+    /// get values of the parameters and of the result
+    pub fn values(&self) -> &[(Identifier, Value)] {
+        &self.values
+    }
+
+    /// get location of the call, or of the clause if it is not a call
+    pub fn loc(&self) -> Location {
+        self.loc.clone().unwrap_or_else(|| self.clause().loc())
+    }
+}
+
+/// reason why an evaluation stops before reaching a value
+enum Stop {
+    /// no evaluation rule applies, which typing rules out
+    Stuck,
+    /// a clause of a contract does not hold
+    Violation(Box<Violation>),
+}
+
+type Eval<T = Value> = Result<T, Stop>;
+
+// =========================================================================
+// Scope
+// =========================================================================
+
+/// local bindings of an evaluation
+#[derive(Debug, Default, Clone)]
+pub struct Scope(HashMap<Identifier, Value>);
+
+impl Scope {
+    /// get scope value
+    pub fn get(&self, id: &Identifier) -> Option<&Value> {
+        self.0.get(id)
+    }
+
+    /// set scope value
+    pub fn set(&mut self, id: Identifier, value: Value) {
+        self.0.insert(id, value);
+    }
+
+    /// bind value to pattern, return `false` if the value does not match the pattern
+    pub fn bind(&mut self, pattern: &Pattern, value: Value) -> bool {
+        match pattern {
+            Pattern::Variable(var) => {
+                self.set(var.identifier().clone(), value);
+                true
+            }
+            Pattern::Tuple(tuple) => match value {
+                Value::Tuple(values) => tuple
+                    .patterns()
+                    .iter()
+                    .zip(values)
+                    .all(|(pattern, value)| self.bind(pattern, value)),
+                _ => unreachable!("tuple pattern is typed as product"),
+            },
+            Pattern::Constructor(ctor) => match value {
+                Value::Variant(variant) if variant.constructor() == ctor.identifier().as_ref() => {
+                    ctor.args()
+                        .iter()
+                        .zip(variant.args())
+                        .all(|(pattern, value)| self.bind(pattern, value.clone()))
+                }
+                Value::Variant(_) => false,
+                _ => unreachable!("constructor pattern is typed as union"),
+            },
+        }
+    }
+}
+
+// =========================================================================
+// Env
+// =========================================================================
 
 #[derive(Debug, Default)]
-pub struct Env(HashMap<Identifier, Value>);
+pub struct Env {
+    /// values of definitions
+    scope: Scope,
+    /// contracts are not checked, their proof obligations are emitted instead
+    unchecked: bool,
+    /// contents of references, by address
+    heap: RefCell<Vec<Value>>,
+}
 
 impl Env {
     /// get environment value
     pub fn get(&self, id: &Identifier) -> Option<&Value> {
-        self.0.get(id)
+        self.scope.get(id)
     }
 
     /// set environment value
     pub fn set(&mut self, id: Identifier, value: Value) {
-        self.0.insert(id, value);
+        self.scope.set(id, value);
+    }
+
+    /// enable or disable checking of contracts when functions are applied
+    pub fn set_check_contracts(&mut self, check: bool) {
+        self.unchecked = !check;
+    }
+
+    /// allocate a new reference holding `value`
+    pub fn alloc(&self, value: Value) -> Value {
+        let mut heap = self.heap.borrow_mut();
+        heap.push(value);
+        Value::Reference(heap.len() - 1)
+    }
+
+    /// read content of reference, `None` if the value is not a reference
+    pub fn read(&self, reference: &Value) -> Option<Value> {
+        match reference {
+            Value::Reference(address) => self.heap.borrow().get(*address).cloned(),
+            _ => None,
+        }
+    }
+
+    /// write `value` in reference, return `false` if the value is not a reference
+    pub fn write(&self, reference: &Value, value: Value) -> bool {
+        match reference {
+            Value::Reference(address) => match self.heap.borrow_mut().get_mut(*address) {
+                Some(content) => {
+                    *content = value;
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// eval expression, fail if a contract does not hold
+    pub fn eval(&self, expr: &Expression) -> Result<Value, Box<Violation>> {
+        match self.eval_in(&Scope::default(), expr) {
+            Ok(value) => Ok(value),
+            Err(Stop::Violation(violation)) => Err(violation),
+            Err(Stop::Stuck) => unreachable!("evaluation of a typed expression is not stuck"),
+        }
     }
 
-    /// eval expression
-    pub fn eval(&self, expr: &Expression) -> Option<Value> {
+    /// eval expression in a local scope
+    fn eval_in(&self, scope: &Scope, expr: &Expression) -> Eval {
         match expr {
-            Expression::Constant(c) => Some(Value::from(Constant::from(c))),
-            Expression::Variable(var) => self.get(var.identifier()).cloned(),
+            Expression::Constant(c) => Ok(Value::from(c)),
+            Expression::Variable(var) => scope
+                .get(var.identifier())
+                .or_else(|| self.get(var.identifier()))
+                .cloned()
+                .ok_or(Stop::Stuck),
+            // a dictionary is a parameter of the definition or the variable of an instance
+            Expression::Instance(instance) => {
+                let dict = instance.dict().ok_or(Stop::Stuck)?;
+                scope
+                    .get(&dict)
+                    .or_else(|| self.get(&dict))
+                    .cloned()
+                    .ok_or(Stop::Stuck)
+            }
+            Expression::Abstraction(abs) => Ok(Value::from(Closure::new(abs, scope.clone()))),
+            Expression::Match(expr_match) => {
+                Ok(Value::from(Closure::of_match(expr_match, scope.clone())))
+            }
+            Expression::Application(app) => {
+                let fun = self.eval_in(scope, app.fun())?;
+                let arg = self.eval_in(scope, app.arg())?;
+                // a violated contract is reported at the call of its function
+                self.apply(&fun, arg).map_err(|stop| match stop {
+                    Stop::Violation(mut violation) if violation.loc.is_none() => {
+                        violation.loc = Some(app.loc());
+                        Stop::Violation(violation)
+                    }
+                    stop => stop,
+                })
+            }
+            Expression::Tuple(tuple) => tuple
+                .elems()
+                .iter()
+                .map(|elem| self.eval_in(scope, elem))
+                .collect::<Eval<Vec<_>>>()
+                .map(Value::Tuple),
+            // propositions are only type-checked, their operands are not evaluated
+            Expression::Binary(binary) if binary.op().is_connective() => Ok(Value::Proposition),
+            Expression::Quantifier(_) => Ok(Value::Proposition),
+            Expression::Binary(binary) => {
+                let left = self.eval_in(scope, binary.left())?;
+                match (binary.op(), left) {
+                    // boolean operators are lazy on their right operand
+                    (BinaryOperator::And, Value::Constant(Constant::Bool(false))) => {
+                        Ok(Value::from(Constant::Bool(false)))
+                    }
+                    (BinaryOperator::Or, Value::Constant(Constant::Bool(true))) => {
+                        Ok(Value::from(Constant::Bool(true)))
+                    }
+                    (BinaryOperator::And | BinaryOperator::Or, _) => {
+                        self.eval_in(scope, binary.right())
+                    }
+                    (op, Value::Constant(left)) => {
+                        let right = self.eval_in(scope, binary.right())?;
+                        match (left, right) {
+                            (Constant::Nat(left), Value::Constant(Constant::Nat(right))) => {
+                                Ok(Value::from(Self::binary_nat(op, left, right)))
+                            }
+                            (Constant::Int(left), Value::Constant(Constant::Int(right))) => {
+                                Ok(Value::from(Self::binary_int(op, left, right)))
+                            }
+                            (Constant::Rat(left), Value::Constant(Constant::Rat(right))) => {
+                                Ok(Value::from(Self::binary_rat(op, left, right)))
+                            }
+                            // other constants only have equality
+                            (left, Value::Constant(right))
+                                if matches!(op, BinaryOperator::Eq | BinaryOperator::NotEq) =>
+                            {
+                                let eq = left == right;
+                                Ok(Value::from(Constant::Bool(
+                                    eq == (op == BinaryOperator::Eq),
+                                )))
+                            }
+                            _ => Err(Stop::Stuck),
+                        }
+                    }
+                    _ => Err(Stop::Stuck),
+                }
+            }
+            Expression::Unary(unary) => match (unary.op(), self.eval_in(scope, unary.expr())?) {
+                (UnaryOperator::Not, Value::Constant(Constant::Bool(b))) => {
+                    Ok(Value::from(Constant::Bool(!b)))
+                }
+                (UnaryOperator::Neg, Value::Constant(Constant::Int(n))) => {
+                    Ok(Value::from(Constant::Int(-n)))
+                }
+                (UnaryOperator::Neg, Value::Constant(Constant::Rat(q))) => {
+                    Ok(Value::from(Constant::Rat(-q)))
+                }
+                _ => Err(Stop::Stuck),
+            },
+            // only the branch selected by the condition is evaluated
+            Expression::If(expr_if) => match self.eval_in(scope, expr_if.cond())? {
+                Value::Constant(Constant::Bool(true)) => self.eval_in(scope, expr_if.then_branch()),
+                Value::Constant(Constant::Bool(false)) => {
+                    self.eval_in(scope, expr_if.else_branch())
+                }
+                _ => Err(Stop::Stuck),
+            },
+            Expression::Let(expr_let) => {
+                let value = self.eval_in(scope, expr_let.value())?;
+                let mut scope = scope.clone();
+                if !scope.bind(expr_let.pattern(), value) {
+                    return Err(Stop::Stuck);
+                }
+                self.eval_in(&scope, expr_let.body())
+            }
+            Expression::Alloc(alloc) => Ok(self.alloc(self.eval_in(scope, alloc.value())?)),
+            Expression::Deref(deref) => {
+                let reference = self.eval_in(scope, deref.reference())?;
+                self.read(&reference).ok_or(Stop::Stuck)
+            }
+            // the reference is evaluated before the value
+            Expression::Assign(assign) => {
+                let reference = self.eval_in(scope, assign.reference())?;
+                let value = self.eval_in(scope, assign.value())?;
+                if self.write(&reference, value) {
+                    Ok(Value::from(Constant::Unit))
+                } else {
+                    Err(Stop::Stuck)
+                }
+            }
+            // fields are evaluated in order of the source
+            Expression::Record(record) => {
+                let mut fields = record
+                    .fields()
+                    .iter()
+                    .map(|field| {
+                        let value = self.eval_in(scope, field.value())?;
+                        Ok((field.index(), field.name().to_string(), value))
+                    })
+                    .collect::<Eval<Vec<_>>>()?;
+                fields.sort_by_key(|(index, _, _)| *index);
+                let fields = fields
+                    .into_iter()
+                    .map(|(_, name, value)| (name, value))
+                    .collect();
+                Ok(Value::from(Record::new(fields)))
+            }
+            Expression::Update(update) => {
+                let Value::Record(record) = self.eval_in(scope, update.record())? else {
+                    return Err(Stop::Stuck);
+                };
+                let mut record = record.as_ref().clone();
+                for field in update.fields() {
+                    let value = self.eval_in(scope, field.value())?;
+                    record = record.with(field.index(), value);
+                }
+                Ok(Value::from(record))
+            }
+            Expression::Projection(projection) => {
+                match self.eval_in(scope, projection.record())? {
+                    Value::Record(record) => {
+                        record.get(projection.index()).cloned().ok_or(Stop::Stuck)
+                    }
+                    _ => Err(Stop::Stuck),
+                }
+            }
+            // a boolean coerced to a proposition is not evaluated either
+            Expression::Coercion(coercion) if coercion.ty().is_prop() => Ok(Value::Proposition),
+            Expression::Coercion(coercion) => {
+                match (
+                    self.eval_in(scope, coercion.expr())?,
+                    coercion.ty().unfold(),
+                ) {
+                    (Value::Constant(c), Type::Builtin(builtin)) => {
+                        c.coerce(builtin.kind()).map(Value::from).ok_or(Stop::Stuck)
+                    }
+                    _ => Err(Stop::Stuck),
+                }
+            }
+            Expression::Checked(checked) if self.unchecked => self.eval_in(scope, checked.body()),
+            Expression::Checked(checked) => self.eval_checked(scope, checked),
         }
     }
 
-    /// add a definition to the environment
-    pub fn add_definition(&mut self, def: &ExpressionDefinition) {
-        let value = self.eval(def.body()).unwrap();
-        match def.pattern() {
-            Pattern::Variable(var) => {
-                self.set(var.identifier().clone(), value.clone());
+    /// eval body of a function, checking preconditions before and postconditions after
+    fn eval_checked(&self, scope: &Scope, checked: &ExpressionChecked) -> Eval {
+        let contract = checked.contract();
+        self.check_clauses(scope, contract, ContractKind::Requires)?;
+        let value = self.eval_in(scope, checked.body())?;
+        if let Some(result) = contract.result() {
+            let mut scope = scope.clone();
+            scope.set(result.as_ref().clone(), value.clone());
+            self.check_clauses(&scope, contract, ContractKind::Ensures)?;
+        }
+        Ok(value)
+    }
+
+    /// check clauses of `contract` of kind `kind`, propositions cannot be
+    /// evaluated and are left to proofs
+    fn check_clauses(
+        &self,
+        scope: &Scope,
+        contract: &Rc<Contract>,
+        kind: ContractKind,
+    ) -> Eval<()> {
+        let clauses = contract.clauses().iter().enumerate();
+        for (index, clause) in clauses.filter(|(_, clause)| clause.kind() == kind) {
+            if let Value::Constant(Constant::Bool(false)) = self.eval_in(scope, clause.expr())? {
+                let result = match kind {
+                    ContractKind::Requires => None,
+                    ContractKind::Ensures => contract.result().map(|id| id.as_ref()),
+                };
+                let values = contract
+                    .params()
+                    .iter()
+                    .chain(result)
+                    .filter_map(|id| Some((id.clone(), scope.get(id)?.clone())))
+                    .collect();
+                return Err(Stop::Violation(Box::new(Violation {
+                    contract: contract.clone(),
+                    clause: index,
+                    values,
+                    loc: None,
+                })));
             }
         }
+        Ok(())
+    }
+
+    /// apply comparison operator, `None` if operator is not a comparison
+    fn compare<T: Ord>(op: BinaryOperator, left: &T, right: &T) -> Option<bool> {
+        match op {
+            BinaryOperator::Eq => Some(left == right),
+            BinaryOperator::NotEq => Some(left != right),
+            BinaryOperator::Less => Some(left < right),
+            BinaryOperator::LessEq => Some(left <= right),
+            BinaryOperator::Greater => Some(left > right),
+            BinaryOperator::GreaterEq => Some(left >= right),
+            _ => None,
+        }
+    }
+
+    /// apply arithmetic or comparison operator on natural numbers,
+    /// as in Coq, subtraction is truncated at `0`, `x / 0` is `0` and `x mod 0` is `x`
+    fn binary_nat(op: BinaryOperator, left: BigUint, right: BigUint) -> Constant {
+        if let Some(b) = Self::compare(op, &left, &right) {
+            return Constant::Bool(b);
+        }
+        let zero = BigUint::ZERO;
+        match op {
+            BinaryOperator::Add => Constant::Nat(left + right),
+            BinaryOperator::Sub if left >= right => Constant::Nat(left - right),
+            BinaryOperator::Sub => Constant::Nat(zero),
+            BinaryOperator::Mul => Constant::Nat(left * right),
+            BinaryOperator::Div if right == zero => Constant::Nat(zero),
+            BinaryOperator::Div => Constant::Nat(left / right),
+            BinaryOperator::Mod if right == zero => Constant::Nat(left),
+            BinaryOperator::Mod => Constant::Nat(left % right),
+            _ => unreachable!("operator is not on numbers"),
+        }
+    }
+
+    /// apply arithmetic or comparison operator on integers,
+    /// as in Coq, division rounds toward minus infinity, the modulo has the sign
+    /// of the divisor, `x / 0` is `0` and `x mod 0` is `x`
+    fn binary_int(op: BinaryOperator, left: BigInt, right: BigInt) -> Constant {
+        if let Some(b) = Self::compare(op, &left, &right) {
+            return Constant::Bool(b);
+        }
+        let zero = BigInt::ZERO;
+        match op {
+            BinaryOperator::Add => Constant::Int(left + right),
+            BinaryOperator::Sub => Constant::Int(left - right),
+            BinaryOperator::Mul => Constant::Int(left * right),
+            BinaryOperator::Div if right == zero => Constant::Int(zero),
+            BinaryOperator::Mod if right == zero => Constant::Int(left),
+            BinaryOperator::Div | BinaryOperator::Mod => {
+                let (mut quotient, mut remainder) = (&left / &right, &left % &right);
+                if remainder != zero && (remainder < zero) != (right < zero) {
+                    quotient -= 1;
+                    remainder += &right;
+                }
+                match op {
+                    BinaryOperator::Div => Constant::Int(quotient),
+                    _ => Constant::Int(remainder),
+                }
+            }
+            _ => unreachable!("operator is not on numbers"),
+        }
+    }
+
+    /// apply arithmetic or comparison operator on rationals, computations are exact,
+    /// `x mod y` is `x - y * ⌊x / y⌋`, `x / 0` is `0` and `x mod 0` is `x`
+    fn binary_rat(op: BinaryOperator, left: Rational, right: Rational) -> Constant {
+        if let Some(b) = Self::compare(op, &left, &right) {
+            return Constant::Bool(b);
+        }
+        match op {
+            BinaryOperator::Add => Constant::Rat(left + right),
+            BinaryOperator::Sub => Constant::Rat(left - right),
+            BinaryOperator::Mul => Constant::Rat(left * right),
+            BinaryOperator::Div => Constant::Rat(left / right),
+            BinaryOperator::Mod => Constant::Rat(left % right),
+            _ => unreachable!("operator is not on numbers"),
+        }
+    }
+
+    /// apply function to argument
+    fn apply(&self, fun: &Value, arg: Value) -> Eval {
+        match fun {
+            Value::Closure(closure) => {
+                let (scope, body) = closure
+                    .branches()
+                    .find_map(|(pattern, body)| {
+                        let mut scope = closure.scope().clone();
+                        scope.bind(pattern, arg.clone()).then_some((scope, body))
+                    })
+                    .ok_or(Stop::Stuck)?;
+                self.eval_in(&scope, body)
+            }
+            Value::Variant(variant) if !variant.is_complete() => {
+                Ok(Value::from(variant.apply(arg)))
+            }
+            Value::Primitive(primitive) => primitive.apply(arg).ok_or(Stop::Stuck),
+            Value::Constant(_)
+            | Value::Tuple(_)
+            | Value::Variant(_)
+            | Value::Proposition
+            | Value::Reference(_)
+            | Value::Record(_) => Err(Stop::Stuck),
+        }
+    }
+
+    /// add a definition to the environment, fail if its contract does not hold
+    pub fn add_definition(&mut self, def: &ExpressionDefinition) -> Result<(), Box<Violation>> {
+        let value = self.eval(def.body())?;
+        self.scope.bind(def.pattern(), value);
+        Ok(())
+    }
+
+    /// add mutually recursive definitions to the environment,
+    /// closures look up definitions when applied, so each body sees all of them
+    pub fn add_fixpoint(&mut self, fixpoint: &Fixpoint) -> Result<(), Box<Violation>> {
+        for def in fixpoint.definitions() {
+            self.add_definition(def)?;
+        }
+        Ok(())
+    }
+
+    /// add constructors of a type definition to the environment
+    pub fn add_type_definition(&mut self, def: &TypeDefinition) {
+        for ctor in def.constructors() {
+            let variant = Variant::new(ctor.id().clone(), ctor.args().len());
+            self.set(ctor.id().as_ref().clone(), Value::from(variant));
+        }
     }
 }
@@ -0,0 +1,60 @@
+use super::super::env::Scope;
+use crate::typer::ast::{Expression, ExpressionAbstraction, ExpressionMatch, Pattern};
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// =========================================================================
+// Closure
+// =========================================================================
+
+pub struct Closure {
+    /// binders of the function with their bodies, tried in order
+    branches: Vec<(Rc<Pattern>, Rc<Expression>)>,
+    /// captured local environment
+    scope: Scope,
+}
+
+impl Closure {
+    /// make a closure of an abstraction in a scope
+    pub fn new(abs: &ExpressionAbstraction, scope: Scope) -> Self {
+        Self {
+            branches: vec![(abs.pattern().clone(), abs.body().clone())],
+            scope,
+        }
+    }
+
+    /// make a closure of a pattern matching in a scope
+    pub fn of_match(expr_match: &ExpressionMatch, scope: Scope) -> Self {
+        let branches = expr_match
+            .branches()
+            .iter()
+            .map(|branch| (branch.pattern().clone(), branch.body().clone()))
+            .collect();
+        Self { branches, scope }
+    }
+
+    /// get binders of the closure with their bodies
+    pub fn branches(&self) -> impl Iterator<Item = (&Pattern, &Expression)> {
+        self.branches
+            .iter()
+            .map(|(pattern, body)| (pattern.as_ref(), body.as_ref()))
+    }
+
+    /// get captured scope
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+}
+
+impl std::fmt::Debug for Closure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<fun>")
+    }
+}
+
+impl Pretty for Closure {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.comment(&"<fun>")
+    }
+}
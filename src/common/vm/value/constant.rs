@@ -1,21 +1,42 @@
 use crate::typer::ast;
 use crate::utils::pretty::Pretty;
+use crate::utils::rational::Rational;
 use crate::utils::theme::{Doc, Theme};
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Constant {
     Nat(BigUint),
+    Int(BigInt),
+    Rat(Rational),
     Bool(bool),
     Char(char),
+    String(String),
+    Unit,
+}
+
+impl Constant {
+    /// coerce number to the number type `kind`, along ℕ ⊂ ℤ ⊂ ℚ
+    pub fn coerce(&self, kind: &ast::TypeBuiltinKind) -> Option<Self> {
+        match (self, kind) {
+            (Self::Nat(n), ast::TypeBuiltinKind::Int) => Some(Self::Int(BigInt::from(n.clone()))),
+            (Self::Nat(n), ast::TypeBuiltinKind::Rat) => Some(Self::Rat(Rational::from(n.clone()))),
+            (Self::Int(i), ast::TypeBuiltinKind::Rat) => Some(Self::Rat(Rational::from(i.clone()))),
+            _ => None,
+        }
+    }
 }
 
 impl Pretty for Constant {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         match self {
             Self::Nat(n) => theme.number(n),
+            Self::Int(i) => theme.integer(i),
+            Self::Rat(q) => q.pretty(theme),
             Self::Bool(b) => theme.boolean(*b),
             Self::Char(c) => theme.character(*c),
+            Self::String(s) => theme.string(s),
+            Self::Unit => theme.operator(&"()"),
         }
     }
 }
@@ -24,8 +45,13 @@ impl From<&ast::Constant> for Constant {
     fn from(c: &ast::Constant) -> Self {
         match c.kind() {
             ast::ConstantKind::Nat(n) => Constant::Nat(n.clone()),
+            ast::ConstantKind::Int(i) => Constant::Int(i.clone()),
+            ast::ConstantKind::Rat(q) => Constant::Rat(q.clone()),
             ast::ConstantKind::Bool(b) => Constant::Bool(*b),
             ast::ConstantKind::Char(c) => Constant::Char(*c),
+            ast::ConstantKind::String(s) => Constant::String(s.clone()),
+            ast::ConstantKind::Unit => Constant::Unit,
+            ast::ConstantKind::Primitive(_) => unreachable!("primitive is a function value"),
         }
     }
 }
@@ -1,9 +1,19 @@
+use crate::typer::ast;
 use crate::utils::location::{Location, WithLoc};
 use crate::utils::pretty::Pretty;
 use crate::utils::theme::{Doc, Theme};
 
+mod closure;
 mod constant;
+mod primitive;
+mod record;
+mod variant;
+pub use closure::Closure;
 pub use constant::Constant;
+pub use primitive::Primitive;
+pub use record::Record;
+use std::rc::Rc;
+pub use variant::Variant;
 
 // =========================================================================
 // Value
@@ -12,6 +22,15 @@ pub use constant::Constant;
 #[derive(Debug, Clone)]
 pub enum Value {
     Constant(Constant),
+    Closure(Rc<Closure>),
+    Tuple(Vec<Value>),
+    Variant(Rc<Variant>),
+    Primitive(Rc<Primitive>),
+    /// proposition, it is never evaluated
+    Proposition,
+    /// address of a reference in the heap of the environment
+    Reference(usize),
+    Record(Rc<Record>),
 }
 
 impl Value {
@@ -25,6 +44,23 @@ impl Pretty for Value {
     fn pretty(&self, theme: &Theme) -> Doc<'_> {
         match self {
             Self::Constant(c) => c.pretty(theme),
+            Self::Closure(c) => c.pretty(theme),
+            Self::Variant(v) => v.pretty(theme),
+            Self::Primitive(p) => p.pretty(theme),
+            Self::Proposition => theme.comment(&"<prop>"),
+            Self::Reference(_) => theme.comment(&"<ref>"),
+            Self::Record(r) => r.pretty(theme),
+            Self::Tuple(values) => Doc::intersperse(
+                values.iter().map(|value| match value {
+                    Self::Tuple(_) => Doc::nil()
+                        .append(theme.operator(&"("))
+                        .append(value.pretty(theme))
+                        .append(theme.operator(&")")),
+                    _ => value.pretty(theme),
+                }),
+                theme.operator(&",").append(Doc::line()),
+            )
+            .group(),
         }
     }
 }
@@ -34,3 +70,36 @@ impl From<Constant> for Value {
         Value::Constant(c)
     }
 }
+
+impl From<&ast::Constant> for Value {
+    fn from(c: &ast::Constant) -> Self {
+        match c.kind() {
+            ast::ConstantKind::Primitive(p) => Value::from(Primitive::new(*p)),
+            _ => Value::Constant(Constant::from(c)),
+        }
+    }
+}
+
+impl From<Primitive> for Value {
+    fn from(p: Primitive) -> Self {
+        Value::Primitive(Rc::new(p))
+    }
+}
+
+impl From<Closure> for Value {
+    fn from(c: Closure) -> Self {
+        Value::Closure(Rc::new(c))
+    }
+}
+
+impl From<Record> for Value {
+    fn from(r: Record) -> Self {
+        Value::Record(Rc::new(r))
+    }
+}
+
+impl From<Variant> for Value {
+    fn from(v: Variant) -> Self {
+        Value::Variant(Rc::new(v))
+    }
+}
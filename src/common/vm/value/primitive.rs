@@ -0,0 +1,73 @@
+use super::{Constant, Value};
+use crate::typer::ast;
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use num_bigint::{BigInt, BigUint, Sign};
+
+// =========================================================================
+// Primitive
+// =========================================================================
+
+#[derive(Debug, Clone)]
+pub struct Primitive {
+    /// function implemented by the interpreter
+    primitive: ast::Primitive,
+    /// arguments given to the primitive
+    args: Vec<Value>,
+}
+
+impl Primitive {
+    /// make a primitive without arguments
+    pub fn new(primitive: ast::Primitive) -> Self {
+        Self {
+            primitive,
+            args: Vec::new(),
+        }
+    }
+
+    /// get primitive function when no argument is given yet
+    pub fn as_primitive(&self) -> Option<ast::Primitive> {
+        self.args.is_empty().then_some(self.primitive)
+    }
+
+    /// give one more argument to the primitive, compute it once all arguments are given
+    pub fn apply(&self, arg: Value) -> Option<Value> {
+        let mut prim = self.clone();
+        prim.args.push(arg);
+        if prim.args.len() < prim.primitive.arity() {
+            return Some(Value::from(prim));
+        }
+        match (prim.primitive, prim.args.as_slice()) {
+            (ast::Primitive::IntOfNat, [Value::Constant(Constant::Nat(n))]) => Some(Value::from(
+                Constant::Int(BigInt::from_biguint(Sign::Plus, n.clone())),
+            )),
+            (ast::Primitive::NatOfInt, [Value::Constant(Constant::Int(i))]) => Some(Value::from(
+                Constant::Nat(i.to_biguint().unwrap_or(BigUint::ZERO)),
+            )),
+            (ast::Primitive::StringLength, [Value::Constant(Constant::String(s))]) => {
+                Some(Value::from(Constant::Nat(BigUint::from(s.chars().count()))))
+            }
+            (
+                ast::Primitive::StringAppend,
+                [Value::Constant(Constant::String(s1)), Value::Constant(Constant::String(s2))],
+            ) => Some(Value::from(Constant::String(format!("{s1}{s2}")))),
+            (
+                ast::Primitive::StringGet,
+                [Value::Constant(Constant::String(s)), Value::Constant(Constant::Nat(n))],
+            ) => {
+                let c = usize::try_from(n)
+                    .ok()
+                    .and_then(|n| s.chars().nth(n))
+                    .unwrap_or('\0');
+                Some(Value::from(Constant::Char(c)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Pretty for Primitive {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        theme.comment(&"<fun>")
+    }
+}
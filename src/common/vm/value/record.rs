@@ -0,0 +1,58 @@
+use super::Value;
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+
+// =========================================================================
+// Record
+// =========================================================================
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    /// fields with their names, in order of definition of the record type
+    fields: Vec<(String, Value)>,
+}
+
+impl Record {
+    /// make a record of fields in order of definition of the record type
+    pub fn new(fields: Vec<(String, Value)>) -> Self {
+        Self { fields }
+    }
+
+    /// get value of field at `index`
+    pub fn get(&self, index: usize) -> Option<&Value> {
+        self.fields.get(index).map(|(_, value)| value)
+    }
+
+    /// copy of the record with a new value for field at `index`
+    pub fn with(&self, index: usize, value: Value) -> Self {
+        let mut record = self.clone();
+        if let Some(field) = record.fields.get_mut(index) {
+            field.1 = value;
+        }
+        record
+    }
+}
+
+impl Pretty for Record {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        Doc::nil()
+            .append(theme.operator(&"{"))
+            .append(Doc::space())
+            .append(Doc::intersperse(
+                self.fields.iter().map(|(name, value)| {
+                    Doc::nil()
+                        .append(theme.expr_var(name))
+                        .append(Doc::space())
+                        .append(theme.operator(&":="))
+                        .append(Doc::space())
+                        .append(value.pretty(theme))
+                        .group()
+                }),
+                theme.operator(&";").append(Doc::line()),
+            ))
+            .append(Doc::space())
+            .append(theme.operator(&"}"))
+            .nest(2)
+            .group()
+    }
+}
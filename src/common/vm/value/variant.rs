@@ -0,0 +1,89 @@
+use super::{Constant, Value};
+use crate::typer::ast::Identifier;
+use crate::utils::pretty::Pretty;
+use crate::utils::theme::{Doc, Theme};
+use std::rc::Rc;
+
+// =========================================================================
+// Variant
+// =========================================================================
+
+#[derive(Debug, Clone)]
+pub struct Variant {
+    /// constructor of the variant
+    constructor: Rc<Identifier>,
+    /// number of arguments of the constructor
+    arity: usize,
+    /// arguments given to the constructor
+    args: Vec<Value>,
+}
+
+impl Variant {
+    /// make a variant of a constructor without arguments
+    pub fn new(constructor: Rc<Identifier>, arity: usize) -> Self {
+        Self {
+            constructor,
+            arity,
+            args: Vec::new(),
+        }
+    }
+
+    /// give one more argument to the constructor
+    pub fn apply(&self, arg: Value) -> Self {
+        let mut variant = self.clone();
+        variant.args.push(arg);
+        variant
+    }
+
+    /// get constructor of the variant
+    pub fn constructor(&self) -> &Identifier {
+        &self.constructor
+    }
+
+    /// get arguments of the variant
+    pub fn args(&self) -> &[Value] {
+        &self.args
+    }
+
+    /// the constructor is applied to all of its arguments
+    pub fn is_complete(&self) -> bool {
+        self.args.len() == self.arity
+    }
+}
+
+impl Pretty for Variant {
+    fn pretty(&self, theme: &Theme) -> Doc<'_> {
+        if !self.is_complete() {
+            return theme.comment(&"<fun>");
+        }
+        self.args
+            .iter()
+            .fold(
+                theme.expr_var(&self.constructor.qualified_name()),
+                |doc, arg| {
+                    let paren = match arg {
+                        Value::Tuple(_) => true,
+                        Value::Variant(variant) => !variant.args.is_empty(),
+                        // a rational without decimal expansion is a division
+                        Value::Constant(Constant::Rat(q)) => q.decimals().is_none(),
+                        Value::Constant(_)
+                        | Value::Closure(_)
+                        | Value::Primitive(_)
+                        | Value::Proposition
+                        | Value::Reference(_)
+                        | Value::Record(_) => false,
+                    };
+                    let doc_arg = if paren {
+                        Doc::nil()
+                            .append(theme.operator(&"("))
+                            .append(arg.pretty(theme))
+                            .append(theme.operator(&")"))
+                    } else {
+                        arg.pretty(theme)
+                    };
+                    doc.append(Doc::line()).append(doc_arg)
+                },
+            )
+            .group()
+    }
+}
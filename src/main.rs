@@ -17,6 +17,12 @@ enum Commands {
     Repl,
     /// interpet a file
     Run { path: String },
+    /// check every file of a package and of its dependencies
+    Build {
+        #[arg(default_value = ".")]
+        /// directory of the package, with its manifest `Start.toml`
+        path: String,
+    },
 
     #[command(group(
         ArgGroup::new("mode")
@@ -57,6 +63,10 @@ async fn main() {
             let path = std::path::PathBuf::from(path);
             file_interpreter::run(&path)
         }
+        Commands::Build { path } => {
+            let path = std::path::PathBuf::from(path);
+            build::run(&path)
+        }
         Commands::Format {
             path,
             print,
@@ -0,0 +1,242 @@
+use assert_cmd::Command;
+use std::fs;
+use std::path::Path;
+use temp_dir::TempDir;
+
+const PROGRAM: &str = "Help Int.
+Help int_of_nat.
+TypeOf string_get.
+Eval string_append \"a\" \"b\".
+Eval nat_of_int (int_of_nat 4).
+Eval true.
+";
+
+const LIBRARY: &str = "(** Two *)
+Definition two := 2.
+Type number := ℕ.
+Definition three : number := 3.
+";
+
+const REQUIRING: &str = "Require \"lib.st\".
+Eval two.
+Eval three.
+Help two.
+";
+
+/// run program with the cache in `cache`, get its output
+fn run(dir: &TempDir, cache: &Path) -> String {
+    run_program(dir, cache, PROGRAM)
+}
+
+/// run `program` with the cache in `cache`, get its output
+fn run_program(dir: &TempDir, cache: &Path, program: &str) -> String {
+    let path = dir.child("main.st");
+    fs::write(&path, program).unwrap();
+    let output = Command::cargo_bin("startlang")
+        .unwrap()
+        .env("STARTLANG_CACHE", cache)
+        .args(["run", path.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn stdlib_interface_is_stored_then_loaded() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    let interface = cache.join("stdlib.sti");
+
+    let checked = run(&dir, &cache);
+    let stored = fs::read_to_string(&interface).unwrap();
+    assert!(stored.starts_with("startlang-interface "));
+
+    assert_eq!(run(&dir, &cache), checked);
+    assert_eq!(fs::read_to_string(&interface).unwrap(), stored);
+
+    // documentation comes from the interface, the standard library is not checked again
+    let edited = stored.replace("doc Integers type", "doc Integers from the interface");
+    fs::write(&interface, edited).unwrap();
+    let loaded = run(&dir, &cache);
+    assert_ne!(loaded, checked);
+    assert_eq!(
+        loaded.replace("Integers from the interface", "Integers type"),
+        checked
+    );
+}
+
+#[test]
+fn stdlib_interface_is_rebuilt_when_invalid() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    let interface = cache.join("stdlib.sti");
+
+    let checked = run(&dir, &cache);
+    let stored = fs::read_to_string(&interface).unwrap();
+
+    fs::write(&interface, "not an interface").unwrap();
+    assert_eq!(run(&dir, &cache), checked);
+    assert_eq!(fs::read_to_string(&interface).unwrap(), stored);
+
+    let hash = stored.lines().nth(1).unwrap();
+    let stale = stored.replace(hash, "hash 0000000000000000");
+    fs::write(&interface, stale).unwrap();
+    assert_eq!(run(&dir, &cache), checked);
+    assert_eq!(fs::read_to_string(&interface).unwrap(), stored);
+}
+
+#[test]
+fn required_interface_is_stored_then_loaded() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    fs::write(dir.child("lib.st"), LIBRARY).unwrap();
+
+    let checked = run_program(&dir, &cache, REQUIRING);
+    let files = fs::read_dir(cache.join("files"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    assert_eq!(files.len(), 1);
+    let interface = &files[0];
+    let stored = fs::read_to_string(interface).unwrap();
+    assert!(stored.starts_with("startlang-interface "));
+    assert_eq!(run_program(&dir, &cache, REQUIRING), checked);
+
+    // values come from the interface, the required file is not checked again
+    fs::write(interface, stored.replace("value (nat 2)", "value (nat 4)")).unwrap();
+    let loaded = run_program(&dir, &cache, REQUIRING);
+    assert_eq!(loaded.replacen('4', "2", 1), checked);
+
+    // an edited file is checked again
+    fs::write(dir.child("lib.st"), LIBRARY.replace(":= 2", ":= 5")).unwrap();
+    assert_eq!(
+        run_program(&dir, &cache, REQUIRING),
+        checked.replacen('2', "5", 1)
+    );
+}
+
+#[test]
+fn required_interface_is_checked_again_when_its_requires_change() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    fs::write(dir.child("b.st"), "Definition two := 2.\n").unwrap();
+    fs::write(
+        dir.child("a.st"),
+        "Require \"b.st\".\nDefinition four := two + two.\n",
+    )
+    .unwrap();
+    let program = "Require \"a.st\".\nEval four.\n";
+
+    let checked = run_program(&dir, &cache, program);
+    assert_eq!(run_program(&dir, &cache, program), checked);
+
+    fs::write(dir.child("b.st"), "Definition two := 3.\n").unwrap();
+    assert_eq!(
+        run_program(&dir, &cache, program),
+        checked.replace('4', "6")
+    );
+}
+
+#[test]
+fn required_file_printing_outputs_is_not_cached() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    fs::write(
+        dir.child("lib.st"),
+        "Definition two := 2.\nEval 40 + two.\n",
+    )
+    .unwrap();
+    let program = "Require \"lib.st\".\n";
+
+    let cold = run_program(&dir, &cache, program);
+    assert_eq!(cold, "42\n");
+    assert_eq!(run_program(&dir, &cache, program), cold);
+    assert!(!cache.join("files").exists());
+}
+
+#[test]
+fn file_requiring_a_file_printing_outputs_is_not_cached() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    fs::write(dir.child("b.st"), "Definition two := 2.\nEval two.\n").unwrap();
+    fs::write(
+        dir.child("a.st"),
+        "Require \"b.st\".\nDefinition four := two + two.\n",
+    )
+    .unwrap();
+    let program = "Require \"a.st\".\nEval four.\n";
+
+    let cold = run_program(&dir, &cache, program);
+    assert_eq!(cold, "2\n4\n");
+    assert_eq!(run_program(&dir, &cache, program), cold);
+    assert_eq!(run_program(&dir, &cache, "Require \"a.st\".\n"), "2\n");
+}
+
+#[test]
+fn package_interface_is_checked_again_when_its_dependencies_change() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    let numbers = dir.child("numbers");
+    let geometry = dir.child("geometry");
+    fs::create_dir_all(numbers.join("src")).unwrap();
+    fs::create_dir_all(geometry.join("src")).unwrap();
+    fs::write(
+        numbers.join("Start.toml"),
+        "[package]\nname = \"numbers\"\n",
+    )
+    .unwrap();
+    fs::write(numbers.join("src/two.st"), "Definition two := 2.\n").unwrap();
+    fs::write(
+        geometry.join("Start.toml"),
+        "[package]\nname = \"geometry\"\n\n[dependencies]\nnumbers = { path = \"../numbers\" }\n",
+    )
+    .unwrap();
+    fs::write(
+        geometry.join("src/four.st"),
+        "Definition four := two + two.\n",
+    )
+    .unwrap();
+    fs::write(
+        geometry.join("src/main.st"),
+        "Require \"four.st\".\nAssert four = 4.\n",
+    )
+    .unwrap();
+    let build = || {
+        Command::cargo_bin("startlang")
+            .unwrap()
+            .env("STARTLANG_CACHE", &cache)
+            .args(["build", geometry.to_str().unwrap()])
+            .output()
+            .unwrap()
+            .status
+            .code()
+    };
+
+    assert_eq!(build(), Some(0));
+    assert_eq!(fs::read_dir(cache.join("files")).unwrap().count(), 3);
+    assert_eq!(build(), Some(0));
+
+    fs::write(numbers.join("src/two.st"), "Definition two := 3.\n").unwrap();
+    assert_ne!(build(), Some(0));
+}
+
+#[test]
+fn interface_of_another_build_is_not_loaded() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.child("cache");
+    let interface = cache.join("stdlib.sti");
+
+    let checked = run(&dir, &cache);
+    let stored = fs::read_to_string(&interface).unwrap();
+
+    let header = stored.lines().next().unwrap();
+    let other = header.rsplit_once(' ').unwrap().0.to_string() + " 0000000000000000";
+    let edited = stored
+        .replacen(header, &other, 1)
+        .replace("doc Integers type", "doc Integers from the interface");
+    fs::write(&interface, edited).unwrap();
+    assert_eq!(run(&dir, &cache), checked);
+    assert_eq!(fs::read_to_string(&interface).unwrap(), stored);
+}
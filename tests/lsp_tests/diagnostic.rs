@@ -36,7 +36,10 @@ fn test_error(diag: PublishDiagnosticsParams, uri: Url) {
     assert_eq!(d.range.start.character, 16);
     assert_eq!(d.range.end.line, 5);
     assert_eq!(d.range.end.character, 17);
-    assert_eq!(d.message, "Parsing expect \":\" or \")\".");
+    assert_eq!(
+        d.message,
+        "Parsing expect \".<field>\" or \"pattern variable\" or \"(\" or \",\" or \":\" or \")\" or \"=>\" or \"constant\" or \"variable\" or \"{\" or \"operator\"."
+    );
 
     let d = ds.next().unwrap();
     assert_eq!(d.severity, Some(DiagnosticSeverity::ERROR));
@@ -99,8 +102,45 @@ async fn did_open_print() {
 
     assert_eq!(diag.uri, uri);
 
-    assert_eq!(diag.diagnostics.len(), 14);
+    assert_eq!(diag.diagnostics.len(), 15);
     for d in diag.diagnostics {
         assert_eq!(d.severity, Some(DiagnosticSeverity::INFORMATION));
     }
 }
+
+#[tokio::test]
+async fn did_open_require() {
+    let mut ctx = TestContext::new("diagnostic");
+    ctx.initialize().await;
+    let text_document = ctx.document_item(
+        "require.st",
+        include_str!("workspace/diagnostic/require.st"),
+    );
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+
+    let uri = ctx.doc_uri("require.st");
+    let diag = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(diag.uri, uri);
+
+    let mut ds = diag.diagnostics.into_iter();
+    let d = ds.next().unwrap();
+    assert_eq!(d.severity, Some(DiagnosticSeverity::ERROR));
+    assert_eq!(d.range.start.line, 0);
+    assert_eq!(d.range.start.character, 8);
+    assert_eq!(d.range.end.line, 0);
+    assert_eq!(d.range.end.character, 20);
+    assert_eq!(d.message, "File \"lib/bad.st\" has errors.");
+
+    let related = d.related_information.unwrap();
+    assert_eq!(related.len(), 1);
+    assert_eq!(related[0].location.uri, ctx.doc_uri("lib/bad.st"));
+    assert_eq!(related[0].location.range.start.line, 1);
+    assert_eq!(related[0].location.range.start.character, 18);
+    assert_eq!(
+        related[0].message,
+        "Expression variable not_exist_var not found."
+    );
+
+    assert!(ds.next().is_none(), "There should be no more diagnostics.");
+}
@@ -190,3 +190,309 @@ async fn hover() {
 
     assert!(hover_nothing.is_none());
 }
+
+#[tokio::test]
+async fn hover_polymorphism() {
+    let mut ctx = TestContext::new("hover");
+    ctx.initialize().await;
+    let text_document = ctx.document_item("poly.st", include_str!("workspace/hover/poly.st"));
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+    let uri = ctx.doc_uri("poly.st");
+
+    let dp = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(dp.diagnostics.len(), 0);
+
+    let hover_def = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 4,
+                    character: 5,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        hover_def.contents,
+        HoverContents::Scalar(MarkedString::from_language_code(
+            "startlang".to_string(),
+            "some <α> : α -> option α".to_string()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn hover_local() {
+    let mut ctx = TestContext::new("hover");
+    ctx.initialize().await;
+    let text_document = ctx.document_item("local.st", include_str!("workspace/hover/local.st"));
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+    let uri = ctx.doc_uri("local.st");
+
+    let dp = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(dp.diagnostics.len(), 0);
+
+    let hover_ref = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 2,
+                    character: 10,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        hover_ref.contents,
+        HoverContents::Scalar(MarkedString::from_language_code(
+            "startlang".to_string(),
+            "side : ℕ".to_string()
+        ))
+    );
+
+    assert_eq!(
+        hover_ref.range,
+        Some(Range {
+            start: Position {
+                line: 2,
+                character: 9
+            },
+            end: Position {
+                line: 2,
+                character: 13
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn hover_theorem() {
+    let mut ctx = TestContext::new("hover");
+    ctx.initialize().await;
+    let text_document = ctx.document_item("theorem.st", include_str!("workspace/hover/theorem.st"));
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+    let uri = ctx.doc_uri("theorem.st");
+
+    let dp = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(dp.diagnostics.len(), 0);
+
+    let hover_ref = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 4,
+                    character: 18,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    let sep = MarkedString::from_markdown("-----".to_string());
+    assert_eq!(
+        hover_ref.contents,
+        HoverContents::Array(vec![
+            MarkedString::from_language_code(
+                "startlang".to_string(),
+                "and_comm : forall a b : Prop, a /\\ b --> b /\\ a".to_string()
+            ),
+            sep,
+            MarkedString::from_markdown("Conjunction is commutative.\n".to_string()),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn hover_contract() {
+    let mut ctx = TestContext::new("hover");
+    ctx.initialize().await;
+    let text_document =
+        ctx.document_item("contract.st", include_str!("workspace/hover/contract.st"));
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+    let uri = ctx.doc_uri("contract.st");
+
+    let dp = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(dp.diagnostics.len(), 0);
+
+    let hover_ref = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 2,
+                    character: 19,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        hover_ref.contents,
+        HoverContents::Array(vec![
+            MarkedString::from_language_code(
+                "startlang".to_string(),
+                "pred : ℕ -> ℕ\n  requires n > 0\n  ensures r + 1 = n".to_string()
+            ),
+            MarkedString::from_markdown("-----".to_string()),
+            MarkedString::from_markdown("Predecessor of a positive number.\n".to_string()),
+        ])
+    );
+
+    assert_eq!(
+        hover_ref.range,
+        Some(Range {
+            start: Position {
+                line: 2,
+                character: 18
+            },
+            end: Position {
+                line: 2,
+                character: 22
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn hover_module() {
+    let mut ctx = TestContext::new("hover");
+    ctx.initialize().await;
+    let text_document = ctx.document_item("module.st", include_str!("workspace/hover/module.st"));
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+    let uri = ctx.doc_uri("module.st");
+
+    let dp = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(dp.diagnostics.len(), 0);
+
+    let sep = MarkedString::from_markdown("-----".to_string());
+    let side = HoverContents::Array(vec![
+        MarkedString::from_language_code("startlang".to_string(), "Shape.side : ℕ".to_string()),
+        sep,
+        MarkedString::from_markdown("Side of a square.\n".to_string()),
+    ]);
+
+    let hover_unqualified = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 3,
+                    character: 24,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(hover_unqualified.contents, side);
+    assert_eq!(
+        hover_unqualified.range,
+        Some(Range {
+            start: Position {
+                line: 3,
+                character: 23
+            },
+            end: Position {
+                line: 3,
+                character: 27
+            }
+        })
+    );
+
+    let hover_qualified = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 5,
+                    character: 26,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(hover_qualified.contents, side);
+    assert_eq!(
+        hover_qualified.range,
+        Some(Range {
+            start: Position {
+                line: 5,
+                character: 19
+            },
+            end: Position {
+                line: 5,
+                character: 29
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn hover_require() {
+    let mut ctx = TestContext::new("hover");
+    ctx.initialize().await;
+    let text_document = ctx.document_item("require.st", include_str!("workspace/hover/require.st"));
+    ctx.notify::<notification::DidOpenTextDocument>(DidOpenTextDocumentParams { text_document })
+        .await;
+    let uri = ctx.doc_uri("require.st");
+
+    let dp = ctx.recv::<PublishDiagnosticsParams>().await;
+    assert_eq!(dp.diagnostics.len(), 0);
+
+    let hover_ref = ctx
+        .request::<request::HoverRequest>(HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position {
+                    line: 1,
+                    character: 20,
+                },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        hover_ref.contents,
+        HoverContents::Array(vec![
+            MarkedString::from_language_code("startlang".to_string(), "side : ℕ".to_string()),
+            MarkedString::from_markdown("-----".to_string()),
+            MarkedString::from_markdown("Side of a square.\n".to_string()),
+        ])
+    );
+
+    assert_eq!(
+        hover_ref.range,
+        Some(Range {
+            start: Position {
+                line: 1,
+                character: 19
+            },
+            end: Position {
+                line: 1,
+                character: 23
+            }
+        })
+    );
+}
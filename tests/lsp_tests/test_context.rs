@@ -42,6 +42,7 @@ impl TestContext {
     pub fn new(base: &str) -> Self {
         let mut child = tokio::process::Command::new(cargo_bin!("startlang"))
             .arg("lsp")
+            .env("STARTLANG_CACHE", "")
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .spawn()